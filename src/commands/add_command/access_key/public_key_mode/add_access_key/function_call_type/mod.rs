@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
 
 /// данные для определения ключа с function call
@@ -73,11 +74,11 @@ impl FunctionCallType {
     ) -> color_eyre::eyre::Result<Self> {
         let allowance: Option<near_primitives::types::Balance> = match item.allowance {
             Some(cli_allowance) => Some(cli_allowance.to_yoctonear()),
-            None => FunctionCallType::input_allowance(),
+            None => FunctionCallType::input_allowance()?,
         };
         let receiver_id: near_primitives::types::AccountId = match item.receiver_id {
             Some(cli_receiver_id) => near_primitives::types::AccountId::from(cli_receiver_id),
-            None => FunctionCallType::input_receiver_id(),
+            None => FunctionCallType::input_receiver_id()?,
         };
         let method_names: Vec<String> = match item.method_names {
             Some(cli_method_names) => {
@@ -90,7 +91,7 @@ impl FunctionCallType {
                         .collect::<Vec<String>>()
                 }
             }
-            None => FunctionCallType::input_method_names(),
+            None => FunctionCallType::input_method_names()?,
         };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -106,7 +107,7 @@ impl FunctionCallType {
 }
 
 impl FunctionCallType {
-    pub fn input_method_names() -> Vec<String> {
+    pub fn input_method_names() -> color_eyre::eyre::Result<Vec<String>> {
         println!();
         let choose_input = vec![
             "Yes, I want to input a list of method names that can be used",
@@ -116,12 +117,14 @@ impl FunctionCallType {
             .with_prompt("Do You want to input a list of method names that can be used")
             .items(&choose_input)
             .default(0)
+            .require_interactive("--method-names")?
             .interact_on_opt(&Term::stderr())
             .unwrap();
-        match select_choose_input {
+        Ok(match select_choose_input {
             Some(0) => {
                 let mut input_method_names: String = Input::new()
                     .with_prompt("Enter a comma-separated list of method names that will be allowed to be called in a transaction signed by this access key.")
+                    .require_interactive("--method-names")?
                     .interact_text()
                     .unwrap();
                 if input_method_names.contains("\"") {
@@ -138,10 +141,10 @@ impl FunctionCallType {
             }
             Some(1) => vec![],
             _ => unreachable!("Error"),
-        }
+        })
     }
 
-    pub fn input_allowance() -> Option<near_primitives::types::Balance> {
+    pub fn input_allowance() -> color_eyre::eyre::Result<Option<near_primitives::types::Balance>> {
         println!();
         let choose_input = vec![
             "Yes, I want to input allowance for receiver ID",
@@ -151,27 +154,30 @@ impl FunctionCallType {
             .with_prompt("Do You want to input an allowance for receiver ID")
             .items(&choose_input)
             .default(0)
+            .require_interactive("--allowance")?
             .interact_on_opt(&Term::stderr())
             .unwrap();
-        match select_choose_input {
+        Ok(match select_choose_input {
             Some(0) => {
                 let allowance_near_balance: crate::common::NearBalance = Input::new()
                     .with_prompt("Enter an allowance which is a balance limit to use by this access key to pay for function call gas and transaction fees.")
+                    .require_interactive("--allowance")?
                     .interact_text()
                     .unwrap();
                 Some(allowance_near_balance.to_yoctonear())
             }
             Some(1) => None,
             _ => unreachable!("Error"),
-        }
+        })
     }
 
-    pub fn input_receiver_id() -> near_primitives::types::AccountId {
+    pub fn input_receiver_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter a receiver to use by this access key to pay for function call gas and transaction fees.")
+            .require_interactive("--receiver-id")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -57,7 +58,7 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let permission: AccessKeyPermission = match item.permission {
             Some(cli_permission) => {
@@ -74,11 +75,12 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -201,6 +203,7 @@ impl AccessKeyPermission {
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&permissions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         match variants[select_permission] {
@@ -1,7 +1,6 @@
 use std::str::FromStr;
 
-/// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Generate a key pair of private and public keys (ed25519 unless `--key-type secp256k1`)
 #[derive(Debug, Default, Clone, clap::Clap)]
 #[clap(
     setting(clap::AppSettings::ColoredHelp),
@@ -9,22 +8,30 @@ use std::str::FromStr;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliGenerateKeypair {
+    /// ed25519 or secp256k1
+    #[clap(long)]
+    key_type: Option<near_crypto::KeyType>,
     #[clap(subcommand)]
     permission: Option<super::add_access_key::CliAccessKeyPermission>,
 }
 
 #[derive(Debug, Clone)]
 pub struct GenerateKeypair {
+    pub key_type: near_crypto::KeyType,
     pub permission: super::add_access_key::AccessKeyPermission,
 }
 
 impl CliGenerateKeypair {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        let args = self
+        let mut args = self
             .permission
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
             .unwrap_or_default();
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned())
+        }
         args
     }
 }
@@ -32,6 +39,7 @@ impl CliGenerateKeypair {
 impl From<GenerateKeypair> for CliGenerateKeypair {
     fn from(generate_keypair: GenerateKeypair) -> Self {
         Self {
+            key_type: Some(generate_keypair.key_type),
             permission: Some(generate_keypair.permission.into()),
         }
     }
@@ -54,7 +62,10 @@ impl GenerateKeypair {
                 sender_account_id,
             )?,
         };
-        Ok(Self { permission })
+        Ok(Self {
+            key_type: item.key_type.unwrap_or(near_crypto::KeyType::ED25519),
+            permission,
+        })
     }
 }
 
@@ -65,12 +76,13 @@ impl GenerateKeypair {
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair().await?;
+            crate::common::generate_keypair(self.key_type).await?;
 
         crate::common::save_access_key_to_keychain(
             network_connection_config.clone(),
             key_pair_properties.clone(),
             &prepopulated_unsigned_transaction.signer_id.to_string(),
+            None,
         )
         .await
         .map_err(|err| {
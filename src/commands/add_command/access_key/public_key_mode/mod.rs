@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -90,6 +91,7 @@ impl PublicKeyMode {
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         match variants[select_mode] {
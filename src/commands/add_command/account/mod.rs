@@ -0,0 +1,572 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::str::FromStr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+pub mod operation_mode;
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a new top-level account
+    NewAccount(CliNewAccount),
+}
+
+#[derive(Debug, Clone)]
+pub enum SendTo {
+    NewAccount(NewAccount),
+}
+
+impl CliSendTo {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::NewAccount(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("new-account".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<SendTo> for CliSendTo {
+    fn from(send_to: SendTo) -> Self {
+        match send_to {
+            SendTo::NewAccount(new_account) => Self::NewAccount(new_account.into()),
+        }
+    }
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        connection_config: crate::common::ConnectionConfig,
+        is_helper_available: bool,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendTo::NewAccount(cli_new_account) => Ok(Self::NewAccount(NewAccount::from(
+                cli_new_account,
+                connection_config,
+                is_helper_available,
+            )?)),
+        }
+    }
+
+    pub fn send_to(
+        connection_config: crate::common::ConnectionConfig,
+        is_helper_available: bool,
+    ) -> color_eyre::eyre::Result<Self> {
+        Self::from(
+            CliSendTo::NewAccount(Default::default()),
+            connection_config,
+            is_helper_available,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        helper_url: Option<url::Url>,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::NewAccount(new_account) => {
+                new_account
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        helper_url,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+/// Specify a new top-level account
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliNewAccount {
+    new_account_id: Option<near_primitives::types::AccountId>,
+    /// Create the account with the account helper of the network
+    #[clap(long)]
+    via_helper: bool,
+    #[clap(subcommand)]
+    create_method: Option<CliCreateMethod>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAccount {
+    new_account_id: near_primitives::types::AccountId,
+    create_method: CreateMethod,
+}
+
+impl CliNewAccount {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .create_method
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if self.via_helper {
+            args.push_front("--via-helper".to_owned());
+        }
+        if let Some(new_account_id) = &self.new_account_id {
+            args.push_front(new_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<NewAccount> for CliNewAccount {
+    fn from(new_account: NewAccount) -> Self {
+        match new_account.create_method {
+            CreateMethod::ViaHelper => Self {
+                new_account_id: Some(new_account.new_account_id),
+                via_helper: true,
+                create_method: None,
+            },
+            CreateMethod::ViaLinkdrop(via_linkdrop) => Self {
+                new_account_id: Some(new_account.new_account_id),
+                via_helper: false,
+                create_method: Some(CliCreateMethod::ViaLinkdrop(via_linkdrop.into())),
+            },
+        }
+    }
+}
+
+impl NewAccount {
+    pub fn from(
+        item: CliNewAccount,
+        connection_config: crate::common::ConnectionConfig,
+        is_helper_available: bool,
+    ) -> color_eyre::eyre::Result<Self> {
+        let new_account_id: near_primitives::types::AccountId = match item.new_account_id {
+            Some(cli_new_account_id) => cli_new_account_id,
+            None => NewAccount::input_new_account_id()?,
+        };
+        let create_method = if item.via_helper {
+            if !is_helper_available {
+                return Err(color_eyre::Report::msg(
+                    "There is no account helper for this network. Specify it with --helper-url.",
+                ));
+            }
+            CreateMethod::ViaHelper
+        } else {
+            match item.create_method {
+                Some(CliCreateMethod::ViaLinkdrop(cli_via_linkdrop)) => CreateMethod::ViaLinkdrop(
+                    ViaLinkdrop::from(cli_via_linkdrop, connection_config, &new_account_id)?,
+                ),
+                None => CreateMethod::choose_create_method(
+                    connection_config,
+                    &new_account_id,
+                    is_helper_available,
+                )?,
+            }
+        };
+        Ok(Self {
+            new_account_id,
+            create_method,
+        })
+    }
+
+    fn input_new_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the new account ID? (example: alice.testnet)")
+            .require_interactive("<new-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        helper_url: Option<url::Url>,
+    ) -> crate::CliResult {
+        crate::common::verify_account_does_not_exist(
+            &network_connection_config,
+            &self.new_account_id,
+        )
+        .await?;
+        let key_pair_properties: crate::common::KeyPairProperties =
+            crate::common::generate_keypair(near_crypto::KeyType::ED25519).await?;
+        let public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+        match self.create_method {
+            CreateMethod::ViaHelper => {
+                let helper_url = helper_url.ok_or_else(|| {
+                    color_eyre::Report::msg(
+                        "There is no account helper for this network. Specify it with --helper-url.",
+                    )
+                })?;
+                create_account_via_helper(&helper_url, &self.new_account_id, &public_key).await?;
+                println!(
+                    "\nThe account <{}> has been created by the account helper {}",
+                    self.new_account_id, helper_url
+                );
+            }
+            CreateMethod::ViaLinkdrop(via_linkdrop) => {
+                crate::common::verify_account_exists(
+                    &network_connection_config,
+                    &via_linkdrop.signer_account_id,
+                )
+                .await?;
+                let unsigned_transaction = near_primitives::transaction::Transaction {
+                    signer_id: via_linkdrop.signer_account_id.clone(),
+                    public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                    receiver_id: via_linkdrop.linkdrop_account_id.clone(),
+                    actions: vec![near_primitives::transaction::Action::FunctionCall(
+                        near_primitives::transaction::FunctionCallAction {
+                            method_name: "create_account".to_string(),
+                            args: serde_json::json!({
+                                "new_account_id": self.new_account_id.to_string(),
+                                "new_public_key": public_key.to_string(),
+                            })
+                            .to_string()
+                            .into_bytes(),
+                            gas: crate::consts::CREATE_ACCOUNT_GAS,
+                            deposit: via_linkdrop.deposit.to_yoctonear(),
+                        },
+                    )],
+                    ..prepopulated_unsigned_transaction
+                };
+                if let Some(transaction_info) = via_linkdrop
+                    .sign_option
+                    .process(
+                        unsigned_transaction,
+                        Some(network_connection_config.clone()),
+                    )
+                    .await?
+                {
+                    let is_failed = matches!(
+                        transaction_info.status,
+                        near_primitives::views::FinalExecutionStatus::Failure(_)
+                    );
+                    crate::common::print_transaction_status(
+                        transaction_info,
+                        Some(network_connection_config.clone()),
+                    );
+                    if is_failed {
+                        return Err(color_eyre::Report::msg(format!(
+                            "The account <{}> was not created",
+                            self.new_account_id
+                        )));
+                    }
+                }
+            }
+        }
+        crate::common::save_access_key_to_keychain(
+            Some(network_connection_config),
+            key_pair_properties,
+            &self.new_account_id.to_string(),
+            None,
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliCreateMethod {
+    /// Call the `create_account` method of the top-level account contract (e.g. `testnet`)
+    ViaLinkdrop(CliViaLinkdrop),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum CreateMethod {
+    #[strum_discriminants(strum(message = "Create the account with the account helper (faucet)"))]
+    ViaHelper,
+    #[strum_discriminants(strum(
+        message = "Create the account with the top-level account contract, paid by my account"
+    ))]
+    ViaLinkdrop(ViaLinkdrop),
+}
+
+impl CliCreateMethod {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::ViaLinkdrop(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("via-linkdrop".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl CreateMethod {
+    fn choose_create_method(
+        connection_config: crate::common::ConnectionConfig,
+        new_account_id: &near_primitives::types::AccountId,
+        is_helper_available: bool,
+    ) -> color_eyre::eyre::Result<Self> {
+        let variants = CreateMethodDiscriminants::iter()
+            .filter(|variant| {
+                is_helper_available || !matches!(variant, CreateMethodDiscriminants::ViaHelper)
+            })
+            .collect::<Vec<_>>();
+        let methods = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_method = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to create the account?")
+            .items(&methods)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        match variants[selected_method] {
+            CreateMethodDiscriminants::ViaHelper => Ok(Self::ViaHelper),
+            CreateMethodDiscriminants::ViaLinkdrop => Ok(Self::ViaLinkdrop(ViaLinkdrop::from(
+                Default::default(),
+                connection_config,
+                new_account_id,
+            )?)),
+        }
+    }
+}
+
+/// Specify the account that pays for the new account
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliViaLinkdrop {
+    signer_account_id: Option<near_primitives::types::AccountId>,
+    /// The initial balance of the new account
+    #[clap(long)]
+    deposit: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug, Clone)]
+pub struct ViaLinkdrop {
+    linkdrop_account_id: near_primitives::types::AccountId,
+    signer_account_id: near_primitives::types::AccountId,
+    deposit: crate::common::NearBalance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl CliViaLinkdrop {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(deposit) = &self.deposit {
+            args.push_front(deposit.to_string());
+            args.push_front("--deposit".to_owned());
+        }
+        if let Some(signer_account_id) = &self.signer_account_id {
+            args.push_front(signer_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<ViaLinkdrop> for CliViaLinkdrop {
+    fn from(via_linkdrop: ViaLinkdrop) -> Self {
+        Self {
+            signer_account_id: Some(via_linkdrop.signer_account_id),
+            deposit: Some(via_linkdrop.deposit),
+            sign_option: Some(via_linkdrop.sign_option.into()),
+        }
+    }
+}
+
+impl ViaLinkdrop {
+    fn from(
+        item: CliViaLinkdrop,
+        connection_config: crate::common::ConnectionConfig,
+        new_account_id: &near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        let linkdrop_account_id = get_linkdrop_account_id(new_account_id)?;
+        let signer_account_id: near_primitives::types::AccountId =
+            match item.signer_account_id.or_else(crate::network::signer) {
+                Some(cli_signer_account_id) => cli_signer_account_id,
+                None => ViaLinkdrop::input_signer_account_id()?,
+            };
+        let deposit: crate::common::NearBalance = match item.deposit {
+            Some(cli_deposit) => cli_deposit,
+            None => Input::new()
+                .with_prompt("How many NEAR Tokens do you want to transfer to the new account? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                .require_interactive("--deposit")?
+                .interact_text()
+                .unwrap(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), signer_account_id.clone())?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), signer_account_id.clone())?,
+        };
+        Ok(Self {
+            linkdrop_account_id,
+            signer_account_id,
+            deposit,
+            sign_option,
+        })
+    }
+
+    fn input_signer_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID that pays for the new account?")
+            .require_interactive("<signer-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+}
+
+/// The top-level account contract can only create its direct sub-accounts,
+/// e.g. `testnet` creates `alice.testnet`
+fn get_linkdrop_account_id(
+    new_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    let new_account_id = new_account_id.to_string();
+    match new_account_id.splitn(2, '.').nth(1) {
+        Some(linkdrop_account_id) if !linkdrop_account_id.contains('.') => {
+            near_primitives::types::AccountId::from_str(linkdrop_account_id).map_err(|err| {
+                color_eyre::Report::msg(format!("Invalid top-level account ID: {}", err))
+            })
+        }
+        _ => Err(color_eyre::Report::msg(format!(
+            "The account <{}> is not a direct sub-account of a top-level account (example: alice.testnet)",
+            new_account_id
+        ))),
+    }
+}
+
+async fn create_account_via_helper(
+    helper_url: &url::Url,
+    new_account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> crate::CliResult {
+    let url = helper_url.join("account")?;
+    let mut response = awc::Client::default()
+        .post(url.as_str())
+        .timeout(crate::consts::ACCOUNT_HELPER_TIMEOUT)
+        .send_json(&serde_json::json!({
+            "newAccountId": new_account_id.to_string(),
+            "newAccountPublicKey": public_key.to_string(),
+        }))
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to send the request to the account helper: {}",
+                err
+            ))
+        })?;
+    if !response.status().is_success() {
+        let body = response.body().await.unwrap_or_default();
+        return Err(color_eyre::Report::msg(format!(
+            "The account helper failed to create the account ({}): {}",
+            response.status(),
+            String::from_utf8_lossy(&body)
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    /// Accepts a single request, answers it with the given status line and returns the request
+    fn mock_helper(status_line: &'static str) -> (u16, std::thread::JoinHandle<String>) {
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            loop {
+                let read = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(headers_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..headers_end]
+                        .lines()
+                        .find_map(|line| {
+                            line.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|value| value.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= headers_end + 4 + content_length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            stream
+                .write_all(
+                    format!(
+                        "{}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                        status_line
+                    )
+                    .as_bytes(),
+                )
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (port, handle)
+    }
+
+    fn public_key() -> near_crypto::PublicKey {
+        near_crypto::PublicKey::from_str("ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp")
+            .unwrap()
+    }
+
+    #[test]
+    fn create_account_with_mock_helper() {
+        let (port, helper) = mock_helper("HTTP/1.1 200 OK");
+        let helper_url: url::Url = format!("http://127.0.0.1:{}/", port).parse().unwrap();
+        let new_account_id = near_primitives::types::AccountId::from_str("alice.testnet").unwrap();
+        actix::System::new()
+            .block_on(create_account_via_helper(
+                &helper_url,
+                &new_account_id,
+                &public_key(),
+            ))
+            .unwrap();
+        let request = helper.join().unwrap();
+        assert!(request.starts_with("POST /account HTTP/1.1"));
+        let body: serde_json::Value =
+            serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "newAccountId": "alice.testnet",
+                "newAccountPublicKey": "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+            })
+        );
+    }
+
+    #[test]
+    fn linkdrop_account_id() {
+        let new_account_id = near_primitives::types::AccountId::from_str("alice.testnet").unwrap();
+        assert_eq!(
+            get_linkdrop_account_id(&new_account_id)
+                .unwrap()
+                .to_string(),
+            "testnet"
+        );
+        let new_account_id =
+            near_primitives::types::AccountId::from_str("app.alice.testnet").unwrap();
+        assert!(get_linkdrop_account_id(&new_account_id).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+/// аргументы, необходимые для создания транзакции в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    /// The account helper endpoint (the helper of the network is used by default)
+    #[clap(long)]
+    helper_url: Option<url::Url>,
+    #[clap(subcommand)]
+    pub send_to: Option<super::super::CliSendTo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    pub helper_url: Option<url::Url>,
+    pub send_to: super::super::SendTo,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .send_to
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(helper_url) = &self.helper_url {
+            args.push_front(helper_url.to_string());
+            args.push_front("--helper-url".to_string());
+        }
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        let helper_url = if network_args.helper_url
+            == network_args.selected_server.connection_config.helper_url()
+        {
+            None
+        } else {
+            network_args.helper_url
+        };
+        Self {
+            selected_server: network_args.selected_server.into(),
+            helper_url,
+            send_to: Some(network_args.send_to.into()),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let connection_config = selected_server.connection_config.clone();
+        let helper_url = item.helper_url.or_else(|| connection_config.helper_url());
+        let send_to = match item.send_to {
+            Some(cli_send_to) => super::super::SendTo::from(
+                cli_send_to,
+                connection_config.clone(),
+                helper_url.is_some(),
+            )?,
+            None => super::super::SendTo::send_to(connection_config.clone(), helper_url.is_some())?,
+        };
+        Ok(Self {
+            selected_server,
+            helper_url,
+            send_to,
+        })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        self.send_to
+            .process(
+                prepopulated_unsigned_transaction,
+                self.selected_server.connection_config,
+                self.helper_url,
+            )
+            .await
+    }
+}
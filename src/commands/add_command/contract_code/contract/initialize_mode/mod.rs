@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -84,6 +85,7 @@ impl NextAction {
             .with_prompt("Do you want to choose next action")
             .items(&actions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_action = match variants[selected_action] {
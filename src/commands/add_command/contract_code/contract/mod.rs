@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -65,6 +66,7 @@ impl Contract {
             .with_prompt("To deploy contract code you will need to choose next action")
             .items(&contracts)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_contract = match variants[selected_contract] {
@@ -142,7 +144,7 @@ impl ContractFile {
     ) -> color_eyre::eyre::Result<Self> {
         let file_path = match item.file_path {
             Some(cli_file_path) => cli_file_path,
-            None => ContractFile::input_file_path(),
+            None => ContractFile::input_file_path()?,
         };
         let next_action = match item.next_action {
             Some(cli_next_action) => self::initialize_mode::NextAction::from(
@@ -163,13 +165,14 @@ impl ContractFile {
 }
 
 impl ContractFile {
-    fn input_file_path() -> std::path::PathBuf {
+    fn input_file_path() -> color_eyre::eyre::Result<std::path::PathBuf> {
         println!();
         let input_file_path: String = Input::new()
             .with_prompt("What is a file location of the contract?")
+            .require_interactive("<file-path>")?
             .interact_text()
             .unwrap();
-        input_file_path.into()
+        Ok(input_file_path.into())
     }
 
     pub async fn process(
@@ -0,0 +1,370 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+/// пополнение неявного аккаунта переводом
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTransfer {
+    sender_account_id: Option<near_primitives::types::AccountId>,
+    #[clap(long)]
+    amount: Option<crate::common::NearBalance>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+/// ожидание поступления средств на неявный аккаунт
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliWaitForFunds {}
+
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    sender_account_id: near_primitives::types::AccountId,
+    amount: crate::common::NearBalance,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliFund {
+    /// Transfer tokens to the implicit account from a signer account
+    Transfer(CliTransfer),
+    /// Wait until somebody sends tokens to the implicit account
+    WaitForFunds(CliWaitForFunds),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum Fund {
+    #[strum_discriminants(strum(message = "Transfer tokens from my account"))]
+    Transfer(Transfer),
+    #[strum_discriminants(strum(message = "Wait for the tokens to arrive"))]
+    WaitForFunds,
+}
+
+impl CliTransfer {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(amount) = &self.amount {
+            args.push_front(amount.to_string());
+            args.push_front("--amount".to_owned());
+        }
+        if let Some(sender_account_id) = &self.sender_account_id {
+            args.push_front(sender_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Transfer> for CliTransfer {
+    fn from(transfer: Transfer) -> Self {
+        Self {
+            sender_account_id: Some(transfer.sender_account_id),
+            amount: Some(transfer.amount),
+            sign_option: Some(transfer.sign_option.into()),
+        }
+    }
+}
+
+impl Transfer {
+    pub fn from(
+        item: CliTransfer,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let sender_account_id: near_primitives::types::AccountId =
+            match item.sender_account_id.or_else(crate::network::signer) {
+                Some(cli_sender_account_id) => cli_sender_account_id,
+                None => Transfer::input_sender_account_id()?,
+            };
+        let amount: crate::common::NearBalance = match item.amount {
+            Some(cli_amount) => cli_amount,
+            None => Input::new()
+                .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                .require_interactive("--amount")?
+                .interact_text()
+                .unwrap(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), sender_account_id.clone())?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), sender_account_id.clone())?,
+        };
+        Ok(Self {
+            sender_account_id,
+            amount,
+            sign_option,
+        })
+    }
+
+    fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the sender?")
+            .require_interactive("<sender-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+}
+
+impl CliFund {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Transfer(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("transfer".to_owned());
+                args
+            }
+            Self::WaitForFunds(_) => {
+                let mut args = std::collections::VecDeque::new();
+                args.push_front("wait-for-funds".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Fund> for CliFund {
+    fn from(fund: Fund) -> Self {
+        match fund {
+            Fund::Transfer(transfer) => Self::Transfer(transfer.into()),
+            Fund::WaitForFunds => Self::WaitForFunds(CliWaitForFunds {}),
+        }
+    }
+}
+
+impl Fund {
+    pub fn from(
+        item: CliFund,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliFund::Transfer(cli_transfer) => Ok(Self::Transfer(Transfer::from(
+                cli_transfer,
+                connection_config,
+            )?)),
+            CliFund::WaitForFunds(_) => Ok(Self::WaitForFunds),
+        }
+    }
+
+    pub fn choose_fund(
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = FundDiscriminants::iter().collect::<Vec<_>>();
+        let funds = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to fund the implicit account?")
+            .items(&funds)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_fund = match variants[selection] {
+            FundDiscriminants::Transfer => CliFund::Transfer(Default::default()),
+            FundDiscriminants::WaitForFunds => CliFund::WaitForFunds(Default::default()),
+        };
+        Self::from(cli_fund, connection_config)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let implicit_account_id = prepopulated_unsigned_transaction.receiver_id.clone();
+        let implicit_public_key = prepopulated_unsigned_transaction.public_key.clone();
+        copy_key_to_network_keychain(&implicit_account_id, &network_connection_config)?;
+        match self {
+            Fund::Transfer(transfer) => {
+                crate::common::verify_account_exists(
+                    &network_connection_config,
+                    &transfer.sender_account_id,
+                )
+                .await?;
+                let unsigned_transaction = near_primitives::transaction::Transaction {
+                    signer_id: transfer.sender_account_id.clone(),
+                    public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                    actions: vec![near_primitives::transaction::Action::Transfer(
+                        near_primitives::transaction::TransferAction {
+                            deposit: transfer.amount.to_yoctonear(),
+                        },
+                    )],
+                    ..prepopulated_unsigned_transaction
+                };
+                match transfer
+                    .sign_option
+                    .process(
+                        unsigned_transaction,
+                        Some(network_connection_config.clone()),
+                    )
+                    .await?
+                {
+                    Some(transaction_info) => {
+                        let failed = matches!(
+                            transaction_info.status,
+                            near_primitives::views::FinalExecutionStatus::Failure(_)
+                        );
+                        crate::common::print_transaction_status(
+                            transaction_info,
+                            Some(network_connection_config.clone()),
+                        );
+                        if failed {
+                            return Err(color_eyre::Report::msg(format!(
+                                "The transfer failed, the implicit account <{}> is not created",
+                                implicit_account_id
+                            )));
+                        }
+                    }
+                    None => {
+                        return Err(color_eyre::Report::msg(format!(
+                            "The transfer was not sent. The implicit account <{}> is created once the transfer is sent.",
+                            implicit_account_id
+                        )))
+                    }
+                };
+            }
+            Fund::WaitForFunds => {
+                println!(
+                    "Send at least a few NEAR tokens to <{}> to create the implicit account.",
+                    implicit_account_id
+                );
+            }
+        }
+        wait_for_account(&implicit_account_id, &network_connection_config).await?;
+        print_next_steps(
+            &implicit_account_id,
+            &implicit_public_key,
+            &network_connection_config,
+        );
+        Ok(())
+    }
+}
+
+/// Copies the key saved in the default keychain to the keychain of the network,
+/// so that sign-with-keychain finds it for the implicit account
+fn copy_key_to_network_keychain(
+    implicit_account_id: &near_primitives::types::AccountId,
+    network_connection_config: &crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    let file_name = format!("{}.json", implicit_account_id);
+    let mut source_path = std::path::PathBuf::from(&home_dir);
+    source_path.push(crate::consts::DIR_NAME_KEY_CHAIN);
+    source_path.push(&file_name);
+    let mut target_path = std::path::PathBuf::from(&home_dir);
+    target_path.push(network_connection_config.dir_name());
+    std::fs::create_dir_all(&target_path)?;
+    target_path.push(&file_name);
+    if source_path != target_path && !target_path.exists() {
+        std::fs::copy(&source_path, &target_path).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to copy the key file: {}", err))
+        })?;
+        println!(
+            "The data for the access key is saved in a file {}",
+            &target_path.display()
+        );
+    }
+    Ok(())
+}
+
+async fn wait_for_account(
+    implicit_account_id: &near_primitives::types::AccountId,
+    network_connection_config: &crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    println!(
+        "Waiting for the implicit account <{}> to appear on chain (press Ctrl+C to stop) ...",
+        implicit_account_id
+    );
+    let started_at = std::time::Instant::now();
+    let account_view = loop {
+        let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccount {
+                    account_id: implicit_account_id.clone(),
+                },
+            })
+            .await;
+        match query_view_method_response {
+            Ok(rpc_query_response) => {
+                if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewAccount(
+                    result,
+                ) = rpc_query_response.kind
+                {
+                    break result;
+                }
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            }
+            Err(err) if is_unknown_account_error(&err) => {}
+            Err(err) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view account: {:?}",
+                    err
+                )))
+            }
+        }
+        if started_at.elapsed() >= crate::consts::IMPLICIT_ACCOUNT_WAIT_TIMEOUT {
+            return Err(color_eyre::Report::msg(format!(
+                "The implicit account <{}> did not appear on chain in {:?}",
+                implicit_account_id,
+                crate::consts::IMPLICIT_ACCOUNT_WAIT_TIMEOUT
+            )));
+        }
+        actix::clock::sleep(crate::consts::IMPLICIT_ACCOUNT_POLL_INTERVAL).await;
+    };
+    println!(
+        "\nThe implicit account <{}> exists, its balance is {}.",
+        implicit_account_id,
+        crate::common::NearBalance::from_yoctonear(account_view.amount)
+    );
+    Ok(())
+}
+
+fn is_unknown_account_error(err: &near_jsonrpc_primitives::errors::RpcError) -> bool {
+    crate::rpc::classify_error(err) == crate::rpc::RpcErrorKind::Handler
+        && err
+            .data
+            .as_ref()
+            .map_or(false, |data| data.to_string().contains("does not exist"))
+}
+
+fn print_next_steps(
+    implicit_account_id: &near_primitives::types::AccountId,
+    implicit_public_key: &near_crypto::PublicKey,
+    network_connection_config: &crate::common::ConnectionConfig,
+) {
+    let network_args = match network_connection_config {
+        crate::common::ConnectionConfig::Testnet => "testnet".to_string(),
+        crate::common::ConnectionConfig::Mainnet => "mainnet".to_string(),
+        crate::common::ConnectionConfig::Betanet => "betanet".to_string(),
+        crate::common::ConnectionConfig::Custom { url } => format!("custom --url {}", url),
+    };
+    println!("\nWhat's next:");
+    println!(
+        "  show the balance:\n    near view account-summary network {} account {} at-final-block",
+        network_args, implicit_account_id
+    );
+    println!(
+        "  add a new access key:\n    near add access-key network {} account {} generate-keypair grant-full-access sign-with-keychain send",
+        network_args, implicit_account_id
+    );
+    println!(
+        "  rotate the key (after adding a new one, delete the generated one):\n    near delete access-key network {} account {} public-key {} sign-with-keychain send",
+        network_args, implicit_account_id, implicit_public_key
+    );
+}
@@ -1,6 +1,10 @@
+use std::convert::TryFrom;
 use std::io::Write;
 use std::str::FromStr;
 
+mod fund;
+pub mod operation_mode;
+
 fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
     const HARDEND: u32 = 1 << 31;
 
@@ -23,9 +27,42 @@ fn bip32path_to_string(bip32path: &slip10::BIP32Path) -> String {
 /// Generate a key pair of private and public keys (use it anywhere you need
 /// Ed25519 keys)
 #[derive(Debug, Clone, Default, clap::Clap)]
-pub struct CliGenerateKeypair {}
+pub struct CliGenerateKeypair {
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateKeypair {
+    pub mode: self::operation_mode::Mode,
+}
 
 impl CliGenerateKeypair {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.mode
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<GenerateKeypair> for CliGenerateKeypair {
+    fn from(generate_keypair: GenerateKeypair) -> Self {
+        Self {
+            mode: Some(generate_keypair.mode.into()),
+        }
+    }
+}
+
+impl GenerateKeypair {
+    pub fn from(item: CliGenerateKeypair) -> color_eyre::eyre::Result<Self> {
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode)?,
+            None => self::operation_mode::Mode::choose_mode()?,
+        };
+        Ok(Self { mode })
+    }
+
     pub async fn process(self) -> crate::CliResult {
         let new_master_seed_phrase_words_count: usize = 12;
         let seed_phrase_hd_path = slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap();
@@ -100,6 +137,18 @@ impl CliGenerateKeypair {
             "The data for the access key is saved in a file {}",
             &path.display()
         );
-        Ok(())
+        let implicit_account_id = near_primitives::types::AccountId::try_from(implicit_account_id)
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Invalid implicit account ID: {}", err))
+            })?;
+        let prepopulated_unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: implicit_account_id.clone(),
+            public_key: near_crypto::PublicKey::from_str(&public_key_str)?,
+            nonce: 0,
+            receiver_id: implicit_account_id,
+            block_hash: Default::default(),
+            actions: vec![],
+        };
+        self.mode.process(prepopulated_unsigned_transaction).await
     }
 }
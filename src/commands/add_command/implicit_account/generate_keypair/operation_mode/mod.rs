@@ -0,0 +1,115 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+pub mod online_mode;
+
+/// сохранение ключа без обращения к сети
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliOfflineArgs {}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliMode {
+    /// Fund the implicit account on the network and wait until it exists
+    Network(self::online_mode::CliNetworkArgs),
+    /// Only save the key pair to the keychain
+    Offline(CliOfflineArgs),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum Mode {
+    #[strum_discriminants(strum(message = "Yes, I want to fund the implicit account"))]
+    Network(self::online_mode::NetworkArgs),
+    #[strum_discriminants(strum(message = "No, I only want to save the key pair"))]
+    Offline,
+}
+
+impl CliMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Network(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("network".to_owned());
+                args
+            }
+            Self::Offline(_) => {
+                let mut args = std::collections::VecDeque::new();
+                args.push_front("offline".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Mode> for CliMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Network(network_args) => {
+                Self::Network(self::online_mode::CliNetworkArgs::from(network_args))
+            }
+            Mode::Offline => Self::Offline(CliOfflineArgs {}),
+        }
+    }
+}
+
+impl Mode {
+    pub fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
+            CliMode::Offline(_) => Ok(Self::Offline),
+        }
+    }
+}
+
+impl Mode {
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        if let Some(is_online) = crate::network::preselected_online_mode() {
+            let cli_mode = if is_online {
+                CliMode::Network(Default::default())
+            } else {
+                CliMode::Offline(Default::default())
+            };
+            return Self::from(cli_mode);
+        }
+        println!();
+        let variants = ModeDiscriminants::iter().collect::<Vec<_>>();
+        let modes = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_mode = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Do you want to fund the implicit account now?")
+            .items(&modes)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_mode = match variants[selected_mode] {
+            ModeDiscriminants::Network => CliMode::Network(Default::default()),
+            ModeDiscriminants::Offline => CliMode::Offline(Default::default()),
+        };
+        Self::from(cli_mode)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        match self {
+            Self::Network(network_args) => {
+                network_args
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+            Self::Offline => Ok(()),
+        }
+    }
+}
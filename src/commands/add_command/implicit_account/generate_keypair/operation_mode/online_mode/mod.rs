@@ -0,0 +1,69 @@
+/// аргументы, необходимые для создания транзакции в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    #[clap(subcommand)]
+    pub fund: Option<super::super::fund::CliFund>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    pub fund: super::super::fund::Fund,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let args = self
+            .fund
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+            fund: Some(network_args.fund.into()),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let connection_config = selected_server.connection_config.clone();
+        let fund = match item.fund {
+            Some(cli_fund) => super::super::fund::Fund::from(cli_fund, connection_config.clone())?,
+            None => super::super::fund::Fund::choose_fund(connection_config.clone())?,
+        };
+        Ok(Self {
+            selected_server,
+            fund,
+        })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        self.fund
+            .process(
+                prepopulated_unsigned_transaction,
+                self.selected_server.connection_config,
+            )
+            .await
+    }
+}
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -39,17 +40,15 @@ impl From<ImplicitAccount> for CliImplicitAccount {
     }
 }
 
-impl From<CliImplicitAccount> for ImplicitAccount {
-    fn from(item: CliImplicitAccount) -> Self {
+impl ImplicitAccount {
+    pub fn from(item: CliImplicitAccount) -> color_eyre::eyre::Result<Self> {
         let public_key_mode = match item.public_key_mode {
-            Some(cli_public_key_mode) => PublicKeyMode::from(cli_public_key_mode),
-            None => PublicKeyMode::choose_public_key_mode(),
+            Some(cli_public_key_mode) => PublicKeyMode::from(cli_public_key_mode)?,
+            None => PublicKeyMode::choose_public_key_mode()?,
         };
-        Self { public_key_mode }
+        Ok(Self { public_key_mode })
     }
-}
 
-impl ImplicitAccount {
     pub async fn process(self) -> crate::CliResult {
         self.public_key_mode.process().await
     }
@@ -65,14 +64,14 @@ pub enum CliPublicKeyMode {
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
 pub enum PublicKeyMode {
     #[strum_discriminants(strum(message = "Generate key pair"))]
-    GenerateKeypair(self::generate_keypair::CliGenerateKeypair),
+    GenerateKeypair(self::generate_keypair::GenerateKeypair),
 }
 
 impl CliPublicKeyMode {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         match self {
-            Self::GenerateKeypair(_) => {
-                let mut args = std::collections::VecDeque::new();
+            Self::GenerateKeypair(subcommand) => {
+                let mut args = subcommand.to_cli_args();
                 args.push_front("generate-keypair".to_owned());
                 args
             }
@@ -84,24 +83,24 @@ impl From<PublicKeyMode> for CliPublicKeyMode {
     fn from(public_key_mode: PublicKeyMode) -> Self {
         match public_key_mode {
             PublicKeyMode::GenerateKeypair(generate_keypair) => {
-                Self::GenerateKeypair(generate_keypair)
+                Self::GenerateKeypair(generate_keypair.into())
             }
         }
     }
 }
 
-impl From<CliPublicKeyMode> for PublicKeyMode {
-    fn from(item: CliPublicKeyMode) -> Self {
+impl PublicKeyMode {
+    pub fn from(item: CliPublicKeyMode) -> color_eyre::eyre::Result<Self> {
         match item {
             CliPublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
-                PublicKeyMode::GenerateKeypair(cli_generate_keypair)
+                Ok(PublicKeyMode::GenerateKeypair(
+                    self::generate_keypair::GenerateKeypair::from(cli_generate_keypair)?,
+                ))
             }
         }
     }
-}
 
-impl PublicKeyMode {
-    pub fn choose_public_key_mode() -> Self {
+    pub fn choose_public_key_mode() -> color_eyre::eyre::Result<Self> {
         let variants = PublicKeyModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
             .iter()
@@ -111,6 +110,7 @@ impl PublicKeyMode {
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         match variants[select_mode] {
@@ -122,9 +122,7 @@ impl PublicKeyMode {
 
     pub async fn process(self) -> crate::CliResult {
         match self {
-            PublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
-                cli_generate_keypair.process().await
-            }
+            PublicKeyMode::GenerateKeypair(generate_keypair) => generate_keypair.process().await,
         }
     }
 }
@@ -1,7 +1,9 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod access_key;
+mod account;
 mod contract_code;
 mod implicit_account;
 mod stake_proposal;
@@ -62,6 +64,8 @@ impl AddAction {
 
 #[derive(Debug, Clone, clap::Clap)]
 pub enum CliAction {
+    /// Add a new top-level account (e.g. alice.testnet) with the account helper or the top-level account contract
+    Account(self::account::operation_mode::CliOperationMode),
     /// Add a new contract code
     ContractCode(self::contract_code::operation_mode::CliOperationMode),
     /// Add an implicit-account
@@ -79,6 +83,8 @@ pub enum CliAction {
 pub enum Action {
     #[strum_discriminants(strum(message = "Add a new access key for an account"))]
     AccessKey(self::access_key::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Add a new top-level account"))]
+    Account(self::account::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Add a new contract code"))]
     ContractCode(self::contract_code::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "Add an implicit-account"))]
@@ -102,6 +108,11 @@ impl CliAction {
                 command.push_front("access-key".to_owned());
                 command
             }
+            Self::Account(subcommand) => {
+                let mut command = subcommand.to_cli_args();
+                command.push_front("account".to_owned());
+                command
+            }
             Self::ImplicitAccount(subcommand) => {
                 let mut command = subcommand.to_cli_args();
                 command.push_front("implicit-account".to_owned());
@@ -126,6 +137,7 @@ impl From<Action> for CliAction {
         match item {
             Action::ContractCode(operation_mode) => Self::ContractCode(operation_mode.into()),
             Action::AccessKey(operation_mode) => Self::AccessKey(operation_mode.into()),
+            Action::Account(operation_mode) => Self::Account(operation_mode.into()),
             Action::ImplicitAccount(implicit_account) => {
                 Self::ImplicitAccount(implicit_account.into())
             }
@@ -141,19 +153,20 @@ impl Action {
             CliAction::AccessKey(cli_operation_mode) => Ok(Action::AccessKey(
                 self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
+            CliAction::Account(cli_operation_mode) => Ok(Action::Account(
+                self::account::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
             CliAction::ContractCode(cli_operation_mode) => Ok(Action::ContractCode(
-                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliAction::ImplicitAccount(cli_generate_keypair) => Ok(Action::ImplicitAccount(
+                self::implicit_account::ImplicitAccount::from(cli_generate_keypair)?,
             )),
-            CliAction::ImplicitAccount(cli_generate_keypair) => {
-                Ok(Action::ImplicitAccount(cli_generate_keypair.into()))
-            }
             CliAction::StakeProposal(cli_operation_mode) => Ok(Action::StakeProposal(
-                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)
-                    .unwrap(),
+                self::stake_proposal::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
             CliAction::SubAccount(cli_operation_mode) => Ok(Action::SubAccount(
-                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::sub_account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -171,10 +184,12 @@ impl Action {
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_action = match variants[selected_action] {
             ActionDiscriminants::AccessKey => CliAction::AccessKey(Default::default()),
+            ActionDiscriminants::Account => CliAction::Account(Default::default()),
             ActionDiscriminants::ContractCode => CliAction::ContractCode(Default::default()),
             ActionDiscriminants::ImplicitAccount => CliAction::ImplicitAccount(Default::default()),
             ActionDiscriminants::StakeProposal => CliAction::StakeProposal(Default::default()),
@@ -193,6 +208,11 @@ impl Action {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Action::Account(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
             Action::ContractCode(operation_mode) => {
                 operation_mode
                     .process(prepopulated_unsigned_transaction)
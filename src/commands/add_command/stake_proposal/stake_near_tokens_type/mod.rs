@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -128,7 +129,7 @@ impl StakeNEARTokensAction {
     ) -> color_eyre::eyre::Result<Self> {
         let stake_amount: crate::common::NearBalance = match item.stake_amount {
             Some(cli_stake_amount) => cli_stake_amount,
-            None => StakeNEARTokensAction::input_stake_amount(),
+            None => StakeNEARTokensAction::input_stake_amount()?,
         };
         let sign_transactions = match item.sign_transactions {
             Some(cli_transaction_signing) => {
@@ -151,11 +152,12 @@ impl StakeNEARTokensAction {
 }
 
 impl StakeNEARTokensAction {
-    fn input_stake_amount() -> crate::common::NearBalance {
-        Input::new()
+    fn input_stake_amount() -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
             .with_prompt("How many NEAR Tokens do you want to stake? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .require_interactive("<stake-amount>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -141,7 +142,7 @@ impl TransactionsSigningAction {
         let transactions_signing_public_key: near_crypto::PublicKey =
             match item.transactions_signing_public_key {
                 Some(cli_transactions_signing_public_key) => cli_transactions_signing_public_key,
-                None => TransactionsSigningAction::input_public_key(),
+                None => TransactionsSigningAction::input_public_key()?,
             };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -155,11 +156,12 @@ impl TransactionsSigningAction {
 }
 
 impl TransactionsSigningAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this server")
+            .require_interactive("<transactions-signing-public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
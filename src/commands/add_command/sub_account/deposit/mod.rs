@@ -1,4 +1,5 @@
-use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
 pub enum CliDeposit {
@@ -131,10 +132,7 @@ impl TransferNEARTokensAction {
     ) -> color_eyre::eyre::Result<Self> {
         let amount: crate::common::TransferAmount = match item.amount {
             Some(cli_amount) => crate::common::TransferAmount::from_unchecked(cli_amount),
-            None => TransferNEARTokensAction::input_amount(
-                connection_config.clone(),
-                sender_account_id.clone(),
-            )?,
+            None => TransferNEARTokensAction::input_amount()?,
         };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
@@ -148,61 +146,13 @@ impl TransferNEARTokensAction {
 }
 
 impl TransferNEARTokensAction {
-    fn input_amount(
-        connection_config: Option<crate::common::ConnectionConfig>,
-        sender_account_id: near_primitives::types::AccountId,
-    ) -> color_eyre::eyre::Result<crate::common::TransferAmount> {
-        match connection_config {
-            Some(connection_config) => {
-                let account_transfer_allowance = crate::common::get_account_transfer_allowance(
-                    &connection_config,
-                    sender_account_id,
-                )?;
-                loop {
-                    let input_amount: crate::common::NearBalance = Input::new()
-                        .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
-                        .interact_text()
-                        .unwrap();
-                    if let Ok(transfer_amount) = crate::common::TransferAmount::from(
-                        input_amount.clone(),
-                        &account_transfer_allowance,
-                    ) {
-                        break Ok(transfer_amount);
-                    } else {
-                        println!(
-                            "\nWARNING! There is only {} available for transfer.",
-                            account_transfer_allowance.transfer_allowance()
-                        );
-                        let choose_input = vec![
-                            format!("Yes, I'd like to transfer {}.", input_amount),
-                            "No, I'd like to change the transfer amount.".to_string(),
-                        ];
-                        let select_choose_input = Select::with_theme(&ColorfulTheme::default())
-                            .with_prompt("Do you want to keep this amount for the transfer?")
-                            .items(&choose_input)
-                            .default(0)
-                            .interact_on_opt(&Term::stderr())
-                            .unwrap();
-                        match select_choose_input {
-                            Some(0) => {
-                                break Ok(crate::common::TransferAmount::from_unchecked(
-                                    input_amount,
-                                ))
-                            }
-                            Some(1) => {}
-                            _ => unreachable!("Error"),
-                        }
-                    }
-                }
-            }
-            None => {
-                let input_amount: crate::common::NearBalance = Input::new()
-                        .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
-                        .interact_text()
-                        .unwrap();
-                Ok(crate::common::TransferAmount::from_unchecked(input_amount))
-            }
-        }
+    fn input_amount() -> color_eyre::eyre::Result<crate::common::TransferAmount> {
+        let input_amount: crate::common::NearBalance = Input::new()
+            .with_prompt("How many NEAR Tokens do you want to transfer? (example: 10NEAR or 0.5near or 10000yoctonear)")
+            .require_interactive("<amount>")?
+            .interact_text()
+            .unwrap();
+        Ok(crate::common::TransferAmount::from_unchecked(input_amount))
     }
 
     pub async fn process(
@@ -210,6 +160,14 @@ impl TransferNEARTokensAction {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            self.amount
+                .check_transfer_allowance(
+                    connection_config,
+                    &prepopulated_unsigned_transaction.signer_id,
+                )
+                .await?;
+        }
         let action = near_primitives::transaction::Action::Transfer(
             near_primitives::transaction::TransferAction {
                 deposit: self.amount.to_yoctonear(),
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -71,6 +72,7 @@ impl FullAccessKey {
             .with_prompt("Сhoose what you want to add")
             .items(&actions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_action = match variants[selected_action] {
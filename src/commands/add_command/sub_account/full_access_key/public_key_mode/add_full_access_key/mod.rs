@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// Add full access key to the sub-account
@@ -56,7 +57,7 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let deposit = match item.deposit {
             Some(cli_deposit) => super::super::super::deposit::Deposit::from(
@@ -78,11 +79,12 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
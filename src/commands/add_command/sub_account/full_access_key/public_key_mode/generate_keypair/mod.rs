@@ -65,11 +65,12 @@ impl GenerateKeypair {
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
         let key_pair_properties: crate::common::KeyPairProperties =
-            crate::common::generate_keypair().await?;
+            crate::common::generate_keypair(near_crypto::KeyType::ED25519).await?;
         crate::common::save_access_key_to_keychain(
             network_connection_config.clone(),
             key_pair_properties.clone(),
             &prepopulated_unsigned_transaction.receiver_id.to_string(),
+            None,
         )
         .await
         .map_err(|err| {
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -121,6 +122,14 @@ impl Mode {
 
 impl Mode {
     fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        if let Some(is_online) = crate::network::preselected_online_mode() {
+            let cli_mode = if is_online {
+                CliMode::Network(Default::default())
+            } else {
+                CliMode::Offline(Default::default())
+            };
+            return Self::from(cli_mode);
+        }
         println!();
         let variants = ModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
@@ -134,6 +143,7 @@ impl Mode {
             )
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_mode = match variants[selected_mode] {
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -124,7 +125,7 @@ impl SubAccount {
     ) -> color_eyre::eyre::Result<Self> {
         let sub_account_id: near_primitives::types::AccountId = match item.sub_account_id {
             Some(cli_sub_account_id) => cli_sub_account_id,
-            None => SubAccount::input_sub_account_id(),
+            None => SubAccount::input_sub_account_id()?,
         };
         let full_access_key = match item.full_access_key {
             Some(cli_full_access_key) => super::full_access_key::FullAccessKey::from(
@@ -145,11 +146,12 @@ impl SubAccount {
 }
 
 impl SubAccount {
-    fn input_sub_account_id() -> near_primitives::types::AccountId {
-        Input::new()
+    fn input_sub_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
             .with_prompt("What is the sub-account ID?")
+            .require_interactive("<sub-account-id>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// данные об отправителе транзакции
@@ -47,22 +48,11 @@ impl Sender {
         item: CliSender,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Self> {
-        let owner_account_id: near_primitives::types::AccountId = match item.owner_account_id {
-            Some(cli_owner_account_id) => match &connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    network_connection_config,
-                    cli_owner_account_id.clone(),
-                )? {
-                    Some(_) => cli_owner_account_id,
-                    None => {
-                        println!("Account <{}> doesn't exist", cli_owner_account_id);
-                        Sender::input_owner_account_id(connection_config.clone())?
-                    }
-                },
-                None => cli_owner_account_id,
-            },
-            None => Sender::input_owner_account_id(connection_config.clone())?,
-        };
+        let owner_account_id: near_primitives::types::AccountId =
+            match item.owner_account_id.or_else(crate::network::signer) {
+                Some(cli_owner_account_id) => cli_owner_account_id,
+                None => Sender::input_owner_account_id()?,
+            };
         let send_to: super::receiver::SendTo = match item.send_to {
             Some(cli_send_to) => super::receiver::SendTo::from(
                 cli_send_to,
@@ -79,26 +69,12 @@ impl Sender {
 }
 
 impl Sender {
-    fn input_owner_account_id(
-        connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("What is the owner account ID?")
-                .interact_text()
-                .unwrap();
-            if let Some(connection_config) = &connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(connection_config, account_id.clone())?
-                {
-                    break Ok(account_id);
-                } else {
-                    println!("Account <{}> doesn't exist", account_id.to_string());
-                }
-            } else {
-                break Ok(account_id);
-            }
-        }
+    fn input_owner_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the owner account ID?")
+            .require_interactive("<owner-account-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
@@ -106,6 +82,10 @@ impl Sender {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_account_exists(connection_config, &self.sender_account_id)
+                .await?;
+        }
         let unsigned_transaction = near_primitives::transaction::Transaction {
             signer_id: self.owner_account_id.clone(),
             ..prepopulated_unsigned_transaction
@@ -0,0 +1,104 @@
+use std::convert::{TryFrom, TryInto};
+
+mod show;
+
+/// работа с настройками near-cli
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliConfig {
+    #[clap(subcommand)]
+    action: Option<CliConfigAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub action: ConfigAction,
+}
+
+impl CliConfig {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Config> for CliConfig {
+    fn from(config: Config) -> Self {
+        Self {
+            action: Some(config.action.into()),
+        }
+    }
+}
+
+impl TryFrom<CliConfig> for Config {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliConfig) -> Result<Self, Self::Error> {
+        let action = match item.action {
+            Some(cli_action) => ConfigAction::from(cli_action)?,
+            None => ConfigAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+}
+
+impl Config {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliConfigAction {
+    /// Show the effective defaults and where each of them comes from
+    Show(self::show::CliShow),
+}
+
+#[derive(Debug, Clone)]
+pub enum ConfigAction {
+    Show(self::show::Show),
+}
+
+impl CliConfigAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Show(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("show".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<ConfigAction> for CliConfigAction {
+    fn from(config_action: ConfigAction) -> Self {
+        match config_action {
+            ConfigAction::Show(show) => Self::Show(show.into()),
+        }
+    }
+}
+
+impl ConfigAction {
+    fn from(item: CliConfigAction) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
+            CliConfigAction::Show(cli_show) => Self::Show(cli_show.try_into()?),
+        })
+    }
+
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        Self::from(CliConfigAction::Show(Default::default()))
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Show(show) => show.process(),
+        }
+    }
+}
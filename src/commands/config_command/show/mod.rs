@@ -0,0 +1,89 @@
+use std::convert::TryFrom;
+
+/// вывод действующих настроек
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliShow {}
+
+#[derive(Debug, Clone)]
+pub struct Show {}
+
+impl CliShow {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::new()
+    }
+}
+
+impl From<Show> for CliShow {
+    fn from(_: Show) -> Self {
+        Self {}
+    }
+}
+
+impl TryFrom<CliShow> for Show {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(_: CliShow) -> Result<Self, Self::Error> {
+        Ok(Self {})
+    }
+}
+
+/// A setting with where its value comes from; a setting without a value is asked for by the commands
+fn print_setting(name: &str, setting: Option<(String, String)>) {
+    match setting {
+        Some((value, source)) => println!("{:<14} {:<40} (from {})", name, value, source),
+        None => println!("{:<14} {:<40} (asked for when needed)", name, "-"),
+    }
+}
+
+impl Show {
+    pub fn process(self) -> crate::CliResult {
+        let project_config = crate::project_config::get();
+        match &project_config.path {
+            Some(path) => println!("Project defaults: {}\n", path.display()),
+            None => println!(
+                "Project defaults: no {} in the working directory or its parents\n",
+                crate::consts::FILE_NAME_PROJECT_CONFIG
+            ),
+        }
+        let project_source = crate::project_config::source();
+        print_setting("network", crate::network::network_setting());
+        print_setting(
+            "signer",
+            crate::network::signer_setting().map(|(signer, source)| (signer.to_string(), source)),
+        );
+        print_setting(
+            "contract",
+            project_config
+                .contract
+                .map(|contract| (contract.to_string(), project_source.clone())),
+        );
+        print_setting(
+            "gas",
+            project_config
+                .gas
+                .map(|gas| (gas.to_string(), project_source.clone())),
+        );
+        print_setting(
+            "deposit",
+            project_config
+                .deposit
+                .map(|deposit| (deposit.to_string(), project_source.clone())),
+        );
+        print_setting(
+            "output_format",
+            Some(match project_config.output_format {
+                Some(output_format) => (output_format.to_string(), project_source),
+                None => (
+                    crate::common::OutputFormat::default().to_string(),
+                    "near-cli defaults".to_string(),
+                ),
+            }),
+        );
+        Ok(())
+    }
+}
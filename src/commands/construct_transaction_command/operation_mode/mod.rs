@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -12,27 +13,48 @@ mod online_mode;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliOperationMode {
+    /// Sign the actions as a delegate action (NEP-366) to be sent by a relayer instead of sending a transaction
+    /// (with a private key, the keychain or an external signer; Ledger and the wallet can't sign it)
+    #[clap(long)]
+    as_delegate: bool,
+    /// The block height the delegate action expires at (required in the offline mode)
+    #[clap(long)]
+    max_block_height: Option<near_primitives::types::BlockHeight>,
     #[clap(subcommand)]
     mode: Option<CliMode>,
 }
 
 #[derive(Debug, Clone)]
 pub struct OperationMode {
+    pub delegate_action_options: Option<crate::delegate_action::DelegateActionOptions>,
     pub mode: Mode,
 }
 
 impl CliOperationMode {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.mode
+        let mut args = self
+            .mode
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        if let Some(max_block_height) = &self.max_block_height {
+            args.push_front(max_block_height.to_string());
+            args.push_front("--max-block-height".to_owned())
+        }
+        if self.as_delegate {
+            args.push_front("--as-delegate".to_owned())
+        }
+        args
     }
 }
 
 impl From<OperationMode> for CliOperationMode {
     fn from(item: OperationMode) -> Self {
         Self {
+            as_delegate: item.delegate_action_options.is_some(),
+            max_block_height: item
+                .delegate_action_options
+                .and_then(|delegate_action_options| delegate_action_options.max_block_height),
             mode: Some(item.mode.into()),
         }
     }
@@ -40,11 +62,26 @@ impl From<OperationMode> for CliOperationMode {
 
 impl OperationMode {
     pub fn from(item: CliOperationMode) -> color_eyre::eyre::Result<Self> {
+        if item.max_block_height.is_some() && !item.as_delegate {
+            return Err(color_eyre::Report::msg(
+                "The option --max-block-height can only be used with --as-delegate",
+            ));
+        }
+        let delegate_action_options = if item.as_delegate {
+            Some(crate::delegate_action::DelegateActionOptions {
+                max_block_height: item.max_block_height,
+            })
+        } else {
+            None
+        };
         let mode = match item.mode {
             Some(cli_mode) => Mode::from(cli_mode)?,
             None => Mode::choose_mode()?,
         };
-        Ok(Self { mode })
+        Ok(Self {
+            delegate_action_options,
+            mode,
+        })
     }
 }
 
@@ -53,7 +90,12 @@ impl OperationMode {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
     ) -> crate::CliResult {
-        self.mode.process(prepopulated_unsigned_transaction).await
+        // The signing step at the end of the chain looks the options up; they are cleared
+        // afterwards so that the next command of a `run` plan sends its transaction as usual
+        crate::delegate_action::set_options(self.delegate_action_options);
+        let result = self.mode.process(prepopulated_unsigned_transaction).await;
+        crate::delegate_action::set_options(None);
+        result
     }
 }
 
@@ -121,6 +163,14 @@ impl Mode {
 
 impl Mode {
     fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        if let Some(is_online) = crate::network::preselected_online_mode() {
+            let cli_mode = if is_online {
+                CliMode::Network(Default::default())
+            } else {
+                CliMode::Offline(Default::default())
+            };
+            return Self::from(cli_mode);
+        }
         println!();
         let variants = ModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
@@ -134,6 +184,7 @@ impl Mode {
             )
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_mode = match variants[selected_mode] {
@@ -1,5 +1,3 @@
-pub mod select_server;
-
 /// аргументы, необходимые для создания транзакции в online mode
 #[derive(Debug, Default, Clone, clap::Clap)]
 #[clap(
@@ -8,41 +6,50 @@ pub mod select_server;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
     #[clap(subcommand)]
-    selected_server: Option<self::select_server::CliSelectServer>,
+    pub send_from: Option<CliSendFrom>,
 }
 
 #[derive(Debug, Clone)]
 pub struct NetworkArgs {
-    selected_server: self::select_server::SelectServer,
+    selected_server: crate::network::SelectServer,
+    pub send_from: SendFrom,
 }
 
 impl CliNetworkArgs {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.selected_server
+        let args = self
+            .send_from
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
     }
 }
 
 impl From<NetworkArgs> for CliNetworkArgs {
     fn from(network_args: NetworkArgs) -> Self {
         Self {
-            selected_server: Some(network_args.selected_server.into()),
+            selected_server: network_args.selected_server.into(),
+            send_from: Some(network_args.send_from.into()),
         }
     }
 }
 
 impl NetworkArgs {
     pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
-        let selected_server = match item.selected_server {
-            Some(cli_selected_server) => {
-                self::select_server::SelectServer::from(cli_selected_server)?
-            }
-            None => self::select_server::SelectServer::choose_server()?,
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let connection_config = selected_server.connection_config.clone();
+        let send_from = match item.send_from {
+            Some(cli_send_from) => SendFrom::from(cli_send_from, Some(connection_config.clone()))?,
+            None => SendFrom::choose_send_from(Some(connection_config.clone()))?,
         };
-        Ok(Self { selected_server })
+        Ok(Self {
+            selected_server,
+            send_from,
+        })
     }
 }
 
@@ -51,8 +58,84 @@ impl NetworkArgs {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
     ) -> crate::CliResult {
-        self.selected_server
-            .process(prepopulated_unsigned_transaction)
+        self.selected_server.verify().await?;
+        self.send_from
+            .process(
+                prepopulated_unsigned_transaction,
+                Some(self.selected_server.connection_config),
+            )
             .await
     }
 }
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSendFrom {
+    /// Specify a sender
+    Sender(crate::commands::construct_transaction_command::sender::CliSender),
+}
+
+#[derive(Debug, Clone)]
+pub enum SendFrom {
+    Sender(crate::commands::construct_transaction_command::sender::Sender),
+}
+
+impl CliSendFrom {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Sender(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sender".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<SendFrom> for CliSendFrom {
+    fn from(send_from: SendFrom) -> Self {
+        match send_from {
+            SendFrom::Sender(sender) => Self::Sender(sender.into()),
+        }
+    }
+}
+
+impl SendFrom {
+    pub fn from(
+        item: CliSendFrom,
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendFrom::Sender(cli_sender) => Ok(Self::Sender(
+                crate::commands::construct_transaction_command::sender::Sender::from(
+                    cli_sender,
+                    connection_config,
+                )?,
+            )),
+        }
+    }
+}
+
+impl SendFrom {
+    pub fn choose_send_from(
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self::from(
+            CliSendFrom::Sender(Default::default()),
+            connection_config,
+        )?)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> crate::CliResult {
+        match self {
+            SendFrom::Sender(sender) => {
+                sender
+                    .process(prepopulated_unsigned_transaction, connection_config)
+                    .await
+            }
+        }
+    }
+}
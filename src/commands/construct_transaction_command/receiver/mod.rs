@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -126,7 +127,7 @@ impl Receiver {
         let receiver_account_id: near_primitives::types::AccountId = match item.receiver_account_id
         {
             Some(cli_receiver_account_id) => cli_receiver_account_id,
-            None => Receiver::input_receiver_account_id(),
+            None => Receiver::input_receiver_account_id()?,
         };
         let action: super::transaction_actions::NextAction = match item.action {
             Some(cli_next_action) => super::transaction_actions::NextAction::from_cli_next_action(
@@ -147,11 +148,13 @@ impl Receiver {
 }
 
 impl Receiver {
-    pub fn input_receiver_account_id() -> near_primitives::types::AccountId {
-        Input::new()
+    pub fn input_receiver_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
+        Ok(Input::new()
             .with_prompt("What is the account ID of the receiver?")
+            .require_interactive("<receiver-account-id>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -164,7 +167,7 @@ impl Receiver {
             ..prepopulated_unsigned_transaction
         };
         self.action
-            .process(unsigned_transaction, network_connection_config)
+            .process(unsigned_transaction, network_connection_config, None)
             .await
     }
 }
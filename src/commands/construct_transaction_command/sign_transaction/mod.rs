@@ -1,11 +1,14 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use near_primitives::borsh::BorshSerialize;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod sign_manually;
+pub mod sign_with_external_signer;
 pub mod sign_with_keychain;
 pub mod sign_with_ledger;
 pub mod sign_with_private_key;
+pub mod sign_with_wallet;
 
 #[derive(Debug, Clone, clap::Clap)]
 pub enum CliSignTransaction {
@@ -15,6 +18,10 @@ pub enum CliSignTransaction {
     SignWithKeychain(self::sign_with_keychain::CliSignKeychain),
     /// Connect your Ledger device and sign transaction with it
     SignWithLedger(self::sign_with_ledger::CliSignLedger),
+    /// Send the transaction to an external signer (a command or a unix socket) and check its signature
+    SignWithExternalSigner(self::sign_with_external_signer::CliSignExternalSigner),
+    /// Approve the transaction in the web wallet, which signs and sends it
+    SignWithWallet(self::sign_with_wallet::CliSignWallet),
     /// Provide arguments to sign a manually transaction
     SignManually(self::sign_manually::CliSignManually),
 }
@@ -34,6 +41,14 @@ pub enum SignTransaction {
         message = "Yes, I want to sign the transaction with Ledger Nano S/X device"
     ))]
     SignWithLedger(self::sign_with_ledger::SignLedger),
+    #[strum_discriminants(strum(
+        message = "Yes, I want to sign the transaction with an external signer (a command or a unix socket)"
+    ))]
+    SignWithExternalSigner(self::sign_with_external_signer::SignExternalSigner),
+    #[strum_discriminants(strum(
+        message = "Yes, I want to approve the transaction in the web wallet (it signs and sends it)"
+    ))]
+    SignWithWallet(self::sign_with_wallet::SignWallet),
     #[strum_discriminants(strum(
         message = "No, I want to construct the transaction and sign it somewhere else"
     ))]
@@ -58,6 +73,16 @@ impl CliSignTransaction {
                 args.push_front("sign-with-ledger".to_owned());
                 args
             }
+            CliSignTransaction::SignWithExternalSigner(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-with-external-signer".to_owned());
+                args
+            }
+            CliSignTransaction::SignWithWallet(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-with-wallet".to_owned());
+                args
+            }
             CliSignTransaction::SignManually(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("sign-manually".to_owned());
@@ -79,6 +104,16 @@ impl From<SignTransaction> for CliSignTransaction {
             SignTransaction::SignWithLedger(sign_with_ledger) => Self::SignWithLedger(
                 self::sign_with_ledger::CliSignLedger::from(sign_with_ledger),
             ),
+            SignTransaction::SignWithExternalSigner(sign_with_external_signer) => {
+                Self::SignWithExternalSigner(
+                    self::sign_with_external_signer::CliSignExternalSigner::from(
+                        sign_with_external_signer,
+                    ),
+                )
+            }
+            SignTransaction::SignWithWallet(sign_with_wallet) => Self::SignWithWallet(
+                self::sign_with_wallet::CliSignWallet::from(sign_with_wallet),
+            ),
             SignTransaction::SignManually(sign_manually) => {
                 Self::SignManually(self::sign_manually::CliSignManually::from(sign_manually))
             }
@@ -97,7 +132,7 @@ impl SignTransaction {
                 let private_key = self::sign_with_private_key::SignPrivateKey::from(
                     cli_private_key,
                     connection_config,
-                );
+                )?;
                 Ok(SignTransaction::SignPrivateKey(private_key))
             }
             CliSignTransaction::SignWithKeychain(cli_key_chain) => {
@@ -109,13 +144,29 @@ impl SignTransaction {
                 Ok(SignTransaction::SignWithKeychain(key_chain))
             }
             CliSignTransaction::SignWithLedger(cli_ledger) => {
-                let ledger =
-                    self::sign_with_ledger::SignLedger::from(cli_ledger, connection_config)?;
+                let ledger = self::sign_with_ledger::SignLedger::from(
+                    cli_ledger,
+                    connection_config,
+                    sender_account_id,
+                )?;
                 Ok(SignTransaction::SignWithLedger(ledger))
             }
+            CliSignTransaction::SignWithExternalSigner(cli_external_signer) => {
+                let external_signer = self::sign_with_external_signer::SignExternalSigner::from(
+                    cli_external_signer,
+                    connection_config,
+                    sender_account_id,
+                )?;
+                Ok(SignTransaction::SignWithExternalSigner(external_signer))
+            }
+            CliSignTransaction::SignWithWallet(cli_wallet) => {
+                let wallet =
+                    self::sign_with_wallet::SignWallet::from(cli_wallet, connection_config)?;
+                Ok(SignTransaction::SignWithWallet(wallet))
+            }
             CliSignTransaction::SignManually(cli_manually) => {
                 let manually =
-                    self::sign_manually::SignManually::from(cli_manually, connection_config);
+                    self::sign_manually::SignManually::from(cli_manually, connection_config)?;
                 Ok(SignTransaction::SignManually(manually))
             }
         }
@@ -137,6 +188,7 @@ impl SignTransaction {
             .with_prompt("Would you like to sign the transaction?")
             .items(&sign_options)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_sign_option = match variants[select_sign_options] {
@@ -149,6 +201,12 @@ impl SignTransaction {
             SignTransactionDiscriminants::SignWithLedger => {
                 CliSignTransaction::SignWithLedger(Default::default())
             }
+            SignTransactionDiscriminants::SignWithExternalSigner => {
+                CliSignTransaction::SignWithExternalSigner(Default::default())
+            }
+            SignTransactionDiscriminants::SignWithWallet => {
+                CliSignTransaction::SignWithWallet(Default::default())
+            }
             SignTransactionDiscriminants::SignManually => {
                 CliSignTransaction::SignManually(Default::default())
             }
@@ -176,6 +234,16 @@ impl SignTransaction {
                     .process(prepopulated_unsigned_transaction, network_connection_config)
                     .await
             }
+            SignTransaction::SignWithExternalSigner(external_signer) => {
+                external_signer
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            SignTransaction::SignWithWallet(wallet) => {
+                wallet
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
             SignTransaction::SignManually(args_manually) => {
                 args_manually
                     .process(prepopulated_unsigned_transaction, network_connection_config)
@@ -185,23 +253,71 @@ impl SignTransaction {
     }
 }
 
-fn input_signer_public_key() -> near_crypto::PublicKey {
-    Input::new()
+impl SignTransaction {
+    /// Signs the transaction actions as a delegate action (`construct-transaction --as-delegate`).
+    /// The Ledger app and the wallet only sign transactions, so they can't sign it.
+    pub async fn sign_delegate_action(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+        delegate_action_options: crate::delegate_action::DelegateActionOptions,
+    ) -> color_eyre::eyre::Result<crate::delegate_action::SignedDelegateAction> {
+        let (signer_private_key, nonce) = match self {
+            SignTransaction::SignPrivateKey(keys) => (keys.signer_private_key, keys.nonce),
+            SignTransaction::SignWithKeychain(chain) => (
+                crate::common::get_secret_key_from_keychain(
+                    &prepopulated_unsigned_transaction.signer_id,
+                )?,
+                chain.nonce,
+            ),
+            SignTransaction::SignWithExternalSigner(external_signer) => {
+                let delegate_action = crate::delegate_action::build_delegate_action(
+                    prepopulated_unsigned_transaction,
+                    external_signer.signer_public_key.clone(),
+                    external_signer.nonce,
+                    delegate_action_options,
+                    network_connection_config,
+                )
+                .await?;
+                return external_signer
+                    .external_signer
+                    .sign_delegate_action(delegate_action);
+            }
+            _ => return Err(color_eyre::Report::msg(
+                "A delegate action can only be signed with a private key, the keychain or an external signer",
+            )),
+        };
+        let delegate_action = crate::delegate_action::build_delegate_action(
+            prepopulated_unsigned_transaction,
+            signer_private_key.public_key(),
+            nonce,
+            delegate_action_options,
+            network_connection_config,
+        )
+        .await?;
+        Ok(delegate_action.sign(&signer_private_key))
+    }
+}
+
+fn input_signer_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    Ok(Input::new()
         .with_prompt("Enter sender (signer) public key")
+        .require_interactive("--signer-public-key")?
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn input_signer_private_key() -> near_crypto::SecretKey {
-    Input::new()
+fn input_signer_private_key() -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    Ok(Input::new()
         .with_prompt("Enter sender (signer) private (secret) key")
+        .require_interactive("--signer-private-key")?
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn input_access_key_nonce(public_key: &str) -> u64 {
+fn input_access_key_nonce(public_key: &str) -> color_eyre::eyre::Result<u64> {
     println!("Your public key: `{}`", public_key);
-    Input::new()
+    Ok(Input::new()
         .with_prompt(
             "Enter transaction nonce for this public key (query the access key information with \
             `./near-cli view nonce \
@@ -209,19 +325,21 @@ fn input_access_key_nonce(public_key: &str) -> u64 {
                 account 'volodymyr.testnet' \
                 public-key ed25519:...` incremented by 1)",
         )
+        .require_interactive("--nonce")?
         .interact_text()
-        .unwrap()
+        .unwrap())
 }
 
-fn input_block_hash() -> near_primitives::hash::CryptoHash {
+fn input_block_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
     let input_block_hash: crate::common::BlockHashAsBase58 = Input::new()
         .with_prompt(
             "Enter recent block hash (query information about the hash of the last block with \
             `./near-cli view recent-block-hash network testnet`)",
         )
+        .require_interactive("--block-hash")?
         .interact_text()
         .unwrap();
-    input_block_hash.inner
+    Ok(input_block_hash.inner)
 }
 
 #[derive(Debug, EnumDiscriminants, Clone, clap::Clap)]
@@ -251,9 +369,11 @@ impl Submit {
         }
     }
 
-    pub fn choose_submit(connection_config: Option<crate::common::ConnectionConfig>) -> Self {
+    pub fn choose_submit(
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Self> {
         if connection_config.is_none() {
-            return Submit::Display;
+            return Ok(Submit::Display);
         }
         println!();
 
@@ -266,19 +386,28 @@ impl Submit {
             .with_prompt("How would you like to proceed")
             .items(&submits)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
-        match variants[select_submit] {
+        Ok(match variants[select_submit] {
             SubmitDiscriminants::Send => Submit::Send,
             SubmitDiscriminants::Display => Submit::Display,
-        }
+        })
     }
 
     pub fn process_offline(
         self,
+        signed_transaction: near_primitives::transaction::SignedTransaction,
         serialize_to_base64: String,
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         println!("Serialize_to_base64:\n{}", &serialize_to_base64);
+        crate::journal::record_transaction(
+            crate::journal::JournalStatus::Signed,
+            None,
+            &signed_transaction.transaction,
+            &serialize_to_base64,
+            None,
+        );
         Ok(None)
     }
 
@@ -291,8 +420,7 @@ impl Submit {
         match self {
             Submit::Send => {
                 println!("Transaction sent ...");
-                let json_rcp_client =
-                    near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+                let json_rcp_client = crate::rpc::RpcClient::new(&network_connection_config);
                 let transaction_info = loop {
                     let transaction_info_result = json_rcp_client
                         .broadcast_tx_commit(near_primitives::serialize::to_base64(
@@ -330,14 +458,43 @@ impl Submit {
                                 }
                                 _ => println!("Unexpected response: {:#?}", err),
                             }
+                            crate::common::record_command_output("error", format!("{:?}", err));
+                            crate::journal::record_transaction(
+                                crate::journal::JournalStatus::Failed,
+                                Some(&network_connection_config),
+                                &signed_transaction.transaction,
+                                &serialize_to_base64,
+                                Some(format!("{:?}", err)),
+                            );
                             return Ok(None);
                         }
                     };
                 };
+                let (status, error) = match &transaction_info.status {
+                    near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => (
+                        crate::journal::JournalStatus::Failed,
+                        Some(format!("{:?}", tx_execution_error)),
+                    ),
+                    _ => (crate::journal::JournalStatus::Succeeded, None),
+                };
+                crate::journal::record_transaction(
+                    status,
+                    Some(&network_connection_config),
+                    &signed_transaction.transaction,
+                    &serialize_to_base64,
+                    error,
+                );
                 Ok(Some(transaction_info))
             }
             Submit::Display => {
                 println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+                crate::journal::record_transaction(
+                    crate::journal::JournalStatus::Signed,
+                    Some(&network_connection_config),
+                    &signed_transaction.transaction,
+                    &serialize_to_base64,
+                    None,
+                );
                 Ok(None)
             }
         }
@@ -56,12 +56,12 @@ impl SignManually {
     pub fn from(
         item: CliSignManually,
         connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> Self {
+    ) -> color_eyre::eyre::Result<Self> {
         let signer_public_key: near_crypto::PublicKey = match item.signer_public_key {
             Some(cli_public_key) => cli_public_key,
-            None => super::input_signer_public_key(),
+            None => super::input_signer_public_key()?,
         };
-        match connection_config {
+        Ok(match connection_config {
             Some(_) => Self {
                 signer_public_key,
                 nonce: None,
@@ -70,11 +70,11 @@ impl SignManually {
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 Self {
                     signer_public_key,
@@ -82,15 +82,11 @@ impl SignManually {
                     block_hash: Some(block_hash),
                 }
             }
-        }
+        })
     }
 }
 
 impl SignManually {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -98,7 +94,7 @@ impl SignManually {
     ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
         let public_key: near_crypto::PublicKey = self.signer_public_key.clone();
 
-        let unsigned_transaction = match network_connection_config {
+        let unsigned_transaction = match network_connection_config.clone() {
             None => near_primitives::transaction::Transaction {
                 public_key,
                 nonce: self.nonce.unwrap_or_default().clone(),
@@ -106,22 +102,22 @@ impl SignManually {
                 ..prepopulated_unsigned_transaction
             },
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
-                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                        block_reference: near_primitives::types::Finality::Final.into(),
-                        request: near_primitives::views::QueryRequest::ViewAccessKey {
-                            account_id: prepopulated_unsigned_transaction.signer_id.clone(),
-                            public_key: public_key.clone(),
-                        },
-                    })
-                    .await
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!(
-                            "Failed to fetch public key information for nonce: {:?}",
-                            err
-                        ))
-                    })?;
+                let online_signer_access_key_response =
+                    crate::rpc::RpcClient::new(&network_connection_config)
+                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                            block_reference: near_primitives::types::Finality::Final.into(),
+                            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                public_key: public_key.clone(),
+                            },
+                        })
+                        .await
+                        .map_err(|err| {
+                            color_eyre::Report::msg(format!(
+                                "Failed to fetch public key information for nonce: {:?}",
+                                err
+                            ))
+                        })?;
                 let current_nonce =
                     if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
                         online_signer_access_key,
@@ -131,10 +127,15 @@ impl SignManually {
                     } else {
                         return Err(color_eyre::Report::msg(format!("Error current_nonce")));
                     };
+                let nonce = crate::common::next_nonce(
+                    &prepopulated_unsigned_transaction.signer_id,
+                    &public_key,
+                    current_nonce,
+                );
                 near_primitives::transaction::Transaction {
                     public_key,
                     block_hash: online_signer_access_key_response.block_hash,
-                    nonce: current_nonce + 1,
+                    nonce,
                     ..prepopulated_unsigned_transaction
                 }
             }
@@ -149,6 +150,13 @@ impl SignManually {
                 .expect("Transaction is not expected to fail on serialization"),
         );
         println!("\nSerialize_to_base64:\n{}", &serialize_to_base64);
+        crate::journal::record_transaction(
+            crate::journal::JournalStatus::Constructed,
+            network_connection_config.as_ref(),
+            &unsigned_transaction,
+            &serialize_to_base64,
+            None,
+        );
         Ok(None)
     }
 }
@@ -0,0 +1,264 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+
+/// Sign constructed transaction with an external signer (a command or a unix socket)
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSignExternalSigner {
+    /// The executable that gets the JSON request on stdin and prints the JSON response
+    #[clap(long)]
+    signer_command: Option<std::path::PathBuf>,
+    /// The unix socket the external signer listens on
+    #[clap(long)]
+    signer_socket: Option<std::path::PathBuf>,
+    /// The access key to sign with (asked from the external signer if not given)
+    #[clap(long)]
+    signer_public_key: Option<near_crypto::PublicKey>,
+    #[clap(long)]
+    nonce: Option<u64>,
+    #[clap(long)]
+    block_hash: Option<near_primitives::hash::CryptoHash>,
+    #[clap(subcommand)]
+    submit: Option<super::Submit>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignExternalSigner {
+    pub external_signer: crate::external_signer::ExternalSigner,
+    pub signer_public_key: near_crypto::PublicKey,
+    pub nonce: Option<u64>,
+    block_hash: Option<near_primitives::hash::CryptoHash>,
+    pub submit: Option<super::Submit>,
+}
+
+impl CliSignExternalSigner {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .submit
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(block_hash) = &self.block_hash {
+            args.push_front(block_hash.to_string());
+            args.push_front("--block-hash".to_owned())
+        }
+        if let Some(nonce) = &self.nonce {
+            args.push_front(nonce.to_string());
+            args.push_front("--nonce".to_owned())
+        }
+        if let Some(signer_public_key) = &self.signer_public_key {
+            args.push_front(signer_public_key.to_string());
+            args.push_front("--signer-public-key".to_owned())
+        }
+        if let Some(path) = &self.signer_socket {
+            args.push_front(path.display().to_string());
+            args.push_front("--signer-socket".to_owned())
+        }
+        if let Some(path) = &self.signer_command {
+            args.push_front(path.display().to_string());
+            args.push_front("--signer-command".to_owned())
+        }
+        args
+    }
+}
+
+impl From<SignExternalSigner> for CliSignExternalSigner {
+    fn from(sign_external_signer: SignExternalSigner) -> Self {
+        let (signer_command, signer_socket) = match sign_external_signer.external_signer {
+            crate::external_signer::ExternalSigner::Command(path) => (Some(path), None),
+            crate::external_signer::ExternalSigner::Socket(path) => (None, Some(path)),
+        };
+        Self {
+            signer_command,
+            signer_socket,
+            signer_public_key: Some(sign_external_signer.signer_public_key),
+            nonce: sign_external_signer.nonce,
+            block_hash: sign_external_signer.block_hash,
+            submit: sign_external_signer.submit,
+        }
+    }
+}
+
+impl SignExternalSigner {
+    pub fn from(
+        item: CliSignExternalSigner,
+        connection_config: Option<crate::common::ConnectionConfig>,
+        sender_account_id: near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        let external_signer = match (item.signer_command, item.signer_socket) {
+            (Some(_), Some(_)) => {
+                return Err(color_eyre::Report::msg(
+                    "The options --signer-command and --signer-socket can't be used together",
+                ))
+            }
+            (Some(path), None) => crate::external_signer::ExternalSigner::Command(path),
+            (None, Some(path)) => crate::external_signer::ExternalSigner::Socket(path),
+            (None, None) => SignExternalSigner::input_external_signer()?,
+        };
+        let signer_public_key = match item.signer_public_key {
+            Some(signer_public_key) => signer_public_key,
+            None => {
+                let signer_public_key = external_signer.get_public_key(&sender_account_id)?;
+                println!(
+                    "The external signer uses the key {} for <{}>",
+                    signer_public_key, sender_account_id
+                );
+                signer_public_key
+            }
+        };
+        let submit: Option<super::Submit> = item.submit;
+        match connection_config {
+            Some(_) => Ok(Self {
+                external_signer,
+                signer_public_key,
+                nonce: None,
+                block_hash: None,
+                submit,
+            }),
+            None => {
+                let nonce: u64 = match item.nonce {
+                    Some(cli_nonce) => cli_nonce,
+                    None => super::input_access_key_nonce(&signer_public_key.to_string())?,
+                };
+                let block_hash = match item.block_hash {
+                    Some(cli_block_hash) => cli_block_hash,
+                    None => super::input_block_hash()?,
+                };
+                Ok(Self {
+                    external_signer,
+                    signer_public_key,
+                    nonce: Some(nonce),
+                    block_hash: Some(block_hash),
+                    submit,
+                })
+            }
+        }
+    }
+}
+
+impl SignExternalSigner {
+    fn input_external_signer() -> color_eyre::eyre::Result<crate::external_signer::ExternalSigner> {
+        let path: String = Input::new()
+            .with_prompt("Enter the path to the external signer command")
+            .require_interactive("--signer-command")?
+            .interact_text()
+            .unwrap();
+        Ok(crate::external_signer::ExternalSigner::Command(path.into()))
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
+        let public_key = self.signer_public_key.clone();
+        let nonce = self.nonce.unwrap_or_default();
+        let block_hash = self.block_hash.unwrap_or_default();
+        let submit: Option<super::Submit> = self.submit.clone();
+        match connection_config.clone() {
+            None => {
+                let unsigned_transaction = near_primitives::transaction::Transaction {
+                    public_key,
+                    nonce,
+                    block_hash,
+                    ..prepopulated_unsigned_transaction
+                };
+                println!("\nUnsigned transaction:\n");
+                crate::common::print_transaction(unsigned_transaction.clone());
+                println!("Sending the transaction to {} ...", self.external_signer);
+                let signed_transaction = self
+                    .external_signer
+                    .sign_transaction(unsigned_transaction)?;
+                let serialize_to_base64 = near_primitives::serialize::to_base64(
+                    signed_transaction
+                        .try_to_vec()
+                        .expect("Transaction is not expected to fail on serialization"),
+                );
+                println!("Your transaction was signed successfully.");
+                match submit {
+                    Some(submit) => submit.process_offline(signed_transaction, serialize_to_base64),
+                    None => {
+                        let submit = super::Submit::choose_submit(connection_config.clone())?;
+                        submit.process_offline(signed_transaction, serialize_to_base64)
+                    }
+                }
+            }
+            Some(network_connection_config) => {
+                let online_signer_access_key_response =
+                    crate::rpc::RpcClient::new(&network_connection_config)
+                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                            block_reference: near_primitives::types::Finality::Final.into(),
+                            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                public_key: public_key.clone(),
+                            },
+                        })
+                        .await
+                        .map_err(|err| {
+                            color_eyre::Report::msg(format!(
+                                "Failed to fetch public key information for nonce: {:?}",
+                                err
+                            ))
+                        })?;
+                let current_nonce =
+                    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                        online_signer_access_key,
+                    ) = online_signer_access_key_response.kind
+                    {
+                        online_signer_access_key.nonce
+                    } else {
+                        return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+                    };
+                let nonce = crate::common::next_nonce(
+                    &prepopulated_unsigned_transaction.signer_id,
+                    &public_key,
+                    current_nonce,
+                );
+                let unsigned_transaction = near_primitives::transaction::Transaction {
+                    public_key,
+                    block_hash: online_signer_access_key_response.block_hash,
+                    nonce,
+                    ..prepopulated_unsigned_transaction
+                };
+                println!("\nUnsigned transaction:\n");
+                crate::common::print_transaction(unsigned_transaction.clone());
+                println!("Sending the transaction to {} ...", self.external_signer);
+                let signed_transaction = self
+                    .external_signer
+                    .sign_transaction(unsigned_transaction)?;
+                let serialize_to_base64 = near_primitives::serialize::to_base64(
+                    signed_transaction
+                        .try_to_vec()
+                        .expect("Transaction is not expected to fail on serialization"),
+                );
+                println!("Your transaction was signed successfully.");
+                match submit {
+                    None => {
+                        let submit = super::Submit::choose_submit(connection_config)?;
+                        submit
+                            .process_online(
+                                network_connection_config,
+                                signed_transaction,
+                                serialize_to_base64,
+                            )
+                            .await
+                    }
+                    Some(submit) => {
+                        submit
+                            .process_online(
+                                network_connection_config,
+                                signed_transaction,
+                                serialize_to_base64,
+                            )
+                            .await
+                    }
+                }
+            }
+        }
+    }
+}
@@ -20,7 +20,7 @@ pub struct CliSignKeychain {
 
 #[derive(Debug, Clone)]
 pub struct SignKeychain {
-    nonce: Option<u64>,
+    pub nonce: Option<u64>,
     block_hash: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::Submit>,
 }
@@ -86,11 +86,11 @@ impl SignKeychain {
 
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&account_json.public_key.to_string()),
+                    None => super::input_access_key_nonce(&account_json.public_key.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 Ok(SignKeychain {
                     nonce: Some(nonce),
@@ -110,10 +110,6 @@ struct User {
 }
 
 impl SignKeychain {
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -138,21 +134,21 @@ impl SignKeychain {
                 if path.exists() {
                     path
                 } else {
-                    let query_view_method_response = self
-                        .rpc_client(network_connection_config.rpc_url().as_str())
-                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                            block_reference: near_primitives::types::Finality::Final.into(),
-                            request: near_primitives::views::QueryRequest::ViewAccessKeyList {
-                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
-                            },
-                        })
-                        .await
-                        .map_err(|err| {
-                            color_eyre::Report::msg(format!(
-                                "Failed to fetch query for view key list: {:?}",
-                                err
-                            ))
-                        })?;
+                    let query_view_method_response =
+                        crate::rpc::RpcClient::new(&network_connection_config)
+                            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                                block_reference: near_primitives::types::Finality::Final.into(),
+                                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                                    account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                },
+                            })
+                            .await
+                            .map_err(|err| {
+                                color_eyre::Report::msg(format!(
+                                    "Failed to fetch query for view key list: {:?}",
+                                    err
+                                ))
+                            })?;
                     let access_key_view =
                         if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
                             result,
@@ -168,14 +164,10 @@ impl SignKeychain {
                     let mut data_path = std::path::PathBuf::new();
                     'outer: for access_key in access_key_view.keys {
                         let account_public_key = access_key.public_key.to_string();
-                        let is_full_access_key: bool = match &access_key.access_key.permission {
-                            near_primitives::views::AccessKeyPermissionView::FullAccess => true,
-                            near_primitives::views::AccessKeyPermissionView::FunctionCall {
-                                allowance: _,
-                                receiver_id: _,
-                                method_names: _,
-                            } => false,
-                        };
+                        let is_suitable_key: bool = crate::common::is_access_key_suitable(
+                            &access_key.access_key.permission,
+                            &prepopulated_unsigned_transaction,
+                        );
                         let dir = path
                             .read_dir()
                             .map_err(|err| {
@@ -190,7 +182,7 @@ impl SignKeychain {
                                     .to_str()
                                     .unwrap()
                                     .contains(account_public_key.rsplit(':').next().unwrap())
-                                    && is_full_access_key
+                                    && is_suitable_key
                                 {
                                     data_path.push(entry.path());
                                     break 'outer;
@@ -214,6 +206,7 @@ impl SignKeychain {
         let sign_with_private_key = super::sign_with_private_key::SignPrivateKey {
             signer_public_key: account_json.public_key,
             signer_private_key: account_json.private_key,
+            signer_private_key_source: crate::common::SecretKeySource::Inline,
             nonce: self.nonce.clone(),
             block_hash: self.block_hash.clone(),
             submit: self.submit.clone(),
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use near_primitives::borsh::BorshSerialize;
 
@@ -62,23 +63,34 @@ impl SignLedger {
     pub fn from(
         item: CliSignLedger,
         connection_config: Option<crate::common::ConnectionConfig>,
+        sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
         let seed_phrase_hd_path = match item.seed_phrase_hd_path {
             Some(hd_path) => hd_path,
-            None => SignLedger::input_seed_phrase_hd_path(),
+            None => match crate::config::get_saved_ledger_hd_path(&sender_account_id) {
+                Some(hd_path) => {
+                    println!(
+                        "Using the HD Path {} saved for <{}>",
+                        hd_path, sender_account_id
+                    );
+                    hd_path
+                }
+                None => SignLedger::input_seed_phrase_hd_path()?,
+            },
         };
         println!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
             seed_phrase_hd_path
         );
-        let public_key = actix::System::new()
-            .block_on(async { near_ledger::get_public_key(seed_phrase_hd_path.clone()).await })
-            .map_err(|near_ledger_error| {
-                color_eyre::Report::msg(format!(
-                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
-                    near_ledger_error
-                ))
-            })?;
+        let public_key = crate::common::block_on(async {
+            near_ledger::get_public_key(seed_phrase_hd_path.clone()).await
+        })
+        .map_err(|near_ledger_error| {
+            color_eyre::Report::msg(format!(
+                "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                near_ledger_error
+            ))
+        })?;
         let signer_public_key = near_crypto::PublicKey::ED25519(
             near_crypto::ED25519PublicKey::from(public_key.to_bytes()),
         );
@@ -94,11 +106,11 @@ impl SignLedger {
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string().clone()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string().clone())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 Ok(Self {
                     seed_phrase_hd_path,
@@ -113,16 +125,13 @@ impl SignLedger {
 }
 
 impl SignLedger {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
-    pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
-        Input::new()
+    pub fn input_seed_phrase_hd_path() -> color_eyre::eyre::Result<slip10::BIP32Path> {
+        Ok(Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
-            .with_initial_text("44'/397'/0'/0'/1'")
+            .with_initial_text(crate::consts::DEFAULT_LEDGER_HD_PATH)
+            .require_interactive("--seed-phrase-hd-path")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -181,30 +190,30 @@ impl SignLedger {
                 );
                 println!("Your transaction was signed successfully.");
                 match submit {
-                    Some(submit) => submit.process_offline(serialize_to_base64),
+                    Some(submit) => submit.process_offline(signed_transaction, serialize_to_base64),
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config.clone());
-                        submit.process_offline(serialize_to_base64)
+                        let submit = super::Submit::choose_submit(connection_config.clone())?;
+                        submit.process_offline(signed_transaction, serialize_to_base64)
                     }
                 }
             }
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
-                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                        block_reference: near_primitives::types::Finality::Final.into(),
-                        request: near_primitives::views::QueryRequest::ViewAccessKey {
-                            account_id: prepopulated_unsigned_transaction.signer_id.clone(),
-                            public_key: public_key.clone(),
-                        },
-                    })
-                    .await
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!(
-                            "Failed to fetch public key information for nonce: {:?}",
-                            err
-                        ))
-                    })?;
+                let online_signer_access_key_response =
+                    crate::rpc::RpcClient::new(&network_connection_config)
+                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                            block_reference: near_primitives::types::Finality::Final.into(),
+                            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                public_key: public_key.clone(),
+                            },
+                        })
+                        .await
+                        .map_err(|err| {
+                            color_eyre::Report::msg(format!(
+                                "Failed to fetch public key information for nonce: {:?}",
+                                err
+                            ))
+                        })?;
                 let current_nonce =
                     if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
                         online_signer_access_key,
@@ -214,10 +223,15 @@ impl SignLedger {
                     } else {
                         return Err(color_eyre::Report::msg(format!("Error current_nonce")));
                     };
+                let nonce = crate::common::next_nonce(
+                    &prepopulated_unsigned_transaction.signer_id,
+                    &public_key,
+                    current_nonce,
+                );
                 let unsigned_transaction = near_primitives::transaction::Transaction {
                     public_key,
                     block_hash: online_signer_access_key_response.block_hash,
-                    nonce: current_nonce + 1,
+                    nonce,
                     ..prepopulated_unsigned_transaction
                 };
                 println!("\nUnsigned transaction:\n");
@@ -259,7 +273,7 @@ impl SignLedger {
                 println!("Your transaction was signed successfully.");
                 match submit {
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config);
+                        let submit = super::Submit::choose_submit(connection_config)?;
                         submit
                             .process_online(
                                 network_connection_config,
@@ -10,8 +10,18 @@ use near_primitives::borsh::BorshSerialize;
 pub struct CliSignPrivateKey {
     #[clap(long)]
     signer_public_key: Option<near_crypto::PublicKey>,
+    /// The private key (it stays in the shell history, prefer the options below)
     #[clap(long)]
     signer_private_key: Option<near_crypto::SecretKey>,
+    /// Read the private key from the file (the key itself or a keychain access key file)
+    #[clap(long)]
+    signer_private_key_file: Option<std::path::PathBuf>,
+    /// Read the private key from the environment variable
+    #[clap(long)]
+    signer_private_key_env: Option<String>,
+    /// Read the private key from the first line of stdin
+    #[clap(long)]
+    signer_private_key_stdin: bool,
     #[clap(long)]
     nonce: Option<u64>,
     #[clap(long)]
@@ -24,6 +34,7 @@ pub struct CliSignPrivateKey {
 pub struct SignPrivateKey {
     pub signer_public_key: near_crypto::PublicKey,
     pub signer_private_key: near_crypto::SecretKey,
+    pub signer_private_key_source: crate::common::SecretKeySource,
     pub nonce: Option<u64>,
     pub block_hash: Option<near_primitives::hash::CryptoHash>,
     pub submit: Option<super::Submit>,
@@ -44,8 +55,19 @@ impl CliSignPrivateKey {
             args.push_front(nonce.to_string());
             args.push_front("--nonce".to_owned())
         }
-        if let Some(signer_secret_key) = &self.signer_private_key {
-            args.push_front(signer_secret_key.to_string());
+        if self.signer_private_key_stdin {
+            args.push_front("--signer-private-key-stdin".to_owned())
+        }
+        if let Some(var) = &self.signer_private_key_env {
+            args.push_front(var.clone());
+            args.push_front("--signer-private-key-env".to_owned())
+        }
+        if let Some(path) = &self.signer_private_key_file {
+            args.push_front(path.display().to_string());
+            args.push_front("--signer-private-key-file".to_owned())
+        }
+        if self.signer_private_key.is_some() {
+            args.push_front(crate::consts::REDACTED_SECRET.to_owned());
             args.push_front("--signer-private-key".to_owned())
         }
         if let Some(signer_public_key) = &self.signer_public_key {
@@ -58,13 +80,31 @@ impl CliSignPrivateKey {
 
 impl From<SignPrivateKey> for CliSignPrivateKey {
     fn from(sign_private_key: SignPrivateKey) -> Self {
-        Self {
+        let mut cli_sign_private_key = Self {
             signer_public_key: Some(sign_private_key.signer_public_key),
-            signer_private_key: Some(sign_private_key.signer_private_key),
+            signer_private_key: None,
+            signer_private_key_file: None,
+            signer_private_key_env: None,
+            signer_private_key_stdin: false,
             nonce: sign_private_key.nonce,
             block_hash: sign_private_key.block_hash,
             submit: sign_private_key.submit,
+        };
+        match sign_private_key.signer_private_key_source {
+            crate::common::SecretKeySource::Inline => {
+                cli_sign_private_key.signer_private_key = Some(sign_private_key.signer_private_key)
+            }
+            crate::common::SecretKeySource::File(path) => {
+                cli_sign_private_key.signer_private_key_file = Some(path)
+            }
+            crate::common::SecretKeySource::Env(var) => {
+                cli_sign_private_key.signer_private_key_env = Some(var)
+            }
+            crate::common::SecretKeySource::Stdin => {
+                cli_sign_private_key.signer_private_key_stdin = true
+            }
         }
+        cli_sign_private_key
     }
 }
 
@@ -72,53 +112,66 @@ impl SignPrivateKey {
     pub fn from(
         item: CliSignPrivateKey,
         connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> Self {
+    ) -> color_eyre::eyre::Result<Self> {
         let signer_public_key: near_crypto::PublicKey = match item.signer_public_key {
             Some(cli_public_key) => cli_public_key,
-            None => super::input_signer_public_key(),
+            None => super::input_signer_public_key()?,
         };
-        let signer_private_key: near_crypto::SecretKey = match item.signer_private_key {
+        let (signer_private_key, signer_private_key_source) = match crate::common::read_secret_key(
+            item.signer_private_key,
+            item.signer_private_key_file,
+            item.signer_private_key_env,
+            item.signer_private_key_stdin,
+        )? {
             Some(signer_private_key) => signer_private_key,
-            None => super::input_signer_private_key(),
+            None => (
+                super::input_signer_private_key()?,
+                crate::common::SecretKeySource::Inline,
+            ),
         };
         let submit: Option<super::Submit> = item.submit;
         match connection_config {
-            Some(_) => Self {
+            Some(_) => Ok(Self {
                 signer_public_key,
                 signer_private_key,
+                signer_private_key_source,
                 nonce: None,
                 block_hash: None,
                 submit,
-            },
+            }),
             None => {
                 let nonce: u64 = match item.nonce {
                     Some(cli_nonce) => cli_nonce,
-                    None => super::input_access_key_nonce(&signer_public_key.to_string()),
+                    None => super::input_access_key_nonce(&signer_public_key.to_string())?,
                 };
                 let block_hash = match item.block_hash {
                     Some(cli_block_hash) => cli_block_hash,
-                    None => super::input_block_hash(),
+                    None => super::input_block_hash()?,
                 };
                 let public_key_origin: near_crypto::PublicKey =
                     near_crypto::SecretKey::public_key(&signer_private_key);
                 if &signer_public_key == &public_key_origin {
-                    Self {
+                    Ok(Self {
                         signer_public_key,
                         signer_private_key,
+                        signer_private_key_source,
                         nonce: Some(nonce),
                         block_hash: Some(block_hash),
                         submit,
-                    }
+                    })
                 } else {
                     println!("\nError: The key pair does not match. Re-enter the keys.\n");
                     let signer_public_key: near_crypto::PublicKey =
-                        super::input_signer_public_key();
+                        super::input_signer_public_key()?;
                     let signer_secret_key: near_crypto::SecretKey =
-                        super::input_signer_private_key();
+                        super::input_signer_private_key()?;
                     Self::from(
                         CliSignPrivateKey {
                             signer_public_key: Some(signer_public_key),
                             signer_private_key: Some(signer_secret_key),
+                            signer_private_key_file: None,
+                            signer_private_key_env: None,
+                            signer_private_key_stdin: false,
                             nonce: Some(nonce),
                             block_hash: Some(block_hash),
                             submit: None,
@@ -132,10 +185,6 @@ impl SignPrivateKey {
 }
 
 impl SignPrivateKey {
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     pub async fn process(
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
@@ -170,30 +219,30 @@ impl SignPrivateKey {
                 crate::common::print_transaction(signed_transaction.transaction.clone());
                 println!("Your transaction was signed successfully.");
                 match submit {
-                    Some(submit) => submit.process_offline(serialize_to_base64),
+                    Some(submit) => submit.process_offline(signed_transaction, serialize_to_base64),
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config.clone());
-                        submit.process_offline(serialize_to_base64)
+                        let submit = super::Submit::choose_submit(connection_config.clone())?;
+                        submit.process_offline(signed_transaction, serialize_to_base64)
                     }
                 }
             }
             Some(network_connection_config) => {
-                let online_signer_access_key_response = self
-                    .rpc_client(network_connection_config.rpc_url().as_str())
-                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                        block_reference: near_primitives::types::Finality::Final.into(),
-                        request: near_primitives::views::QueryRequest::ViewAccessKey {
-                            account_id: prepopulated_unsigned_transaction.signer_id.clone(),
-                            public_key: public_key.clone(),
-                        },
-                    })
-                    .await
-                    .map_err(|err| {
-                        color_eyre::Report::msg(format!(
-                            "Failed to fetch public key information for nonce: {:?}",
-                            err
-                        ))
-                    })?;
+                let online_signer_access_key_response =
+                    crate::rpc::RpcClient::new(&network_connection_config)
+                        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                            block_reference: near_primitives::types::Finality::Final.into(),
+                            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                                account_id: prepopulated_unsigned_transaction.signer_id.clone(),
+                                public_key: public_key.clone(),
+                            },
+                        })
+                        .await
+                        .map_err(|err| {
+                            color_eyre::Report::msg(format!(
+                                "Failed to fetch public key information for nonce: {:?}",
+                                err
+                            ))
+                        })?;
                 let current_nonce =
                     if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
                         online_signer_access_key,
@@ -203,10 +252,15 @@ impl SignPrivateKey {
                     } else {
                         return Err(color_eyre::Report::msg(format!("Error current_nonce")));
                     };
+                let nonce = crate::common::next_nonce(
+                    &prepopulated_unsigned_transaction.signer_id,
+                    &public_key,
+                    current_nonce,
+                );
                 let unsigned_transaction = near_primitives::transaction::Transaction {
                     public_key,
                     block_hash: online_signer_access_key_response.block_hash,
-                    nonce: current_nonce + 1,
+                    nonce,
                     ..prepopulated_unsigned_transaction
                 };
                 let signature =
@@ -225,7 +279,7 @@ impl SignPrivateKey {
                 println!("Your transaction was signed successfully.");
                 match submit {
                     None => {
-                        let submit = super::Submit::choose_submit(connection_config);
+                        let submit = super::Submit::choose_submit(connection_config)?;
                         submit
                             .process_online(
                                 network_connection_config,
@@ -0,0 +1,259 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+use url_open::UrlOpen;
+
+/// Sign constructed transaction in the web wallet
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliSignWallet {}
+
+#[derive(Debug, Clone)]
+pub struct SignWallet {}
+
+impl CliSignWallet {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        std::collections::VecDeque::new()
+    }
+}
+
+impl From<SignWallet> for CliSignWallet {
+    fn from(_: SignWallet) -> Self {
+        Self {}
+    }
+}
+
+impl SignWallet {
+    pub fn from(
+        _: CliSignWallet,
+        connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Self> {
+        match connection_config {
+            Some(_) => Ok(Self {}),
+            None => Err(color_eyre::Report::msg(
+                "The wallet sends the transaction itself, it can't be used in the offline mode",
+            )),
+        }
+    }
+}
+
+impl SignWallet {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: Option<crate::common::ConnectionConfig>,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
+        let network_connection_config = match network_connection_config {
+            Some(network_connection_config) => network_connection_config,
+            None => {
+                return Err(color_eyre::Report::msg(
+                    "The wallet can't be used in the offline mode",
+                ))
+            }
+        };
+        // The wallet fills in its own access key, nonce and block hash
+        let unsigned_transaction = prepopulated_unsigned_transaction;
+        println!("\nUnsigned transaction:\n");
+        crate::common::print_transaction(unsigned_transaction.clone());
+        let callback_server = match crate::common::CallbackServer::start(wallet_callback) {
+            Ok(callback_server) => Some(callback_server),
+            Err(err) => {
+                println!(
+                    "Failed to start a local server to capture the wallet response ({}). You will have to enter the transaction hash manually.",
+                    err
+                );
+                None
+            }
+        };
+        let url = sign_url(
+            &network_connection_config,
+            &unsigned_transaction,
+            callback_server
+                .as_ref()
+                .map(|callback_server| callback_server.url("/callback")),
+        )?;
+        println!(
+            "If your browser doesn't automatically open, please visit this URL:\n {}\n",
+            &url.as_str()
+        );
+        url.open();
+
+        let transaction_hash = match callback_server {
+            Some(callback_server) => {
+                println!(
+                    "Waiting for the wallet to redirect back (up to {} seconds) ...",
+                    crate::consts::WALLET_SIGN_CALLBACK_TIMEOUT.as_secs()
+                );
+                match callback_server
+                    .wait(crate::consts::WALLET_SIGN_CALLBACK_TIMEOUT)
+                    .await
+                {
+                    Some(WalletCallback::Success { transaction_hashes }) => {
+                        match transaction_hashes.into_iter().next() {
+                            Some(transaction_hash) => transaction_hash,
+                            None => {
+                                return Err(color_eyre::Report::msg(
+                                    "The wallet returned no transaction hash",
+                                ))
+                            }
+                        }
+                    }
+                    Some(WalletCallback::Failure { error }) => {
+                        return Err(color_eyre::Report::msg(format!(
+                            "The wallet did not send the transaction: {}",
+                            error
+                        )))
+                    }
+                    None => {
+                        return Err(color_eyre::Report::msg(
+                            "The wallet did not redirect back in time",
+                        ))
+                    }
+                }
+            }
+            None => input_transaction_hash()?,
+        };
+        println!("Fetching the transaction {} ...", transaction_hash);
+        let transaction_info = crate::rpc::RpcClient::new(&network_connection_config)
+            .tx(
+                transaction_hash.to_string(),
+                unsigned_transaction.signer_id.clone(),
+            )
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch the transaction {}: {:?}",
+                    transaction_hash, err
+                ))
+            })?;
+        Ok(Some(transaction_info))
+    }
+}
+
+fn input_transaction_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    let transaction_hash: crate::common::BlockHashAsBase58 = Input::new()
+        .with_prompt("Enter the hash of the transaction sent by the wallet")
+        .require_interactive("<transaction-hash>")?
+        .interact_text()
+        .unwrap();
+    Ok(transaction_hash.inner)
+}
+
+/// The wallet deep link that asks to sign and send the transaction
+fn sign_url(
+    network_connection_config: &crate::common::ConnectionConfig,
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+    callback_url: Option<url::Url>,
+) -> color_eyre::eyre::Result<url::Url> {
+    let mut url: url::Url = network_connection_config.wallet_url().join("sign")?;
+    url.query_pairs_mut().append_pair(
+        "transactions",
+        &near_primitives::serialize::to_base64(
+            unsigned_transaction
+                .try_to_vec()
+                .expect("Transaction is not expected to fail on serialization"),
+        ),
+    );
+    if let Some(callback_url) = callback_url {
+        url.query_pairs_mut()
+            .append_pair("callbackUrl", callback_url.as_str());
+    }
+    Ok(url)
+}
+
+/// The result of the wallet redirect to the local server
+#[derive(Debug, Clone, PartialEq)]
+enum WalletCallback {
+    Success {
+        transaction_hashes: Vec<near_primitives::hash::CryptoHash>,
+    },
+    Failure {
+        error: String,
+    },
+}
+
+impl WalletCallback {
+    /// The wallet appends `transactionHashes` on success, `errorCode` and `errorMessage` otherwise
+    fn from_query(query: &std::collections::HashMap<String, String>) -> Self {
+        if let Some(transaction_hashes) = query.get("transactionHashes") {
+            let transaction_hashes: Result<Vec<near_primitives::hash::CryptoHash>, _> =
+                transaction_hashes
+                    .split(',')
+                    .map(|transaction_hash| transaction_hash.parse())
+                    .collect();
+            if let Ok(transaction_hashes) = transaction_hashes {
+                return Self::Success { transaction_hashes };
+            }
+        }
+        let error = match (query.get("errorCode"), query.get("errorMessage")) {
+            (Some(code), Some(message)) => format!("{} ({})", message, code),
+            (Some(code), None) => code.clone(),
+            (None, Some(message)) => message.clone(),
+            (None, None) => "unexpected response".to_string(),
+        };
+        Self::Failure { error }
+    }
+}
+
+/// Captures the `callbackUrl` redirect from the wallet
+fn wallet_callback(
+    path: &str,
+    query: &std::collections::HashMap<String, String>,
+) -> Option<(WalletCallback, actix_web::HttpResponse)> {
+    if path != "/callback" {
+        return None;
+    }
+    let wallet_callback = WalletCallback::from_query(query);
+    let body = match &wallet_callback {
+        WalletCallback::Success { .. } => {
+            "The transaction was sent. You can close this page and return to the terminal."
+        }
+        WalletCallback::Failure { .. } => {
+            "The transaction was not sent. Please return to the terminal."
+        }
+    };
+    Some((
+        wallet_callback,
+        actix_web::HttpResponse::Ok()
+            .content_type("text/plain; charset=utf-8")
+            .body(body),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wallet_callback_from_query() {
+        let transaction_hash = near_primitives::hash::CryptoHash::default();
+        let query: std::collections::HashMap<String, String> = vec![(
+            "transactionHashes".to_string(),
+            transaction_hash.to_string(),
+        )]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            WalletCallback::from_query(&query),
+            WalletCallback::Success {
+                transaction_hashes: vec![transaction_hash]
+            }
+        );
+        let query: std::collections::HashMap<String, String> = vec![
+            ("errorCode".to_string(), "userRejected".to_string()),
+            ("errorMessage".to_string(), "User rejected".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            WalletCallback::from_query(&query),
+            WalletCallback::Failure {
+                error: "User rejected (userRejected)".to_string()
+            }
+        );
+    }
+}
@@ -67,6 +67,7 @@ impl FullAccessType {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
         public_key: near_crypto::PublicKey,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let access_key: near_primitives::account::AccessKey = near_primitives::account::AccessKey {
             nonce,
@@ -87,12 +88,20 @@ impl FullAccessType {
         match *self.next_action {
             super::super::super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::super::super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
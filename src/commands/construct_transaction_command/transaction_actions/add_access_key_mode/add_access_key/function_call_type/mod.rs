@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, Select};
 use std::vec;
@@ -74,11 +75,11 @@ impl FunctionCallType {
     ) -> color_eyre::eyre::Result<Self> {
         let allowance: Option<near_primitives::types::Balance> = match item.allowance {
             Some(cli_allowance) => Some(cli_allowance.to_yoctonear()),
-            None => FunctionCallType::input_allowance(),
+            None => FunctionCallType::input_allowance()?,
         };
         let receiver_id: near_primitives::types::AccountId = match item.receiver_id {
             Some(cli_receiver_id) => near_primitives::types::AccountId::from(cli_receiver_id),
-            None => FunctionCallType::input_receiver_id(),
+            None => FunctionCallType::input_receiver_id()?,
         };
         let method_names: Vec<String> = match item.method_names {
             Some(cli_method_names) => {
@@ -91,7 +92,7 @@ impl FunctionCallType {
                         .collect::<Vec<String>>()
                 }
             }
-            None => FunctionCallType::input_method_names(),
+            None => FunctionCallType::input_method_names()?,
         };
         let skip_next_action: super::super::super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::super::super::NextAction::from_cli_skip_next_action(
@@ -114,7 +115,7 @@ impl FunctionCallType {
 }
 
 impl FunctionCallType {
-    pub fn input_method_names() -> Vec<String> {
+    pub fn input_method_names() -> color_eyre::eyre::Result<Vec<String>> {
         println!();
         let choose_input = vec![
             "Yes, I want to input a list of method names that can be used",
@@ -124,12 +125,14 @@ impl FunctionCallType {
             .with_prompt("Do You want to input a list of method names that can be used")
             .items(&choose_input)
             .default(0)
+            .require_interactive("--method-names")?
             .interact_on_opt(&Term::stderr())
             .unwrap();
-        match select_choose_input {
+        Ok(match select_choose_input {
             Some(0) => {
                 let mut input_method_names: String = Input::new()
                     .with_prompt("Enter a list of method names that can be used. The access key only allows transactions with the function call of one of the given method names. Empty list means any method name can be used.")
+                    .require_interactive("--method-names")?
                     .interact_text()
                     .unwrap();
                 if input_method_names.contains("\"") {
@@ -146,10 +149,10 @@ impl FunctionCallType {
             }
             Some(1) => vec![],
             _ => unreachable!("Error"),
-        }
+        })
     }
 
-    pub fn input_allowance() -> Option<near_primitives::types::Balance> {
+    pub fn input_allowance() -> color_eyre::eyre::Result<Option<near_primitives::types::Balance>> {
         println!();
         let choose_input = vec![
             "Yes, I want to input allowance for receiver ID",
@@ -159,27 +162,30 @@ impl FunctionCallType {
             .with_prompt("Do You want to input an allowance for receiver ID")
             .items(&choose_input)
             .default(0)
+            .require_interactive("--allowance")?
             .interact_on_opt(&Term::stderr())
             .unwrap();
-        match select_choose_input {
+        Ok(match select_choose_input {
             Some(0) => {
                 let allowance_near_balance: crate::common::NearBalance = Input::new()
                     .with_prompt("Enter an allowance which is a balance limit to use by this access key to pay for function call gas and transaction fees.")
+                    .require_interactive("--allowance")?
                     .interact_text()
                     .unwrap();
                 Some(allowance_near_balance.to_yoctonear())
             }
             Some(1) => None,
             _ => unreachable!("Error"),
-        }
+        })
     }
 
-    pub fn input_receiver_id() -> near_primitives::types::AccountId {
+    pub fn input_receiver_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter a receiver to use by this access key to pay for function call gas and transaction fees.")
+            .require_interactive("--receiver-id")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -189,6 +195,7 @@ impl FunctionCallType {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
         public_key: near_crypto::PublicKey,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let access_key: near_primitives::account::AccessKey = near_primitives::account::AccessKey {
             nonce,
@@ -215,12 +222,20 @@ impl FunctionCallType {
         match *self.next_action {
             super::super::super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::super::super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
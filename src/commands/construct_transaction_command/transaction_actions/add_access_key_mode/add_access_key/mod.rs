@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::{theme::ColorfulTheme, Input, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
@@ -58,11 +59,11 @@ impl AddAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AddAccessKeyAction::input_public_key(),
+            None => AddAccessKeyAction::input_public_key()?,
         };
         let nonce: near_primitives::types::Nonce = match item.nonce {
             Some(cli_nonce) => near_primitives::types::Nonce::from(cli_nonce),
-            None => AddAccessKeyAction::input_access_key_nonce(),
+            None => AddAccessKeyAction::input_access_key_nonce()?,
         };
         let permission: AccessKeyPermission = match item.permission {
             Some(cli_permission) => {
@@ -79,18 +80,20 @@ impl AddAccessKeyAction {
 }
 
 impl AddAccessKeyAction {
-    fn input_access_key_nonce() -> near_primitives::types::Nonce {
-        Input::new()
+    fn input_access_key_nonce() -> color_eyre::eyre::Result<near_primitives::types::Nonce> {
+        Ok(Input::new()
             .with_prompt("Enter the nonce for this access key")
+            .require_interactive("<nonce>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -98,6 +101,7 @@ impl AddAccessKeyAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         match self.permission {
             AccessKeyPermission::GrantFullAccess(full_access_type) => {
@@ -107,6 +111,7 @@ impl AddAccessKeyAction {
                         prepopulated_unsigned_transaction,
                         network_connection_config,
                         self.public_key,
+                        multisig_request,
                     )
                     .await
             }
@@ -117,6 +122,7 @@ impl AddAccessKeyAction {
                         prepopulated_unsigned_transaction,
                         network_connection_config,
                         self.public_key,
+                        multisig_request,
                     )
                     .await
             }
@@ -214,6 +220,7 @@ impl AccessKeyPermission {
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&permissions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         match variants[select_permission] {
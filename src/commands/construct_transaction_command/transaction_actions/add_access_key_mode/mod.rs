@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -59,9 +60,14 @@ impl AddAccessKeyMode {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         self.public_key_mode
-            .process(prepopulated_unsigned_transaction, network_connection_config)
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                multisig_request,
+            )
             .await
     }
 }
@@ -152,6 +158,7 @@ impl PublicKeyMode {
             .with_prompt("Select a permission that you want to add to the access key:")
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         match variants[select_mode] {
@@ -172,16 +179,25 @@ impl PublicKeyMode {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         match self {
             PublicKeyMode::PublicKey(add_access_key_action) => {
                 add_access_key_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             PublicKeyMode::GenerateKeypair(cli_generate_keypair) => {
                 cli_generate_keypair
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
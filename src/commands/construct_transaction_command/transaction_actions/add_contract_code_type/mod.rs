@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::Input;
 
@@ -53,7 +54,7 @@ impl ContractFile {
     ) -> color_eyre::eyre::Result<Self> {
         let file_path = match item.file_path {
             Some(cli_file_path) => cli_file_path,
-            None => ContractFile::input_file_path(),
+            None => ContractFile::input_file_path()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -71,13 +72,14 @@ impl ContractFile {
 }
 
 impl ContractFile {
-    fn input_file_path() -> std::path::PathBuf {
+    fn input_file_path() -> color_eyre::eyre::Result<std::path::PathBuf> {
         println!();
         let input_file_path: String = Input::new()
             .with_prompt("What is a file location of the contract?")
+            .require_interactive("<file-path>")?
             .interact_text()
             .unwrap();
-        input_file_path.into()
+        Ok(input_file_path.into())
     }
 
     #[async_recursion(?Send)]
@@ -85,6 +87,7 @@ impl ContractFile {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let code = std::fs::read(&self.file_path.clone())
             .map_err(|err| color_eyre::Report::msg(format!("Failed to open file: {:?}", err)))?;
@@ -100,12 +103,20 @@ impl ContractFile {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::Input;
 
@@ -77,22 +78,23 @@ impl CallFunctionAction {
     ) -> color_eyre::eyre::Result<Self> {
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionAction::input_method_name(),
+            None => CallFunctionAction::input_method_name()?,
         };
         let args: Vec<u8> = match item.args {
             Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
+            None => CallFunctionAction::input_args()?,
         };
-        let gas: near_primitives::types::Gas = match item.gas {
+        let gas: near_primitives::types::Gas = match item.gas.or_else(crate::project_config::gas) {
             Some(cli_gas) => match cli_gas {
                 crate::common::NearGas { inner: num } => num,
             },
-            None => CallFunctionAction::input_gas(),
-        };
-        let deposit: near_primitives::types::Balance = match item.deposit {
-            Some(cli_deposit) => cli_deposit.to_yoctonear(),
-            None => CallFunctionAction::input_deposit(),
+            None => CallFunctionAction::input_gas()?,
         };
+        let deposit: near_primitives::types::Balance =
+            match item.deposit.or_else(crate::project_config::deposit) {
+                Some(cli_deposit) => cli_deposit.to_yoctonear(),
+                None => CallFunctionAction::input_deposit()?,
+            };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
                 cli_skip_action,
@@ -112,20 +114,22 @@ impl CallFunctionAction {
 }
 
 impl CallFunctionAction {
-    fn input_method_name() -> String {
+    fn input_method_name() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter a method name")
+            .require_interactive("<method-name>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_gas() -> near_primitives::types::Gas {
+    fn input_gas() -> color_eyre::eyre::Result<near_primitives::types::Gas> {
         println!();
         let gas: u64 = loop {
             let input_gas: crate::common::NearGas = Input::new()
                 .with_prompt("Enter a gas for function")
                 .with_initial_text("100 TeraGas")
+                .require_interactive("--prepaid-gas")?
                 .interact_text()
                 .unwrap();
             let gas: u64 = match input_gas {
@@ -137,28 +141,30 @@ impl CallFunctionAction {
                 println!("You need to enter a value of no more than 300 TERAGAS")
             }
         };
-        gas
+        Ok(gas)
     }
 
-    fn input_args() -> Vec<u8> {
+    fn input_args() -> color_eyre::eyre::Result<Vec<u8>> {
         println!();
         let input: String = Input::new()
             .with_prompt("Enter args for function")
+            .require_interactive("<args>")?
             .interact_text()
             .unwrap();
-        input.into_bytes()
+        Ok(input.into_bytes())
     }
 
-    fn input_deposit() -> near_primitives::types::Balance {
+    fn input_deposit() -> color_eyre::eyre::Result<near_primitives::types::Balance> {
         println!();
         let deposit: crate::common::NearBalance = Input::new()
             .with_prompt(
                 "Enter a deposit for function (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
             .with_initial_text("0 NEAR")
+            .require_interactive("--attached-deposit")?
             .interact_text()
             .unwrap();
-        deposit.to_yoctonear()
+        Ok(deposit.to_yoctonear())
     }
 
     #[async_recursion(?Send)]
@@ -166,6 +172,7 @@ impl CallFunctionAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
@@ -184,12 +191,20 @@ impl CallFunctionAction {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
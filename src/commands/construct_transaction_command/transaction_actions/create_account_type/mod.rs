@@ -62,6 +62,7 @@ impl CreateAccountAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let action = near_primitives::transaction::Action::CreateAccount(
             near_primitives::transaction::CreateAccountAction {},
@@ -75,12 +76,20 @@ impl CreateAccountAction {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
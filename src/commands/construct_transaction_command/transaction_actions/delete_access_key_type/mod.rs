@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::Input;
 
@@ -53,7 +54,7 @@ impl DeleteAccessKeyAction {
     ) -> color_eyre::eyre::Result<Self> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => DeleteAccessKeyAction::input_public_key(),
+            None => DeleteAccessKeyAction::input_public_key()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -71,11 +72,12 @@ impl DeleteAccessKeyAction {
 }
 
 impl DeleteAccessKeyAction {
-    pub fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    pub fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter the access key to remove it")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -83,6 +85,7 @@ impl DeleteAccessKeyAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let action = near_primitives::transaction::Action::DeleteKey(
             near_primitives::transaction::DeleteKeyAction {
@@ -98,12 +101,20 @@ impl DeleteAccessKeyAction {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
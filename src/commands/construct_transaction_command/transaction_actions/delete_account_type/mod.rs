@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::Input;
 
@@ -9,6 +10,9 @@ use dialoguer::Input;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliDeleteAccountAction {
+    /// Skip typing the account ID to confirm the deletion
+    #[clap(long)]
+    yes: bool,
     #[clap(long)]
     beneficiary_id: Option<near_primitives::types::AccountId>,
     #[clap(subcommand)]
@@ -17,6 +21,7 @@ pub struct CliDeleteAccountAction {
 
 #[derive(Debug, Clone)]
 pub struct DeleteAccountAction {
+    pub yes: bool,
     pub beneficiary_id: near_primitives::types::AccountId,
     pub next_action: Box<super::NextAction>,
 }
@@ -32,6 +37,9 @@ impl CliDeleteAccountAction {
             args.push_front(beneficiary_id.to_string());
             args.push_front("--beneficiary-id".to_owned())
         };
+        if self.yes {
+            args.push_front("--yes".to_owned());
+        }
         args
     }
 }
@@ -39,6 +47,7 @@ impl CliDeleteAccountAction {
 impl From<DeleteAccountAction> for CliDeleteAccountAction {
     fn from(delete_account_action: DeleteAccountAction) -> Self {
         Self {
+            yes: delete_account_action.yes,
             beneficiary_id: Some(delete_account_action.beneficiary_id),
             next_action: Some(super::CliSkipNextAction::Skip(super::CliSkipAction {
                 sign_option: None,
@@ -55,7 +64,7 @@ impl DeleteAccountAction {
     ) -> color_eyre::eyre::Result<Self> {
         let beneficiary_id: near_primitives::types::AccountId = match item.beneficiary_id {
             Some(cli_account_id) => cli_account_id,
-            None => DeleteAccountAction::input_beneficiary_id(),
+            None => DeleteAccountAction::input_beneficiary_id()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -66,6 +75,7 @@ impl DeleteAccountAction {
             None => super::NextAction::input_next_action(connection_config, sender_account_id)?,
         };
         Ok(Self {
+            yes: item.yes,
             beneficiary_id,
             next_action: Box::new(skip_next_action),
         })
@@ -73,12 +83,13 @@ impl DeleteAccountAction {
 }
 
 impl DeleteAccountAction {
-    pub fn input_beneficiary_id() -> near_primitives::types::AccountId {
+    pub fn input_beneficiary_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter the beneficiary ID to delete this account ID")
+            .require_interactive("--beneficiary-id")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -86,7 +97,17 @@ impl DeleteAccountAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_account_exists(connection_config, &self.beneficiary_id).await?;
+        }
+        crate::common::confirm_account_deletion(
+            network_connection_config.as_ref(),
+            &prepopulated_unsigned_transaction.receiver_id,
+            self.yes,
+        )
+        .await?;
         let beneficiary_id: near_primitives::types::AccountId = self.beneficiary_id.clone();
         let action = near_primitives::transaction::Action::DeleteAccount(
             near_primitives::transaction::DeleteAccountAction { beneficiary_id },
@@ -100,12 +121,20 @@ impl DeleteAccountAction {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(unsigned_transaction, network_connection_config)
+                    .process(
+                        unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -144,6 +145,7 @@ impl NextAction {
             .with_prompt("Select an action that you want to add to the action:")
             .items(&next_action)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_next_action = match variants[select_next_action] {
@@ -161,16 +163,25 @@ impl NextAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         match self {
             NextAction::AddAction(select_action) => {
                 select_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
@@ -222,8 +233,8 @@ impl SelectAction {
                 cli_transaction_subcommand,
                 connection_config,
                 sender_account_id,
-            ),
-            None => ActionSubcommand::choose_action_command(connection_config, sender_account_id),
+            )?,
+            None => ActionSubcommand::choose_action_command(connection_config, sender_account_id)?,
         };
         Ok(Self {
             transaction_subcommand,
@@ -236,9 +247,14 @@ impl SelectAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         self.transaction_subcommand
-            .process(prepopulated_unsigned_transaction, network_connection_config)
+            .process(
+                prepopulated_unsigned_transaction,
+                network_connection_config,
+                multisig_request,
+            )
             .await
     }
 }
@@ -367,75 +383,67 @@ impl ActionSubcommand {
         item: CliActionSubcommand,
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
-    ) -> Self {
-        match item {
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
             CliActionSubcommand::TransferNEARTokens(cli_transfer_near_token) => {
                 Self::TransferNEARTokens(
                     self::transfer_near_tokens_type::TransferNEARTokensAction::from(
                         cli_transfer_near_token,
                         connection_config,
                         sender_account_id,
-                    )
-                    .unwrap(),
+                    )?,
                 )
             }
-            CliActionSubcommand::CreateAccount(cli_create_account) => Self::CreateAccount(
-                self::create_account_type::CreateAccountAction::from(
+            CliActionSubcommand::CreateAccount(cli_create_account) => {
+                Self::CreateAccount(self::create_account_type::CreateAccountAction::from(
                     cli_create_account,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::DeleteAccount(cli_delete_account) => Self::DeleteAccount(
-                self::delete_account_type::DeleteAccountAction::from(
+                )?)
+            }
+            CliActionSubcommand::DeleteAccount(cli_delete_account) => {
+                Self::DeleteAccount(self::delete_account_type::DeleteAccountAction::from(
                     cli_delete_account,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::AddAccessKey(cli_add_access_key) => Self::AddAccessKey(
-                self::add_access_key_mode::AddAccessKeyMode::from(
+                )?)
+            }
+            CliActionSubcommand::AddAccessKey(cli_add_access_key) => {
+                Self::AddAccessKey(self::add_access_key_mode::AddAccessKeyMode::from(
                     cli_add_access_key,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::DeleteAccessKey(cli_delete_access_key) => Self::DeleteAccessKey(
-                self::delete_access_key_type::DeleteAccessKeyAction::from(
+                )?)
+            }
+            CliActionSubcommand::DeleteAccessKey(cli_delete_access_key) => {
+                Self::DeleteAccessKey(self::delete_access_key_type::DeleteAccessKeyAction::from(
                     cli_delete_access_key,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::StakeNEARTokens(cli_stake_near_token) => Self::StakeNEARTokens(
-                self::stake_near_tokens_type::StakeNEARTokensAction::from(
+                )?)
+            }
+            CliActionSubcommand::StakeNEARTokens(cli_stake_near_token) => {
+                Self::StakeNEARTokens(self::stake_near_tokens_type::StakeNEARTokensAction::from(
                     cli_stake_near_token,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::CallFunction(cli_call_function) => Self::CallFunction(
-                self::call_function_type::CallFunctionAction::from(
+                )?)
+            }
+            CliActionSubcommand::CallFunction(cli_call_function) => {
+                Self::CallFunction(self::call_function_type::CallFunctionAction::from(
                     cli_call_function,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-            CliActionSubcommand::AddContractCode(cli_contract_file) => Self::AddContractCode(
-                self::add_contract_code_type::ContractFile::from(
+                )?)
+            }
+            CliActionSubcommand::AddContractCode(cli_contract_file) => {
+                Self::AddContractCode(self::add_contract_code_type::ContractFile::from(
                     cli_contract_file,
                     connection_config,
                     sender_account_id,
-                )
-                .unwrap(),
-            ),
-        }
+                )?)
+            }
+        })
     }
 }
 
@@ -443,7 +451,7 @@ impl ActionSubcommand {
     pub fn choose_action_command(
         connection_config: Option<crate::common::ConnectionConfig>,
         sender_account_id: near_primitives::types::AccountId,
-    ) -> ActionSubcommand {
+    ) -> color_eyre::eyre::Result<ActionSubcommand> {
         println!();
         let variants = ActionSubcommandDiscriminants::iter().collect::<Vec<_>>();
         let action_subcommands = variants
@@ -454,6 +462,7 @@ impl ActionSubcommand {
             .with_prompt("Select an action that you want to add to the action:")
             .items(&action_subcommands)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_action_subcomand = match variants[select_action_subcommand] {
@@ -489,46 +498,79 @@ impl ActionSubcommand {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         match self {
             ActionSubcommand::TransferNEARTokens(args_transfer) => {
                 args_transfer
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::CallFunction(args_function) => {
                 args_function
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::StakeNEARTokens(args_stake) => {
                 args_stake
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::CreateAccount(args_create_account) => {
                 args_create_account
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::DeleteAccount(args_delete_account) => {
                 args_delete_account
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::AddAccessKey(args_add_access_key) => {
                 args_add_access_key
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::DeleteAccessKey(args_delete_access_key) => {
                 args_delete_access_key
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             ActionSubcommand::AddContractCode(args_contract_file) => {
                 args_contract_file
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
@@ -603,13 +645,29 @@ impl SkipAction {
         self,
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
+        let unsigned_transaction = match multisig_request {
+            Some(multisig_request_method) => {
+                multisig_request_method.wrap_actions(prepopulated_unsigned_transaction)?
+            }
+            None => prepopulated_unsigned_transaction,
+        };
+        if let Some(delegate_action_options) = crate::delegate_action::options() {
+            let signed_delegate_action = self
+                .sign_option
+                .sign_delegate_action(
+                    unsigned_transaction,
+                    network_connection_config,
+                    delegate_action_options,
+                )
+                .await?;
+            crate::delegate_action::print_signed_delegate_action(&signed_delegate_action);
+            return Ok(());
+        }
         match self
             .sign_option
-            .process(
-                prepopulated_unsigned_transaction,
-                network_connection_config.clone(),
-            )
+            .process(unsigned_transaction, network_connection_config.clone())
             .await?
         {
             Some(transaction_info) => {
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use async_recursion::async_recursion;
 use dialoguer::Input;
 
@@ -59,11 +60,11 @@ impl StakeNEARTokensAction {
     ) -> color_eyre::eyre::Result<Self> {
         let stake_amount: crate::common::NearBalance = match item.stake_amount {
             Some(cli_stake_amount) => cli_stake_amount,
-            None => StakeNEARTokensAction::input_stake_amount(),
+            None => StakeNEARTokensAction::input_stake_amount()?,
         };
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => StakeNEARTokensAction::input_public_key(),
+            None => StakeNEARTokensAction::input_public_key()?,
         };
         let skip_next_action: super::NextAction = match item.next_action {
             Some(cli_skip_action) => super::NextAction::from_cli_skip_next_action(
@@ -82,18 +83,20 @@ impl StakeNEARTokensAction {
 }
 
 impl StakeNEARTokensAction {
-    fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this stake")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_stake_amount() -> crate::common::NearBalance {
-        Input::new()
+    fn input_stake_amount() -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(Input::new()
                         .with_prompt("How many NEAR Tokens do you want to stake? (example: 10NEAR or 0.5near or 10000yoctonear)")
+                        .require_interactive("<stake-amount>")?
                         .interact_text()
-                        .unwrap()
+                        .unwrap())
     }
 
     #[async_recursion(?Send)]
@@ -101,6 +104,7 @@ impl StakeNEARTokensAction {
         self,
         mut prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
+        multisig_request: Option<crate::commands::multisig_command::MultisigRequestMethod>,
     ) -> crate::CliResult {
         let action = near_primitives::transaction::Action::Stake(
             near_primitives::transaction::StakeAction {
@@ -112,12 +116,20 @@ impl StakeNEARTokensAction {
         match *self.next_action {
             super::NextAction::AddAction(select_action) => {
                 select_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
             super::NextAction::Skip(skip_action) => {
                 skip_action
-                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .process(
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                        multisig_request,
+                    )
                     .await
             }
         }
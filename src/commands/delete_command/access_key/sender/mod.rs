@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// Specify the account to be deleted
@@ -47,22 +48,11 @@ impl Sender {
         item: CliSender,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Self> {
-        let sender_account_id: near_primitives::types::AccountId = match item.sender_account_id {
-            Some(cli_sender_account_id) => match &connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    network_connection_config,
-                    cli_sender_account_id.clone(),
-                )? {
-                    Some(_) => cli_sender_account_id,
-                    None => {
-                        println!("Account <{}> doesn't exist", cli_sender_account_id);
-                        Sender::input_sender_account_id(connection_config.clone())?
-                    }
-                },
-                None => cli_sender_account_id,
-            },
-            None => Sender::input_sender_account_id(connection_config.clone())?,
-        };
+        let sender_account_id: near_primitives::types::AccountId =
+            match item.sender_account_id.or_else(crate::network::signer) {
+                Some(cli_sender_account_id) => cli_sender_account_id,
+                None => Sender::input_sender_account_id()?,
+            };
         let delete_public_key = match item.delete_public_key {
             Some(cli_delete_access_key) => super::DeleteAccessKeyAction::from(
                 cli_delete_access_key,
@@ -82,26 +72,12 @@ impl Sender {
 }
 
 impl Sender {
-    fn input_sender_account_id(
-        connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("Which account ID do you need to remove the key from?")
-                .interact_text()
-                .unwrap();
-            if let Some(connection_config) = &connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(connection_config, account_id.clone())?
-                {
-                    break Ok(account_id);
-                } else {
-                    println!("Account <{}> doesn't exist", account_id.to_string());
-                }
-            } else {
-                break Ok(account_id);
-            }
-        }
+    fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("Which account ID do you need to remove the key from?")
+            .require_interactive("<sender-account-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
@@ -109,6 +85,10 @@ impl Sender {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_account_exists(connection_config, &self.sender_account_id)
+                .await?;
+        }
         let unsigned_transaction = near_primitives::transaction::Transaction {
             signer_id: self.sender_account_id.clone(),
             receiver_id: self.sender_account_id.clone(),
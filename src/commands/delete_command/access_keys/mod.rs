@@ -0,0 +1,609 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use near_primitives::borsh::BorshSerialize;
+
+pub mod operation_mode;
+
+/// Delete the function-call keys of the account that match all the given filters
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliDeleteAccessKeys {
+    /// The account whose access keys are deleted (defaults to the global --signer)
+    account_id: Option<near_primitives::types::AccountId>,
+    /// Only the keys for calls to this contract
+    #[clap(long)]
+    receiver: Option<near_primitives::types::AccountId>,
+    /// Only the keys that name this method (the keys allowing any method are not matched)
+    #[clap(long)]
+    method: Option<String>,
+    /// Only the keys with the allowance below this amount (the keys with no limit are not matched)
+    #[clap(long)]
+    allowance_below: Option<crate::common::NearBalance>,
+    /// Only the keys that are not saved in the keychain of the network
+    #[clap(long)]
+    not_in_keychain: bool,
+    /// Skip typing the account ID to confirm the deletion
+    #[clap(long)]
+    yes: bool,
+    /// The full access key signing the transactions (the keychain is used if no key is given)
+    #[clap(long)]
+    signer_private_key: Option<near_crypto::SecretKey>,
+    /// Read the signing key from the file
+    #[clap(long)]
+    signer_private_key_file: Option<std::path::PathBuf>,
+    /// Read the signing key from the environment variable
+    #[clap(long)]
+    signer_private_key_env: Option<String>,
+    /// Read the signing key from the first line of stdin
+    #[clap(long)]
+    signer_private_key_stdin: bool,
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteAccessKeys {
+    cleanup: AccessKeysCleanup,
+    mode: self::operation_mode::Mode,
+}
+
+/// What `delete access-keys` deletes and how it signs, passed down to the selected server
+#[derive(Debug, Clone)]
+pub struct AccessKeysCleanup {
+    account_id: near_primitives::types::AccountId,
+    filter: AccessKeyFilter,
+    yes: bool,
+    signer_private_key: Option<(near_crypto::SecretKey, crate::common::SecretKeySource)>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct AccessKeyFilter {
+    receiver_id: Option<near_primitives::types::AccountId>,
+    method_name: Option<String>,
+    allowance_below: Option<crate::common::NearBalance>,
+    not_in_keychain: bool,
+}
+
+impl CliDeleteAccessKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .mode
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if self.signer_private_key_stdin {
+            args.push_front("--signer-private-key-stdin".to_owned());
+        }
+        if let Some(var) = &self.signer_private_key_env {
+            args.push_front(var.clone());
+            args.push_front("--signer-private-key-env".to_owned());
+        }
+        if let Some(path) = &self.signer_private_key_file {
+            args.push_front(path.display().to_string());
+            args.push_front("--signer-private-key-file".to_owned());
+        }
+        if self.signer_private_key.is_some() {
+            args.push_front(crate::consts::REDACTED_SECRET.to_string());
+            args.push_front("--signer-private-key".to_owned());
+        }
+        if self.yes {
+            args.push_front("--yes".to_owned());
+        }
+        if self.not_in_keychain {
+            args.push_front("--not-in-keychain".to_owned());
+        }
+        if let Some(allowance_below) = &self.allowance_below {
+            args.push_front(allowance_below.to_string().replace(' ', ""));
+            args.push_front("--allowance-below".to_owned());
+        }
+        if let Some(method) = &self.method {
+            args.push_front(method.clone());
+            args.push_front("--method".to_owned());
+        }
+        if let Some(receiver) = &self.receiver {
+            args.push_front(receiver.to_string());
+            args.push_front("--receiver".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<DeleteAccessKeys> for CliDeleteAccessKeys {
+    fn from(delete_access_keys: DeleteAccessKeys) -> Self {
+        let cleanup = delete_access_keys.cleanup;
+        let mut cli_delete_access_keys = Self {
+            account_id: Some(cleanup.account_id),
+            receiver: cleanup.filter.receiver_id,
+            method: cleanup.filter.method_name,
+            allowance_below: cleanup.filter.allowance_below,
+            not_in_keychain: cleanup.filter.not_in_keychain,
+            yes: cleanup.yes,
+            mode: Some(delete_access_keys.mode.into()),
+            ..Default::default()
+        };
+        match cleanup.signer_private_key {
+            Some((private_key, crate::common::SecretKeySource::Inline)) => {
+                cli_delete_access_keys.signer_private_key = Some(private_key)
+            }
+            Some((_, crate::common::SecretKeySource::File(path))) => {
+                cli_delete_access_keys.signer_private_key_file = Some(path)
+            }
+            Some((_, crate::common::SecretKeySource::Env(var))) => {
+                cli_delete_access_keys.signer_private_key_env = Some(var)
+            }
+            Some((_, crate::common::SecretKeySource::Stdin)) => {
+                cli_delete_access_keys.signer_private_key_stdin = true
+            }
+            None => {}
+        }
+        cli_delete_access_keys
+    }
+}
+
+impl DeleteAccessKeys {
+    pub fn from(item: CliDeleteAccessKeys) -> color_eyre::eyre::Result<Self> {
+        let mut filter = AccessKeyFilter {
+            receiver_id: item.receiver,
+            method_name: item.method,
+            allowance_below: item.allowance_below,
+            not_in_keychain: item.not_in_keychain,
+        };
+        if filter.is_empty() {
+            filter.receiver_id = Some(DeleteAccessKeys::input_receiver_id()?);
+        }
+        let signer_private_key = crate::common::read_secret_key(
+            item.signer_private_key,
+            item.signer_private_key_file,
+            item.signer_private_key_env,
+            item.signer_private_key_stdin,
+        )?;
+        let account_id = match item.account_id.or_else(crate::network::signer) {
+            Some(account_id) => account_id,
+            None => DeleteAccessKeys::input_account_id()?,
+        };
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode)?,
+            None => self::operation_mode::Mode::choose_mode()?,
+        };
+        Ok(Self {
+            cleanup: AccessKeysCleanup {
+                account_id,
+                filter,
+                yes: item.yes,
+                signer_private_key,
+            },
+            mode,
+        })
+    }
+}
+
+impl DeleteAccessKeys {
+    fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID whose access keys you want to delete?")
+            .require_interactive("<account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    fn input_receiver_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("Delete the function-call keys for which contract?")
+            .require_interactive("--receiver")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.mode.process(self.cleanup).await
+    }
+}
+
+impl AccessKeyFilter {
+    fn is_empty(&self) -> bool {
+        self.receiver_id.is_none()
+            && self.method_name.is_none()
+            && self.allowance_below.is_none()
+            && !self.not_in_keychain
+    }
+
+    /// Only the function-call keys are ever matched
+    fn matches(
+        &self,
+        access_key: &near_primitives::views::AccessKeyInfoView,
+        keychain_public_keys: &std::collections::HashSet<near_crypto::PublicKey>,
+    ) -> bool {
+        let (allowance, receiver_id, method_names) = match &access_key.access_key.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => return false,
+            near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } => (allowance, receiver_id, method_names),
+        };
+        if let Some(filter_receiver_id) = &self.receiver_id {
+            if receiver_id.to_string() != filter_receiver_id.to_string() {
+                return false;
+            }
+        }
+        if let Some(method_name) = &self.method_name {
+            if !method_names.contains(method_name) {
+                return false;
+            }
+        }
+        if let Some(allowance_below) = &self.allowance_below {
+            match allowance {
+                Some(allowance) if *allowance < allowance_below.to_yoctonear() => {}
+                _ => return false,
+            }
+        }
+        !(self.not_in_keychain && keychain_public_keys.contains(&access_key.public_key))
+    }
+}
+
+impl AccessKeysCleanup {
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let account_id = self.account_id;
+        let signer_private_key = match self.signer_private_key {
+            Some((private_key, _)) => private_key,
+            None => crate::common::get_secret_key_from_keychain(&account_id)?,
+        };
+        let signer_public_key = signer_private_key.public_key();
+
+        let rpc_client = crate::rpc::RpcClient::new(&network_connection_config);
+        let access_key_list_response = rpc_client
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKeyList {
+                    account_id: account_id.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "Failed to fetch query for view key list: {:?}",
+                    err
+                ))
+            })?;
+        let access_keys =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKeyList(
+                access_key_list,
+            ) = access_key_list_response.kind
+            {
+                access_key_list.keys
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        let signer_access_key = access_keys
+            .iter()
+            .find(|access_key| access_key.public_key == signer_public_key)
+            .ok_or_else(|| {
+                color_eyre::Report::msg(format!(
+                    "The signing key {} is not an access key of <{}>",
+                    signer_public_key, account_id
+                ))
+            })?;
+        if !matches!(
+            signer_access_key.access_key.permission,
+            near_primitives::views::AccessKeyPermissionView::FullAccess
+        ) {
+            return Err(color_eyre::Report::msg(format!(
+                "The signing key {} of <{}> is not a full access key",
+                signer_public_key, account_id
+            )));
+        }
+
+        let keychain_public_keys = if self.filter.not_in_keychain {
+            let mut keychain_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+            keychain_dir.push(network_connection_config.dir_name());
+            read_keychain_public_keys(&keychain_dir, &account_id)?
+        } else {
+            std::collections::HashSet::new()
+        };
+        let matched_keys = access_keys
+            .iter()
+            .filter(|access_key| access_key.public_key != signer_public_key)
+            .filter(|access_key| self.filter.matches(access_key, &keychain_public_keys))
+            .collect::<Vec<_>>();
+        if matched_keys.is_empty() {
+            println!("No access keys of <{}> match the filters.", account_id);
+            return Ok(());
+        }
+        println!(
+            "{} of {} access keys of <{}> match the filters:",
+            matched_keys.len(),
+            access_keys.len(),
+            account_id
+        );
+        for (index, access_key) in matched_keys.iter().enumerate() {
+            if let near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } = &access_key.access_key.permission
+            {
+                let allowance_message = match allowance {
+                    Some(amount) => format!(
+                        "with an allowance of {}",
+                        crate::common::NearBalance::from_yoctonear(*amount)
+                    ),
+                    None => format!("with no limit"),
+                };
+                println!(
+                    "{: >4}. {} for {:?} function calls on {} {}",
+                    index + 1,
+                    access_key.public_key,
+                    method_names,
+                    receiver_id,
+                    allowance_message
+                );
+            }
+        }
+        if !self.yes {
+            let confirmation: String = Input::new()
+                .with_prompt(format!(
+                    "To confirm the deletion of {} access keys, type the account ID <{}>",
+                    matched_keys.len(),
+                    account_id
+                ))
+                .allow_empty(true)
+                .require_interactive("--yes")?
+                .interact_text()
+                .unwrap();
+            if confirmation.trim() != account_id.to_string() {
+                return Err(color_eyre::Report::msg(
+                    "The account ID does not match. No access keys are deleted.",
+                ));
+            }
+        }
+
+        let empty_transaction = near_primitives::transaction::Transaction {
+            signer_id: account_id.clone(),
+            public_key: signer_public_key.clone(),
+            nonce: 0,
+            receiver_id: account_id.clone(),
+            block_hash: access_key_list_response.block_hash,
+            actions: vec![],
+        };
+        // The largest signature (secp256k1) is added to the size of the unsigned transaction
+        let header_size = empty_transaction.get_hash_and_size().1 + 1 + 65;
+        let batches = batch_actions(
+            matched_keys
+                .iter()
+                .map(|access_key| {
+                    near_primitives::transaction::Action::DeleteKey(
+                        near_primitives::transaction::DeleteKeyAction {
+                            public_key: access_key.public_key.clone(),
+                        },
+                    )
+                })
+                .collect(),
+            header_size,
+        );
+        let batches_count = batches.len();
+        let mut deleted_keys_count = 0;
+        let sign_with_private_key = crate::commands::construct_transaction_command::sign_transaction::sign_with_private_key::SignPrivateKey {
+            signer_public_key: signer_public_key.clone(),
+            signer_private_key,
+            signer_private_key_source: crate::common::SecretKeySource::Inline,
+            nonce: None,
+            block_hash: None,
+            submit: Some(crate::commands::construct_transaction_command::sign_transaction::Submit::Send),
+        };
+        for (index, actions) in batches.into_iter().enumerate() {
+            let actions_count = actions.len();
+            let unsigned_transaction = near_primitives::transaction::Transaction {
+                actions,
+                ..empty_transaction.clone()
+            };
+            println!(
+                "\nDeleting {} access keys (transaction {} of {}) ...",
+                actions_count,
+                index + 1,
+                batches_count
+            );
+            let transaction_info = match sign_with_private_key
+                .clone()
+                .process(
+                    unsigned_transaction,
+                    Some(network_connection_config.clone()),
+                )
+                .await?
+            {
+                Some(transaction_info) => transaction_info,
+                None => {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The transaction was not sent. {} of {} access keys are deleted.",
+                        deleted_keys_count,
+                        matched_keys.len()
+                    )))
+                }
+            };
+            let failed = matches!(
+                transaction_info.status,
+                near_primitives::views::FinalExecutionStatus::Failure(_)
+            );
+            crate::common::print_transaction_status(
+                transaction_info,
+                Some(network_connection_config.clone()),
+            );
+            if failed {
+                return Err(color_eyre::Report::msg(format!(
+                    "{} of {} access keys are deleted.",
+                    deleted_keys_count,
+                    matched_keys.len()
+                )));
+            }
+            deleted_keys_count += actions_count;
+        }
+        println!(
+            "\n{} access keys of <{}> are deleted.",
+            deleted_keys_count, account_id
+        );
+        crate::common::record_command_output("deleted_access_keys", deleted_keys_count.to_string());
+        Ok(())
+    }
+}
+
+/// Packs the actions into as few transactions as the protocol limits allow
+fn batch_actions(
+    actions: Vec<near_primitives::transaction::Action>,
+    header_size: u64,
+) -> Vec<Vec<near_primitives::transaction::Action>> {
+    let mut batches: Vec<Vec<near_primitives::transaction::Action>> = vec![];
+    let mut batch_size = header_size;
+    for action in actions {
+        let action_size = action
+            .try_to_vec()
+            .expect("Action is not expected to fail on serialization")
+            .len() as u64;
+        match batches.last_mut() {
+            Some(batch)
+                if batch.len() < crate::consts::MAX_ACTIONS_PER_TRANSACTION
+                    && batch_size + action_size <= crate::consts::MAX_TRANSACTION_SIZE =>
+            {
+                batch.push(action);
+                batch_size += action_size;
+            }
+            _ => {
+                batches.push(vec![action]);
+                batch_size = header_size + action_size;
+            }
+        }
+    }
+    batches
+}
+
+/// The public keys of the account saved in the keychain directory of the network: the default
+/// key in `<account_id>.json` and the keys in `<account_id>/*.json`
+fn read_keychain_public_keys(
+    keychain_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<std::collections::HashSet<near_crypto::PublicKey>> {
+    #[derive(serde::Deserialize)]
+    struct KeychainAccessKey {
+        public_key: near_crypto::PublicKey,
+    }
+    let mut paths = vec![keychain_dir.join(format!("{}.json", account_id))];
+    if let Ok(entries) = std::fs::read_dir(keychain_dir.join(account_id.to_string())) {
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .map_or(false, |extension| extension == "json")
+            {
+                paths.push(path);
+            }
+        }
+    }
+    let mut public_keys = std::collections::HashSet::new();
+    for path in paths.iter().filter(|path| path.exists()) {
+        let data = std::fs::read_to_string(path).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", path, err))
+        })?;
+        let access_key: KeychainAccessKey = serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", path, err))
+        })?;
+        public_keys.insert(access_key.public_key);
+    }
+    Ok(public_keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn function_call_key(
+        seed: &str,
+        receiver_id: &str,
+        method_names: Vec<String>,
+        allowance: Option<near_primitives::types::Balance>,
+    ) -> near_primitives::views::AccessKeyInfoView {
+        near_primitives::views::AccessKeyInfoView {
+            public_key: near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, seed)
+                .public_key(),
+            access_key: near_primitives::views::AccessKeyView {
+                nonce: 0,
+                permission: near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                    allowance,
+                    receiver_id: receiver_id.parse().unwrap(),
+                    method_names,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn filter_access_keys() {
+        let game_key = function_call_key("game", "game.near", vec![], Some(1_000));
+        let dex_key = function_call_key("dex", "dex.near", vec!["swap".to_string()], None);
+        let keychain_public_keys = vec![game_key.public_key.clone()].into_iter().collect();
+        let filter = AccessKeyFilter {
+            receiver_id: Some(near_primitives::types::AccountId::from_str("game.near").unwrap()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&game_key, &keychain_public_keys));
+        assert!(!filter.matches(&dex_key, &keychain_public_keys));
+        let filter = AccessKeyFilter {
+            method_name: Some("swap".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&game_key, &keychain_public_keys));
+        assert!(filter.matches(&dex_key, &keychain_public_keys));
+        let filter = AccessKeyFilter {
+            allowance_below: Some(crate::common::NearBalance::from_yoctonear(1_001)),
+            ..Default::default()
+        };
+        assert!(filter.matches(&game_key, &keychain_public_keys));
+        assert!(!filter.matches(&dex_key, &keychain_public_keys));
+        let filter = AccessKeyFilter {
+            not_in_keychain: true,
+            ..Default::default()
+        };
+        assert!(!filter.matches(&game_key, &keychain_public_keys));
+        assert!(filter.matches(&dex_key, &keychain_public_keys));
+
+        let full_access_key = near_primitives::views::AccessKeyInfoView {
+            public_key: near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "full")
+                .public_key(),
+            access_key: near_primitives::views::AccessKeyView {
+                nonce: 0,
+                permission: near_primitives::views::AccessKeyPermissionView::FullAccess,
+            },
+        };
+        assert!(!filter.matches(&full_access_key, &keychain_public_keys));
+    }
+
+    #[test]
+    fn batch_delete_key_actions() {
+        let actions = (0..250)
+            .map(|index| {
+                near_primitives::transaction::Action::DeleteKey(
+                    near_primitives::transaction::DeleteKeyAction {
+                        public_key: near_crypto::SecretKey::from_seed(
+                            near_crypto::KeyType::ED25519,
+                            &index.to_string(),
+                        )
+                        .public_key(),
+                    },
+                )
+            })
+            .collect();
+        let batches = batch_actions(actions, 200);
+        assert_eq!(
+            batches.iter().map(|batch| batch.len()).collect::<Vec<_>>(),
+            vec![100, 100, 50]
+        );
+    }
+}
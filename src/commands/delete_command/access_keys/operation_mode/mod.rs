@@ -0,0 +1,60 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+pub mod online_mode;
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliMode {
+    /// Delete the access keys with online mode
+    Network(self::online_mode::CliNetworkArgs),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum Mode {
+    #[strum_discriminants(strum(message = "Yes, I keep it simple"))]
+    Network(self::online_mode::NetworkArgs),
+}
+
+impl CliMode {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Network(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("network".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Mode> for CliMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Network(network_args) => {
+                Self::Network(self::online_mode::CliNetworkArgs::from(network_args))
+            }
+        }
+    }
+}
+
+impl Mode {
+    pub fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
+        }
+    }
+}
+
+impl Mode {
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
+        Self::from(CliMode::Network(Default::default()))
+    }
+
+    pub async fn process(self, cleanup: super::AccessKeysCleanup) -> crate::CliResult {
+        match self {
+            Self::Network(network_args) => network_args.process(cleanup).await,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/// аргументы, необходимые для удаления ключей в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let args = std::collections::VecDeque::new();
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        Ok(Self { selected_server })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(self, cleanup: super::super::AccessKeysCleanup) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        cleanup
+            .process(self.selected_server.connection_config)
+            .await
+    }
+}
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 pub mod operation_mode;
@@ -11,6 +12,9 @@ mod sender;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliDeleteAccountAction {
+    /// Skip typing the account ID to confirm the deletion
+    #[clap(long)]
+    yes: bool,
     beneficiary_id: Option<near_primitives::types::AccountId>,
     #[clap(subcommand)]
     sign_option: Option<
@@ -20,6 +24,7 @@ pub struct CliDeleteAccountAction {
 
 #[derive(Debug, Clone)]
 pub struct DeleteAccountAction {
+    pub yes: bool,
     pub beneficiary_id: near_primitives::types::AccountId,
     pub sign_option:
         crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
@@ -35,6 +40,9 @@ impl CliDeleteAccountAction {
         if let Some(beneficiary_id) = &self.beneficiary_id {
             args.push_front(beneficiary_id.to_string());
         }
+        if self.yes {
+            args.push_front("--yes".to_owned());
+        }
         args
     }
 }
@@ -42,6 +50,7 @@ impl CliDeleteAccountAction {
 impl From<DeleteAccountAction> for CliDeleteAccountAction {
     fn from(delete_account_action: DeleteAccountAction) -> Self {
         Self {
+            yes: delete_account_action.yes,
             beneficiary_id: Some(delete_account_action.beneficiary_id),
             sign_option: Some(delete_account_action.sign_option.into()),
         }
@@ -55,26 +64,15 @@ impl DeleteAccountAction {
         sender_account_id: near_primitives::types::AccountId,
     ) -> color_eyre::eyre::Result<Self> {
         let beneficiary_id: near_primitives::types::AccountId = match item.beneficiary_id {
-            Some(cli_account_id) => match &connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    network_connection_config,
-                    cli_account_id.clone(),
-                )? {
-                    Some(_) => cli_account_id,
-                    None => {
-                        println!("Account <{}> doesn't exist", cli_account_id);
-                        DeleteAccountAction::input_beneficiary_id(connection_config.clone())?
-                    }
-                },
-                None => cli_account_id,
-            },
-            None => DeleteAccountAction::input_beneficiary_id(connection_config.clone())?,
+            Some(cli_account_id) => cli_account_id,
+            None => DeleteAccountAction::input_beneficiary_id()?,
         };
         let sign_option = match item.sign_option {
             Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, connection_config, sender_account_id)?,
             None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(connection_config, sender_account_id)?,
         };
         Ok(Self {
+            yes: item.yes,
             beneficiary_id,
             sign_option,
         })
@@ -82,26 +80,12 @@ impl DeleteAccountAction {
 }
 
 impl DeleteAccountAction {
-    pub fn input_beneficiary_id(
-        connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("Enter the beneficiary ID to delete this account ID")
-                .interact_text()
-                .unwrap();
-            if let Some(connection_config) = &connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(connection_config, account_id.clone())?
-                {
-                    break Ok(account_id);
-                } else {
-                    println!("Account <{}> doesn't exist", account_id);
-                }
-            } else {
-                break Ok(account_id);
-            }
-        }
+    pub fn input_beneficiary_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("Enter the beneficiary ID to delete this account ID")
+            .require_interactive("<beneficiary-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
@@ -109,6 +93,15 @@ impl DeleteAccountAction {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_account_exists(connection_config, &self.beneficiary_id).await?;
+        }
+        crate::common::confirm_account_deletion(
+            network_connection_config.as_ref(),
+            &prepopulated_unsigned_transaction.receiver_id,
+            self.yes,
+        )
+        .await?;
         let beneficiary_id: near_primitives::types::AccountId = self.beneficiary_id.clone();
         let action = near_primitives::transaction::Action::DeleteAccount(
             near_primitives::transaction::DeleteAccountAction { beneficiary_id },
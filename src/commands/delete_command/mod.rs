@@ -1,7 +1,9 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod access_key;
+mod access_keys;
 mod account;
 
 /// инструмент выбора to delete action
@@ -61,6 +63,8 @@ impl DeleteAction {
 pub enum CliAction {
     /// Delete an access key for an account
     AccessKey(self::access_key::operation_mode::CliOperationMode),
+    /// Delete the function-call keys matching the filters, in as few transactions as possible
+    AccessKeys(self::access_keys::CliDeleteAccessKeys),
     /// Delete this account
     Account(self::account::operation_mode::CliOperationMode),
 }
@@ -70,6 +74,8 @@ pub enum CliAction {
 pub enum Action {
     #[strum_discriminants(strum(message = "Delete an access key for this account"))]
     AccessKey(self::access_key::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Delete the access keys matching the filters"))]
+    AccessKeys(self::access_keys::DeleteAccessKeys),
     #[strum_discriminants(strum(message = "Delete this account"))]
     Account(self::account::operation_mode::OperationMode),
 }
@@ -82,6 +88,11 @@ impl CliAction {
                 command.push_front("access-key".to_owned());
                 command
             }
+            Self::AccessKeys(subcommand) => {
+                let mut command = subcommand.to_cli_args();
+                command.push_front("access-keys".to_owned());
+                command
+            }
             Self::Account(subcommand) => {
                 let mut command = subcommand.to_cli_args();
                 command.push_front("account".to_owned());
@@ -95,6 +106,7 @@ impl From<Action> for CliAction {
     fn from(action: Action) -> Self {
         match action {
             Action::AccessKey(operation_mode) => Self::AccessKey(operation_mode.into()),
+            Action::AccessKeys(delete_access_keys) => Self::AccessKeys(delete_access_keys.into()),
             Action::Account(operation_mode) => Self::Account(operation_mode.into()),
         }
     }
@@ -104,10 +116,13 @@ impl Action {
     fn from(item: CliAction) -> color_eyre::eyre::Result<Self> {
         match item {
             CliAction::AccessKey(cli_operation_mode) => Ok(Action::AccessKey(
-                self::access_key::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::access_key::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliAction::AccessKeys(cli_delete_access_keys) => Ok(Action::AccessKeys(
+                self::access_keys::DeleteAccessKeys::from(cli_delete_access_keys)?,
             )),
             CliAction::Account(cli_operation_mode) => Ok(Action::Account(
-                self::account::operation_mode::OperationMode::from(cli_operation_mode).unwrap(),
+                self::account::operation_mode::OperationMode::from(cli_operation_mode)?,
             )),
         }
     }
@@ -125,10 +140,12 @@ impl Action {
             .with_prompt("Сhoose what you want to delete")
             .items(&actions)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_action = match variants[selected_action] {
             ActionDiscriminants::AccessKey => CliAction::AccessKey(Default::default()),
+            ActionDiscriminants::AccessKeys => CliAction::AccessKeys(Default::default()),
             ActionDiscriminants::Account => CliAction::Account(Default::default()),
         };
         Ok(Self::from(cli_action)?)
@@ -144,6 +161,7 @@ impl Action {
                     .process(prepopulated_unsigned_transaction)
                     .await
             }
+            Action::AccessKeys(delete_access_keys) => delete_access_keys.process().await,
             Action::Account(operation_mode) => {
                 operation_mode
                     .process(prepopulated_unsigned_transaction)
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// вызов CallFunction
@@ -14,6 +15,9 @@ pub struct CliCallFunctionAction {
     deposit: Option<crate::common::NearBalance>,
     #[clap(long = "prepaid-gas")]
     gas: Option<crate::common::NearGas>,
+    /// Path to the contract ABI (metadata) file used to offer the contract methods
+    #[clap(long)]
+    abi_file: Option<std::path::PathBuf>,
     #[clap(subcommand)]
     send_from: Option<super::signer::CliSendFrom>,
 }
@@ -24,6 +28,7 @@ pub struct CallFunctionAction {
     args: Vec<u8>,
     gas: near_primitives::types::Gas,
     deposit: near_primitives::types::Balance,
+    abi_file: Option<std::path::PathBuf>,
     send_from: super::signer::SendFrom,
 }
 
@@ -48,6 +53,10 @@ impl CliCallFunctionAction {
         if let Some(method_name) = &self.method_name {
             args.push_front(method_name.to_string());
         };
+        if let Some(abi_file) = &self.abi_file {
+            args.push_front(abi_file.as_path().display().to_string());
+            args.push_front("--abi-file".to_owned());
+        };
         args
     }
 }
@@ -61,6 +70,7 @@ impl From<CallFunctionAction> for CliCallFunctionAction {
             deposit: Some(crate::common::NearBalance::from_yoctonear(
                 call_function_action.deposit,
             )),
+            abi_file: call_function_action.abi_file,
             send_from: Some(call_function_action.send_from.into()),
         }
     }
@@ -71,24 +81,38 @@ impl CallFunctionAction {
         item: CliCallFunctionAction,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Self> {
+        let mut args_template: Option<String> = None;
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionAction::input_method_name(),
+            None => {
+                let methods = crate::common::find_contract_methods(item.abi_file.as_deref());
+                match crate::common::select_contract_method(
+                    &methods,
+                    crate::common::ContractMethodKind::View,
+                )? {
+                    Some(method) => {
+                        args_template = method.args_template();
+                        method.name
+                    }
+                    None => CallFunctionAction::input_method_name()?,
+                }
+            }
         };
         let args: Vec<u8> = match item.args {
             Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionAction::input_args(),
+            None => CallFunctionAction::input_args(args_template)?,
         };
-        let gas: near_primitives::types::Gas = match item.gas {
+        let gas: near_primitives::types::Gas = match item.gas.or_else(crate::project_config::gas) {
             Some(cli_gas) => match cli_gas {
                 crate::common::NearGas { inner: num } => num,
             },
-            None => CallFunctionAction::input_gas(),
-        };
-        let deposit: near_primitives::types::Balance = match item.deposit {
-            Some(cli_deposit) => cli_deposit.to_yoctonear(),
-            None => CallFunctionAction::input_deposit(),
+            None => CallFunctionAction::input_gas()?,
         };
+        let deposit: near_primitives::types::Balance =
+            match item.deposit.or_else(crate::project_config::deposit) {
+                Some(cli_deposit) => cli_deposit.to_yoctonear(),
+                None => CallFunctionAction::input_deposit()?,
+            };
         let send_from = match item.send_from {
             Some(cli_send_from) => super::signer::SendFrom::from(cli_send_from, connection_config)?,
             None => super::signer::SendFrom::choose_send_from(connection_config)?,
@@ -98,26 +122,29 @@ impl CallFunctionAction {
             args,
             gas,
             deposit,
+            abi_file: item.abi_file,
             send_from,
         })
     }
 }
 
 impl CallFunctionAction {
-    fn input_method_name() -> String {
+    fn input_method_name() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter a method name")
+            .require_interactive("<method-name>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_gas() -> near_primitives::types::Gas {
+    fn input_gas() -> color_eyre::eyre::Result<near_primitives::types::Gas> {
         println!();
         let gas: u64 = loop {
             let input_gas: crate::common::NearGas = Input::new()
                 .with_prompt("Enter a gas for function")
                 .with_initial_text("100 TeraGas")
+                .require_interactive("--prepaid-gas")?
                 .interact_text()
                 .unwrap();
             let gas: u64 = match input_gas {
@@ -129,28 +156,31 @@ impl CallFunctionAction {
                 println!("You need to enter a value of no more than 300 TERAGAS")
             }
         };
-        gas
+        Ok(gas)
     }
 
-    fn input_args() -> Vec<u8> {
+    fn input_args(args_template: Option<String>) -> color_eyre::eyre::Result<Vec<u8>> {
         println!();
         let input: String = Input::new()
             .with_prompt("Enter args for function")
+            .with_initial_text(args_template.unwrap_or_default())
+            .require_interactive("<args>")?
             .interact_text()
             .unwrap();
-        input.into_bytes()
+        Ok(input.into_bytes())
     }
 
-    fn input_deposit() -> near_primitives::types::Balance {
+    fn input_deposit() -> color_eyre::eyre::Result<near_primitives::types::Balance> {
         println!();
         let deposit: crate::common::NearBalance = Input::new()
             .with_prompt(
                 "Enter a deposit for function (example: 10NEAR or 0.5near or 10000yoctonear).",
             )
             .with_initial_text("0 NEAR")
+            .require_interactive("--attached-deposit")?
             .interact_text()
             .unwrap();
-        deposit.to_yoctonear()
+        Ok(deposit.to_yoctonear())
     }
 
     pub async fn process(
@@ -158,6 +188,14 @@ impl CallFunctionAction {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_contract_method(
+                connection_config,
+                &prepopulated_unsigned_transaction.receiver_id,
+                &self.method_name,
+            )
+            .await?;
+        }
         let action = near_primitives::transaction::Action::FunctionCall(
             near_primitives::transaction::FunctionCallAction {
                 method_name: self.method_name.clone(),
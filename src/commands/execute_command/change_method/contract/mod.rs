@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -57,6 +58,10 @@ impl SendTo {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        if let Some(connection_config) = &network_connection_config {
+            crate::common::verify_account_exists(connection_config, &self.contract_account_id)
+                .await?;
+        }
         match self {
             SendTo::Contract(receiver) => {
                 receiver
@@ -114,22 +119,12 @@ impl Contract {
         item: CliContract,
         connection_config: Option<crate::common::ConnectionConfig>,
     ) -> color_eyre::eyre::Result<Self> {
-        let contract_account_id: near_primitives::types::AccountId = match item.contract_account_id
+        let contract_account_id: near_primitives::types::AccountId = match item
+            .contract_account_id
+            .or_else(crate::project_config::contract)
         {
-            Some(cli_contract_account_id) => match &connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    network_connection_config,
-                    cli_contract_account_id.clone(),
-                )? {
-                    Some(_) => cli_contract_account_id,
-                    None => {
-                        println!("Account <{}> doesn't exist", cli_contract_account_id);
-                        Contract::input_receiver_account_id(connection_config.clone())?
-                    }
-                },
-                None => cli_contract_account_id,
-            },
-            None => Contract::input_receiver_account_id(connection_config.clone())?,
+            Some(cli_contract_account_id) => cli_contract_account_id,
+            None => Contract::input_receiver_account_id()?,
         };
         let call = match item.call {
             Some(cli_call) => super::CallFunction::from(cli_call, connection_config)?,
@@ -143,26 +138,12 @@ impl Contract {
 }
 
 impl Contract {
-    fn input_receiver_account_id(
-        connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("What is the account ID of the contract?")
-                .interact_text()
-                .unwrap();
-            if let Some(connection_config) = &connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(connection_config, account_id.clone())?
-                {
-                    break Ok(account_id);
-                } else {
-                    println!("Account <{}> doesn't exist", account_id.to_string());
-                }
-            } else {
-                break Ok(account_id);
-            }
-        }
+    fn input_receiver_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the contract?")
+            .require_interactive("<contract-account-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -69,6 +70,7 @@ impl CallFunction {
             .with_prompt("Call your function")
             .items(&commands)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_call = match variants[selection] {
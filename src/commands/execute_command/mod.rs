@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -129,6 +130,7 @@ impl Method {
             .with_prompt("Choose your method")
             .items(&methods)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_method = match variants[selected_method] {
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// Call view function
@@ -10,6 +13,9 @@ use dialoguer::Input;
 pub struct CliCallFunctionView {
     method_name: Option<String>,
     function_args: Option<String>,
+    /// Path to the contract ABI (metadata) file used to offer the contract methods
+    #[clap(long)]
+    abi_file: Option<std::path::PathBuf>,
     #[clap(subcommand)]
     selected_block_id: Option<super::block_id::CliBlockId>,
 }
@@ -18,6 +24,7 @@ pub struct CliCallFunctionView {
 pub struct CallFunctionView {
     method_name: String,
     function_args: Vec<u8>,
+    abi_file: Option<std::path::PathBuf>,
     selected_block_id: super::block_id::BlockId,
 }
 
@@ -34,6 +41,10 @@ impl CliCallFunctionView {
         if let Some(method_name) = &self.method_name {
             args.push_front(method_name.to_string());
         };
+        if let Some(abi_file) = &self.abi_file {
+            args.push_front(abi_file.as_path().display().to_string());
+            args.push_front("--abi-file".to_owned());
+        };
         args
     }
 }
@@ -45,49 +56,69 @@ impl From<CallFunctionView> for CliCallFunctionView {
             function_args: Some(
                 String::from_utf8(call_function_view.function_args).unwrap_or_default(),
             ),
+            abi_file: call_function_view.abi_file,
             selected_block_id: Some(call_function_view.selected_block_id.into()),
         }
     }
 }
 
-impl From<CliCallFunctionView> for CallFunctionView {
-    fn from(item: CliCallFunctionView) -> Self {
+impl TryFrom<CliCallFunctionView> for CallFunctionView {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliCallFunctionView) -> Result<Self, Self::Error> {
+        let mut args_template: Option<String> = None;
         let method_name: String = match item.method_name {
             Some(cli_method_name) => cli_method_name,
-            None => CallFunctionView::input_method_name(),
+            None => {
+                let methods = crate::common::find_contract_methods(item.abi_file.as_deref());
+                match crate::common::select_contract_method(
+                    &methods,
+                    crate::common::ContractMethodKind::Change,
+                )? {
+                    Some(method) => {
+                        args_template = method.args_template();
+                        method.name
+                    }
+                    None => CallFunctionView::input_method_name()?,
+                }
+            }
         };
         let function_args: Vec<u8> = match item.function_args {
             Some(cli_args) => cli_args.into_bytes(),
-            None => CallFunctionView::input_function_args(),
+            None => CallFunctionView::input_function_args(args_template)?,
         };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => cli_block_id.try_into()?,
+            None => super::block_id::BlockId::choose_block_id()?,
         };
-        Self {
+        Ok(Self {
             method_name,
             function_args,
+            abi_file: item.abi_file,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl CallFunctionView {
-    fn input_method_name() -> String {
+    fn input_method_name() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter a method name")
+            .require_interactive("<method-name>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    fn input_function_args() -> Vec<u8> {
+    fn input_function_args(args_template: Option<String>) -> color_eyre::eyre::Result<Vec<u8>> {
         println!();
         let input: String = Input::new()
             .with_prompt("Enter args for function")
+            .with_initial_text(args_template.unwrap_or_default())
+            .require_interactive("<function-args>")?
             .interact_text()
             .unwrap();
-        input.into_bytes()
+        Ok(input.into_bytes())
     }
 
     pub async fn process(
@@ -95,6 +126,12 @@ impl CallFunctionView {
         network_connection_config: crate::common::ConnectionConfig,
         contract_account_id: near_primitives::types::AccountId,
     ) -> crate::CliResult {
+        crate::common::verify_contract_method(
+            &network_connection_config,
+            &contract_account_id,
+            &self.method_name,
+        )
+        .await?;
         self.selected_block_id
             .process(
                 contract_account_id,
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -39,18 +42,20 @@ impl From<CallFunction> for CliCallFunction {
     }
 }
 
-impl From<CliCallFunction> for CallFunction {
-    fn from(item: CliCallFunction) -> Self {
-        match item {
+impl TryFrom<CliCallFunction> for CallFunction {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliCallFunction) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliCallFunction::Call(cli_call_function_view) => {
-                CallFunction::Call(cli_call_function_view.into())
+                CallFunction::Call(cli_call_function_view.try_into()?)
             }
-        }
+        })
     }
 }
 
 impl CallFunction {
-    pub fn choose_call_function() -> Self {
+    pub fn choose_call_function() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = CallFunctionDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
@@ -61,12 +66,13 @@ impl CallFunction {
             .with_prompt("Call your function")
             .items(&commands)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_call = match variants[selection] {
             CallFunctionDiscriminants::Call => CliCallFunction::Call(Default::default()),
         };
-        Self::from(cli_call)
+        Self::try_from(cli_call)
     }
 
     pub async fn process(
@@ -1,3 +1,6 @@
+use std::convert::TryInto;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -32,13 +35,10 @@ impl From<SendTo> for CliSendTo {
 }
 
 impl SendTo {
-    pub fn from(
-        item: CliSendTo,
-        connection_config: crate::common::ConnectionConfig,
-    ) -> color_eyre::eyre::Result<Self> {
+    pub fn from(item: CliSendTo) -> color_eyre::eyre::Result<Self> {
         match item {
             CliSendTo::Contract(cli_receiver) => {
-                let receiver = Receiver::from(cli_receiver, connection_config)?;
+                let receiver = Receiver::from(cli_receiver)?;
                 Ok(Self::Contract(receiver))
             }
         }
@@ -46,10 +46,8 @@ impl SendTo {
 }
 
 impl SendTo {
-    pub fn send_to(
-        connection_config: crate::common::ConnectionConfig,
-    ) -> color_eyre::eyre::Result<Self> {
-        Self::from(CliSendTo::Contract(Default::default()), connection_config)
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
+        Self::from(CliSendTo::Contract(Default::default()))
     }
 
     pub async fn process(
@@ -105,36 +103,17 @@ impl From<Receiver> for CliReceiver {
 }
 
 impl Receiver {
-    fn from(
-        item: CliReceiver,
-        connection_config: crate::common::ConnectionConfig,
-    ) -> color_eyre::eyre::Result<Self> {
-        let contract_account_id: near_primitives::types::AccountId = match item.contract_account_id
+    fn from(item: CliReceiver) -> color_eyre::eyre::Result<Self> {
+        let contract_account_id: near_primitives::types::AccountId = match item
+            .contract_account_id
+            .or_else(crate::project_config::contract)
         {
-            Some(cli_contract_account_id) => {
-                let contract_code_hash: near_primitives::hash::CryptoHash =
-                    match crate::common::get_account_state(
-                        &connection_config,
-                        cli_contract_account_id.clone(),
-                    )? {
-                        Some(account_view) => account_view.code_hash,
-                        None => near_primitives::hash::CryptoHash::default(),
-                    };
-                if contract_code_hash == near_primitives::hash::CryptoHash::default() {
-                    println!(
-                        "Contract code is not deployed to this account <{}>.",
-                        cli_contract_account_id
-                    );
-                    Receiver::input_contract_account_id(connection_config)?
-                } else {
-                    cli_contract_account_id
-                }
-            }
-            None => Receiver::input_contract_account_id(connection_config)?,
+            Some(cli_contract_account_id) => cli_contract_account_id,
+            None => Receiver::input_contract_account_id()?,
         };
         let call = match item.call {
-            Some(cli_call) => cli_call.into(),
-            None => super::CallFunction::choose_call_function(),
+            Some(cli_call) => cli_call.try_into()?,
+            None => super::CallFunction::choose_call_function()?,
         };
         Ok(Self {
             contract_account_id,
@@ -144,37 +123,34 @@ impl Receiver {
 }
 
 impl Receiver {
-    fn input_contract_account_id(
-        connection_config: crate::common::ConnectionConfig,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let contract_account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("What is the account ID of the contract?")
-                .interact_text()
-                .unwrap();
-            let contract_code_hash: near_primitives::hash::CryptoHash =
-                match crate::common::get_account_state(
-                    &connection_config,
-                    contract_account_id.clone(),
-                )? {
-                    Some(account_view) => account_view.code_hash,
-                    None => near_primitives::hash::CryptoHash::default(),
-                };
-            if contract_code_hash == near_primitives::hash::CryptoHash::default() {
-                println!(
-                    "Contract code is not deployed to this account <{}>.",
-                    contract_account_id.to_string()
-                )
-            } else {
-                break Ok(contract_account_id);
-            }
-        }
+    fn input_contract_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the contract?")
+            .require_interactive("<contract-account-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
         self,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
+        let contract_code_hash: near_primitives::hash::CryptoHash =
+            match crate::common::get_account_state(
+                &network_connection_config,
+                self.contract_account_id.clone(),
+            )
+            .await?
+            {
+                Some(account_view) => account_view.code_hash,
+                None => near_primitives::hash::CryptoHash::default(),
+            };
+        if contract_code_hash == near_primitives::hash::CryptoHash::default() {
+            return Err(color_eyre::Report::msg(format!(
+                "Contract code is not deployed to this account <{}>.",
+                self.contract_account_id
+            )));
+        }
         self.call
             .process(network_connection_config, self.contract_account_id)
             .await
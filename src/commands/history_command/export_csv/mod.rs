@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+use std::io::Write;
+
+/// выгрузка журнала транзакций в CSV
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliExportCsv {
+    /// The file to write the CSV to (the standard output by default)
+    #[clap(long)]
+    output: Option<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportCsv {
+    output: Option<std::path::PathBuf>,
+}
+
+impl CliExportCsv {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(output) = &self.output {
+            args.push_front(output.display().to_string());
+            args.push_front("--output".to_owned());
+        }
+        args
+    }
+}
+
+impl From<ExportCsv> for CliExportCsv {
+    fn from(export_csv: ExportCsv) -> Self {
+        Self {
+            output: export_csv.output,
+        }
+    }
+}
+
+impl TryFrom<CliExportCsv> for ExportCsv {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliExportCsv) -> Result<Self, Self::Error> {
+        Ok(Self {
+            output: item.output,
+        })
+    }
+}
+
+/// Quotes the field if it contains a separator, a quote or a line break (RFC 4180)
+fn escape_csv_field(field: &str) -> String {
+    if field.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_csv(entries: &[crate::journal::JournalEntry]) -> String {
+    let mut csv =
+        "id,timestamp,network,status,signer_id,receiver_id,actions,hash,error,transaction\n"
+            .to_string();
+    for (index, entry) in entries.iter().enumerate() {
+        let fields = [
+            (index + 1).to_string(),
+            crate::journal::format_timestamp(entry.timestamp),
+            entry.network.clone().unwrap_or_default(),
+            entry.status.to_string(),
+            entry.signer_id.clone(),
+            entry.receiver_id.clone(),
+            entry.actions.clone(),
+            entry.hash.clone(),
+            entry.error.clone().unwrap_or_default(),
+            entry.transaction.clone(),
+        ];
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+    csv
+}
+
+impl ExportCsv {
+    pub fn process(self) -> crate::CliResult {
+        let csv = to_csv(&crate::journal::load()?);
+        match self.output {
+            Some(output) => {
+                std::fs::File::create(&output)
+                    .and_then(|mut file| file.write_all(csv.as_bytes()))
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to write the file {:?}: {}",
+                            &output, err
+                        ))
+                    })?;
+                println!("The journal is exported to {}", output.display());
+            }
+            None => print!("{}", csv),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_csv_fields() {
+        assert_eq!(escape_csv_field("Transfer(1 NEAR)"), "Transfer(1 NEAR)");
+        assert_eq!(
+            escape_csv_field("Transfer(1 NEAR), FunctionCall(claim)"),
+            "\"Transfer(1 NEAR), FunctionCall(claim)\""
+        );
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
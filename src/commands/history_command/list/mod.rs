@@ -0,0 +1,68 @@
+use std::convert::TryFrom;
+
+/// вывод списка записанных транзакций
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliList {
+    /// The number of the latest transactions to show (all by default)
+    #[clap(long)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct List {
+    limit: Option<usize>,
+}
+
+impl CliList {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(limit) = &self.limit {
+            args.push_front(limit.to_string());
+            args.push_front("--limit".to_owned());
+        }
+        args
+    }
+}
+
+impl From<List> for CliList {
+    fn from(list: List) -> Self {
+        Self { limit: list.limit }
+    }
+}
+
+impl TryFrom<CliList> for List {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliList) -> Result<Self, Self::Error> {
+        Ok(Self { limit: item.limit })
+    }
+}
+
+impl List {
+    pub fn process(self) -> crate::CliResult {
+        let entries = crate::journal::load()?;
+        if entries.is_empty() {
+            println!("There are no recorded transactions.");
+            return Ok(());
+        }
+        let limit = self.limit.unwrap_or_else(|| entries.len());
+        for (index, entry) in entries.iter().enumerate().rev().take(limit) {
+            println!(
+                "#{:<5} {}  {:<11} {:<10} {} -> {}: {}",
+                index + 1,
+                crate::journal::format_timestamp(entry.timestamp),
+                entry.status,
+                entry.network.as_deref().unwrap_or("offline"),
+                entry.signer_id,
+                entry.receiver_id,
+                entry.actions
+            );
+        }
+        Ok(())
+    }
+}
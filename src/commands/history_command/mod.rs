@@ -0,0 +1,189 @@
+use std::convert::TryInto;
+
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod export_csv;
+mod list;
+mod resend;
+mod show;
+
+/// работа с журналом транзакций
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliHistory {
+    #[clap(subcommand)]
+    action: Option<CliHistoryAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct History {
+    pub action: HistoryAction,
+}
+
+impl CliHistory {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<History> for CliHistory {
+    fn from(history: History) -> Self {
+        Self {
+            action: Some(history.action.into()),
+        }
+    }
+}
+
+impl History {
+    pub fn from(item: CliHistory) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => HistoryAction::from(cli_action)?,
+            None => HistoryAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliHistoryAction {
+    /// List the recorded transactions, the latest first
+    List(self::list::CliList),
+    /// Show the recorded transaction
+    Show(self::show::CliShow),
+    /// Send the recorded transaction again; an expired transaction is signed again with a fresh nonce and block hash
+    Resend(self::resend::CliResend),
+    /// Export the journal as CSV
+    ExportCsv(self::export_csv::CliExportCsv),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum HistoryAction {
+    #[strum_discriminants(strum(message = "List the recorded transactions"))]
+    List(self::list::List),
+    #[strum_discriminants(strum(message = "Show a recorded transaction"))]
+    Show(self::show::Show),
+    #[strum_discriminants(strum(message = "Resend a recorded transaction"))]
+    Resend(self::resend::Resend),
+    #[strum_discriminants(strum(message = "Export the journal as CSV"))]
+    ExportCsv(self::export_csv::ExportCsv),
+}
+
+impl CliHistoryAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::List(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("list".to_owned());
+                args
+            }
+            Self::Show(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("show".to_owned());
+                args
+            }
+            Self::Resend(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("resend".to_owned());
+                args
+            }
+            Self::ExportCsv(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("export-csv".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<HistoryAction> for CliHistoryAction {
+    fn from(history_action: HistoryAction) -> Self {
+        match history_action {
+            HistoryAction::List(list) => Self::List(list.into()),
+            HistoryAction::Show(show) => Self::Show(show.into()),
+            HistoryAction::Resend(resend) => Self::Resend(resend.into()),
+            HistoryAction::ExportCsv(export_csv) => Self::ExportCsv(export_csv.into()),
+        }
+    }
+}
+
+impl HistoryAction {
+    fn from(item: CliHistoryAction) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliHistoryAction::List(cli_list) => Ok(HistoryAction::List(cli_list.try_into()?)),
+            CliHistoryAction::Show(cli_show) => Ok(HistoryAction::Show(cli_show.try_into()?)),
+            CliHistoryAction::Resend(cli_resend) => Ok(HistoryAction::Resend(
+                self::resend::Resend::from(cli_resend)?,
+            )),
+            CliHistoryAction::ExportCsv(cli_export_csv) => {
+                Ok(HistoryAction::ExportCsv(cli_export_csv.try_into()?))
+            }
+        }
+    }
+
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = HistoryActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selection] {
+            HistoryActionDiscriminants::List => CliHistoryAction::List(Default::default()),
+            HistoryActionDiscriminants::Show => CliHistoryAction::Show(Default::default()),
+            HistoryActionDiscriminants::Resend => CliHistoryAction::Resend(Default::default()),
+            HistoryActionDiscriminants::ExportCsv => {
+                CliHistoryAction::ExportCsv(Default::default())
+            }
+        };
+        Self::from(cli_action)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::List(list) => list.process(),
+            Self::Show(show) => show.process(),
+            Self::Resend(resend) => resend.process().await,
+            Self::ExportCsv(export_csv) => export_csv.process(),
+        }
+    }
+}
+
+/// Asks for the number of the journal entry shown by `history list`
+fn input_entry_id() -> color_eyre::eyre::Result<usize> {
+    Ok(dialoguer::Input::new()
+        .with_prompt("Enter the number of the transaction in the journal")
+        .require_interactive("<id>")?
+        .interact_text()
+        .unwrap())
+}
+
+/// Returns the journal entry by its number, counted from 1
+fn get_entry(id: usize) -> color_eyre::eyre::Result<crate::journal::JournalEntry> {
+    crate::journal::load()?
+        .into_iter()
+        .nth(id.wrapping_sub(1))
+        .ok_or_else(|| {
+            color_eyre::Report::msg(format!("There is no transaction #{} in the journal", id))
+        })
+}
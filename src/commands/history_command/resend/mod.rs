@@ -0,0 +1,248 @@
+/// повторная отправка записанной транзакции
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliResend {
+    /// The number of the transaction shown by `history list`
+    id: Option<usize>,
+    /// testnet, mainnet, betanet or the RPC URL (the network of the recorded transaction by default)
+    #[clap(long)]
+    network: Option<String>,
+    /// Send the transaction again even if it has already been executed
+    #[clap(long)]
+    force: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Resend {
+    id: usize,
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+    force: bool,
+}
+
+impl CliResend {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if self.force {
+            args.push_front("--force".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+            args.push_front("--network".to_owned());
+        }
+        if let Some(id) = &self.id {
+            args.push_front(id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Resend> for CliResend {
+    fn from(resend: Resend) -> Self {
+        Self {
+            id: Some(resend.id),
+            network: resend
+                .network_connection_config
+                .as_ref()
+                .map(crate::journal::network_name),
+            force: resend.force,
+        }
+    }
+}
+
+impl Resend {
+    pub fn from(item: CliResend) -> color_eyre::eyre::Result<Self> {
+        let network_connection_config = match item.network {
+            Some(network) => Some(
+                crate::journal::network_connection_config_from_name(&network).ok_or_else(|| {
+                    color_eyre::Report::msg(format!(
+                        "<{}> is neither testnet, mainnet, betanet nor an RPC URL",
+                        network
+                    ))
+                })?,
+            ),
+            None => None,
+        };
+        let id = match item.id {
+            Some(id) => id,
+            None => super::input_entry_id()?,
+        };
+        Ok(Self {
+            id,
+            network_connection_config,
+            force: item.force,
+        })
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let entry = super::get_entry(self.id)?;
+        let network_connection_config = match self
+            .network_connection_config
+            .or_else(|| entry.network_connection_config())
+        {
+            Some(network_connection_config) => network_connection_config,
+            None => {
+                return Err(color_eyre::Report::msg(
+                    "The transaction was recorded in the offline mode, choose the network with --network",
+                ))
+            }
+        };
+        let signed_transaction = entry.signed_transaction()?;
+        if !self.force {
+            if entry.status == crate::journal::JournalStatus::Succeeded {
+                return Err(color_eyre::Report::msg(
+                    "The transaction has already succeeded. To execute the same actions once again, use --force.",
+                ));
+            }
+            if is_executed(&network_connection_config, &signed_transaction).await? {
+                return Err(color_eyre::Report::msg(format!(
+                    "The transaction {} has been executed although it was recorded as failed (e.g. the RPC request timed out). To execute the same actions once again, use --force.",
+                    signed_transaction.get_hash()
+                )));
+            }
+        }
+        let transaction_info = if is_refresh_needed(
+            &network_connection_config,
+            &signed_transaction.transaction,
+        )
+        .await?
+        {
+            println!("The transaction has expired or its nonce has been used. It will be signed again with a fresh nonce and block hash.");
+            let signer_private_key = crate::common::get_secret_key_from_keychain(
+                &signed_transaction.transaction.signer_id,
+            )?;
+            if signer_private_key.public_key() != signed_transaction.transaction.public_key {
+                return Err(color_eyre::Report::msg(format!(
+                    "The keychain has no access key {} for the account <{}>. Construct the transaction again.",
+                    signed_transaction.transaction.public_key,
+                    signed_transaction.transaction.signer_id
+                )));
+            }
+            let sign_with_private_key = crate::commands::construct_transaction_command::sign_transaction::sign_with_private_key::SignPrivateKey {
+                signer_public_key: signed_transaction.transaction.public_key.clone(),
+                signer_private_key,
+                signer_private_key_source: crate::common::SecretKeySource::Inline,
+                nonce: None,
+                block_hash: None,
+                submit: Some(crate::commands::construct_transaction_command::sign_transaction::Submit::Send),
+            };
+            sign_with_private_key
+                .process(
+                    signed_transaction.transaction,
+                    Some(network_connection_config.clone()),
+                )
+                .await?
+        } else {
+            crate::commands::construct_transaction_command::sign_transaction::Submit::Send
+                .process_online(
+                    network_connection_config.clone(),
+                    signed_transaction,
+                    entry.transaction,
+                )
+                .await?
+        };
+        match transaction_info {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                );
+                Ok(())
+            }
+            None => Err(color_eyre::Report::msg(
+                "The transaction was not executed, see the error above",
+            )),
+        }
+    }
+}
+
+/// Looks the transaction up on chain: a transaction that reached the chain and failed there
+/// did not execute its actions, so it is not reported as executed
+async fn is_executed(
+    network_connection_config: &crate::common::ConnectionConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+) -> color_eyre::eyre::Result<bool> {
+    let transaction_hash = signed_transaction.get_hash();
+    match crate::rpc::RpcClient::archival(network_connection_config)
+        .tx(
+            transaction_hash.to_string(),
+            signed_transaction.transaction.signer_id.clone(),
+        )
+        .await
+    {
+        Ok(transaction_info) => Ok(!matches!(
+            transaction_info.status,
+            near_primitives::views::FinalExecutionStatus::Failure(_)
+        )),
+        // the node handled the request and doesn't know the transaction
+        Err(err) if crate::rpc::classify_error(&err) == crate::rpc::RpcErrorKind::Handler => {
+            Ok(false)
+        }
+        Err(err) => Err(color_eyre::Report::msg(format!(
+            "Failed to check whether the transaction {} has been executed: {:?}",
+            transaction_hash, err
+        ))),
+    }
+}
+
+/// A signed transaction can't be sent again once its block hash is older than
+/// the validity period or its nonce has been used by the access key
+async fn is_refresh_needed(
+    network_connection_config: &crate::common::ConnectionConfig,
+    transaction: &near_primitives::transaction::Transaction,
+) -> color_eyre::eyre::Result<bool> {
+    let json_rpc_client = crate::rpc::RpcClient::new(network_connection_config);
+    let block_height = match json_rpc_client
+        .block(near_primitives::types::BlockReference::BlockId(
+            near_primitives::types::BlockId::Hash(transaction.block_hash),
+        ))
+        .await
+    {
+        Ok(block) => block.header.height,
+        // the block is unknown to the node, e.g. it has been garbage collected
+        Err(err) if crate::rpc::is_unknown_block_error(&err) => return Ok(true),
+        // anything else (e.g. the node is unreachable) says nothing about the transaction,
+        // and signing it again could execute it twice
+        Err(err) => {
+            return Err(color_eyre::Report::msg(format!(
+                "Failed to fetch the block {} of the transaction: {:?}",
+                transaction.block_hash, err
+            )))
+        }
+    };
+    let status = json_rpc_client.status().await.map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to fetch the network status: {:?}", err))
+    })?;
+    if status
+        .sync_info
+        .latest_block_height
+        .saturating_sub(block_height)
+        >= crate::consts::TRANSACTION_VALIDITY_PERIOD
+    {
+        return Ok(true);
+    }
+    let access_key_response = json_rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id: transaction.signer_id.clone(),
+                public_key: transaction.public_key.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch public key information for nonce: {:?}",
+                err
+            ))
+        })?;
+    match access_key_response.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) => {
+            Ok(access_key.nonce >= transaction.nonce)
+        }
+        _ => Err(color_eyre::Report::msg("Error current_nonce")),
+    }
+}
@@ -0,0 +1,97 @@
+use std::convert::TryFrom;
+
+/// вывод записанной транзакции
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliShow {
+    /// The number of the transaction shown by `history list`
+    id: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Show {
+    id: usize,
+}
+
+impl CliShow {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(id) = &self.id {
+            args.push_front(id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Show> for CliShow {
+    fn from(show: Show) -> Self {
+        Self { id: Some(show.id) }
+    }
+}
+
+impl TryFrom<CliShow> for Show {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliShow) -> Result<Self, Self::Error> {
+        let id = match item.id {
+            Some(id) => id,
+            None => super::input_entry_id()?,
+        };
+        Ok(Self { id })
+    }
+}
+
+impl Show {
+    pub fn process(self) -> crate::CliResult {
+        let entry = super::get_entry(self.id)?;
+        println!("Transaction #{}", self.id);
+        println!(
+            "Recorded at: {} UTC",
+            crate::journal::format_timestamp(entry.timestamp)
+        );
+        println!("Network: {}", entry.network.as_deref().unwrap_or("offline"));
+        println!("Status: {}", entry.status);
+        println!("Hash: {}", entry.hash);
+        if let Some(error) = &entry.error {
+            println!("Error: {}", error);
+        }
+        match entry.status {
+            crate::journal::JournalStatus::Constructed => {
+                let bytes =
+                    near_primitives::serialize::from_base64(&entry.transaction).map_err(|err| {
+                        color_eyre::Report::msg(format!("The journal entry is corrupted: {}", err))
+                    })?;
+                let transaction: near_primitives::transaction::Transaction =
+                    near_primitives::borsh::BorshDeserialize::try_from_slice(&bytes).map_err(
+                        |err| {
+                            color_eyre::Report::msg(format!(
+                                "The journal entry is corrupted: {}",
+                                err
+                            ))
+                        },
+                    )?;
+                println!("\nUnsigned transaction:");
+                crate::common::print_transaction(transaction);
+            }
+            _ => {
+                println!("\nSigned transaction:");
+                crate::common::print_transaction(entry.signed_transaction()?.transaction);
+            }
+        }
+        if let (crate::journal::JournalStatus::Succeeded, Some(network_connection_config)) =
+            (entry.status, entry.network_connection_config())
+        {
+            println!(
+                "\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{}{}",
+                network_connection_config.transaction_explorer(),
+                entry.hash
+            );
+        }
+        println!("\nSerialize_to_base64:\n{}", entry.transaction);
+        Ok(())
+    }
+}
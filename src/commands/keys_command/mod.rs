@@ -0,0 +1,126 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod rotate;
+
+/// работа с ключами в keychain
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliKeys {
+    #[clap(subcommand)]
+    action: Option<CliKeysAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keys {
+    pub action: KeysAction,
+}
+
+impl CliKeys {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Keys> for CliKeys {
+    fn from(keys: Keys) -> Self {
+        Self {
+            action: Some(keys.action.into()),
+        }
+    }
+}
+
+impl Keys {
+    pub fn from(item: CliKeys) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => KeysAction::from(cli_action)?,
+            None => KeysAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+}
+
+impl Keys {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliKeysAction {
+    /// Replace the full access key of the account in the keychain with a new one
+    Rotate(self::rotate::CliRotate),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum KeysAction {
+    #[strum_discriminants(strum(message = "Rotate the access key of an account"))]
+    Rotate(self::rotate::Rotate),
+}
+
+impl CliKeysAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Rotate(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("rotate".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<KeysAction> for CliKeysAction {
+    fn from(keys_action: KeysAction) -> Self {
+        match keys_action {
+            KeysAction::Rotate(rotate) => Self::Rotate(rotate.into()),
+        }
+    }
+}
+
+impl KeysAction {
+    fn from(item: CliKeysAction) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliKeysAction::Rotate(cli_rotate) => {
+                Ok(KeysAction::Rotate(self::rotate::Rotate::from(cli_rotate)?))
+            }
+        }
+    }
+}
+
+impl KeysAction {
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = KeysActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selection] {
+            KeysActionDiscriminants::Rotate => CliKeysAction::Rotate(Default::default()),
+        };
+        Self::from(cli_action)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Rotate(rotate) => rotate.process().await,
+        }
+    }
+}
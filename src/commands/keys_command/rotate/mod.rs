@@ -0,0 +1,289 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use std::str::FromStr;
+
+pub mod operation_mode;
+
+/// Replace the full access key of the account from the keychain with a new one in one transaction
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRotate {
+    /// The account whose keychain key is rotated (defaults to the global --signer)
+    account_id: Option<near_primitives::types::AccountId>,
+    /// The type of the new key: ed25519 (default) or secp256k1
+    #[clap(long)]
+    key_type: Option<near_crypto::KeyType>,
+    #[clap(subcommand)]
+    mode: Option<self::operation_mode::CliMode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rotate {
+    account_id: near_primitives::types::AccountId,
+    key_type: near_crypto::KeyType,
+    mode: self::operation_mode::Mode,
+}
+
+impl CliRotate {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .mode
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Rotate> for CliRotate {
+    fn from(rotate: Rotate) -> Self {
+        Self {
+            account_id: Some(rotate.account_id),
+            key_type: Some(rotate.key_type),
+            mode: Some(rotate.mode.into()),
+        }
+    }
+}
+
+impl Rotate {
+    pub fn from(item: CliRotate) -> color_eyre::eyre::Result<Self> {
+        let account_id = match item.account_id.or_else(crate::network::signer) {
+            Some(account_id) => account_id,
+            None => Rotate::input_account_id()?,
+        };
+        let mode = match item.mode {
+            Some(cli_mode) => self::operation_mode::Mode::from(cli_mode)?,
+            None => self::operation_mode::Mode::choose_mode()?,
+        };
+        Ok(Self {
+            account_id,
+            key_type: item.key_type.unwrap_or(near_crypto::KeyType::ED25519),
+            mode,
+        })
+    }
+}
+
+impl Rotate {
+    fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID whose access key you want to rotate?")
+            .require_interactive("<account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.mode.process(self.account_id, self.key_type).await
+    }
+}
+
+/// Sends AddKey(new) and DeleteKey(old) signed by the old key, waits for the final block to have
+/// the new key and then makes it the keychain key of the account
+pub async fn process(
+    account_id: near_primitives::types::AccountId,
+    key_type: near_crypto::KeyType,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let mut keychain_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    keychain_dir.push(network_connection_config.dir_name());
+    let old_private_key = read_keychain_private_key(&keychain_dir, &account_id)?;
+    let old_public_key = old_private_key.public_key();
+
+    let rpc_client = crate::rpc::RpcClient::new(&network_connection_config);
+    let access_key_response = rpc_client
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id: account_id.clone(),
+                public_key: old_public_key.clone(),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "The keychain key {} is not an access key of <{}>: {:?}",
+                old_public_key, account_id, err
+            ))
+        })?;
+    let old_access_key =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) =
+            access_key_response.kind
+        {
+            access_key
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+        };
+    if !matches!(
+        old_access_key.permission,
+        near_primitives::views::AccessKeyPermissionView::FullAccess
+    ) {
+        return Err(color_eyre::Report::msg(format!(
+            "The keychain key {} of <{}> is not a full access key",
+            old_public_key, account_id
+        )));
+    }
+
+    let key_pair_properties = crate::common::generate_keypair(key_type).await?;
+    let new_public_key = near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+    // The new key is on disk before it can become the only key of the account
+    let new_key_path =
+        crate::common::save_access_key_file(&keychain_dir, &key_pair_properties, &account_id)?;
+    println!(
+        "The new access key {} is saved in a file {}",
+        new_public_key,
+        new_key_path.display()
+    );
+
+    // The signer of the shared flow fills in the nonce and the block hash of the old key
+    let unsigned_transaction = near_primitives::transaction::Transaction {
+        signer_id: account_id.clone(),
+        public_key: old_public_key.clone(),
+        nonce: 0,
+        receiver_id: account_id.clone(),
+        block_hash: Default::default(),
+        actions: vec![
+            near_primitives::transaction::Action::AddKey(
+                near_primitives::transaction::AddKeyAction {
+                    public_key: new_public_key.clone(),
+                    access_key: near_primitives::account::AccessKey {
+                        nonce: 0,
+                        permission: near_primitives::account::AccessKeyPermission::FullAccess,
+                    },
+                },
+            ),
+            near_primitives::transaction::Action::DeleteKey(
+                near_primitives::transaction::DeleteKeyAction {
+                    public_key: old_public_key.clone(),
+                },
+            ),
+        ],
+    };
+    let sign_with_private_key = crate::commands::construct_transaction_command::sign_transaction::sign_with_private_key::SignPrivateKey {
+        signer_public_key: old_public_key.clone(),
+        signer_private_key: old_private_key,
+        signer_private_key_source: crate::common::SecretKeySource::Inline,
+        nonce: None,
+        block_hash: None,
+        submit: Some(crate::commands::construct_transaction_command::sign_transaction::Submit::Send),
+    };
+    println!("Rotating the access key of <{}> ...", account_id);
+    match sign_with_private_key
+        .process(
+            unsigned_transaction,
+            Some(network_connection_config.clone()),
+        )
+        .await?
+    {
+        Some(transaction_info) => {
+            let failed = matches!(
+                transaction_info.status,
+                near_primitives::views::FinalExecutionStatus::Failure(_)
+            );
+            crate::common::print_transaction_status(
+                transaction_info,
+                Some(network_connection_config.clone()),
+            );
+            if failed {
+                return Err(color_eyre::Report::msg(format!(
+                    "The access key of <{}> was not rotated, the keychain is left as is",
+                    account_id
+                )));
+            }
+        }
+        // The transaction may still be executed, so the outcome is looked up on chain below
+        None => println!("Failed to get the transaction outcome"),
+    }
+
+    wait_for_final_access_key(&rpc_client, &account_id, &new_public_key).await?;
+    let backups = crate::common::replace_access_key_in_keychain(
+        &keychain_dir,
+        &key_pair_properties,
+        &account_id,
+        &old_public_key,
+    )?;
+    println!(
+        "\nThe access key of <{}> is rotated: {} replaced {} in the keychain.",
+        account_id, new_public_key, old_public_key
+    );
+    for backup in backups {
+        println!(
+            "The old keychain entry is kept in a file {}",
+            backup.display()
+        );
+    }
+    crate::common::record_command_output("public_key", new_public_key.to_string());
+    Ok(())
+}
+
+/// The default key of the account in the keychain directory of the network
+fn read_keychain_private_key(
+    keychain_dir: &std::path::Path,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    #[derive(serde::Deserialize)]
+    struct KeychainAccessKey {
+        private_key: near_crypto::SecretKey,
+    }
+    let path = keychain_dir.join(format!("{}.json", account_id));
+    let data = std::fs::read_to_string(&path).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "There is no access key of <{}> in the keychain ({:?}): {}",
+            account_id, &path, err
+        ))
+    })?;
+    let access_key: KeychainAccessKey = serde_json::from_str(&data).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", &path, err))
+    })?;
+    Ok(access_key.private_key)
+}
+
+/// Waits until the new key is a full access key of the account in the final block
+async fn wait_for_final_access_key(
+    rpc_client: &crate::rpc::RpcClient,
+    account_id: &near_primitives::types::AccountId,
+    new_public_key: &near_crypto::PublicKey,
+) -> crate::CliResult {
+    println!("Waiting for the new key to be final ...");
+    let started_at = std::time::Instant::now();
+    loop {
+        let query_view_method_response = rpc_client
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: account_id.clone(),
+                    public_key: new_public_key.clone(),
+                },
+            })
+            .await;
+        if let Ok(rpc_query_response) = query_view_method_response {
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) =
+                rpc_query_response.kind
+            {
+                if matches!(
+                    access_key.permission,
+                    near_primitives::views::AccessKeyPermissionView::FullAccess
+                ) {
+                    return Ok(());
+                }
+            }
+        }
+        if started_at.elapsed() >= crate::consts::ACCESS_KEY_ROTATION_TIMEOUT {
+            return Err(color_eyre::Report::msg(format!(
+                "The new key {} of <{}> is not final yet, the keychain is left as is. Once the key is on chain, make the saved file the keychain key of the account.",
+                new_public_key, account_id
+            )));
+        }
+        actix::clock::sleep(crate::consts::ACCESS_KEY_ROTATION_POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,200 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub mod operation_mode;
+
+/// The key derived on the Ledger device and the accounts it has access to
+#[derive(Debug, Clone)]
+struct LedgerKey {
+    seed_phrase_hd_path: slip10::BIP32Path,
+    public_key: near_crypto::PublicKey,
+    implicit_account_id: String,
+    account_ids: Vec<near_primitives::types::AccountId>,
+}
+
+pub async fn process(
+    count: u32,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> crate::CliResult {
+    let mut ledger_keys: Vec<LedgerKey> = vec![];
+    for index in 0..count {
+        let seed_phrase_hd_path =
+            slip10::BIP32Path::from_str(&format!("44'/397'/0'/0'/{}'", index)).unwrap();
+        println!(
+            "Please allow getting the PublicKey on Ledger device (HD Path: {})",
+            seed_phrase_hd_path
+        );
+        let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone())
+            .await
+            .map_err(|near_ledger_error| {
+                color_eyre::Report::msg(format!(
+                    "An error occurred while trying to get PublicKey from Ledger device: {:?}",
+                    near_ledger_error
+                ))
+            })?;
+        let implicit_account_id = hex::encode(&public_key);
+        let public_key = near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
+            public_key.to_bytes(),
+        ));
+        let account_ids = find_account_ids(
+            &network_connection_config,
+            &public_key,
+            &implicit_account_id,
+        )
+        .await?;
+        println!(
+            "\nSeed Phrase HD Path: {}\nPublic Key: {}\nImplicit Account ID: {}\nAccounts: {}\n",
+            seed_phrase_hd_path,
+            public_key,
+            implicit_account_id,
+            if account_ids.is_empty() {
+                "none found".to_string()
+            } else {
+                account_ids
+                    .iter()
+                    .map(|account_id| account_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        );
+        ledger_keys.push(LedgerKey {
+            seed_phrase_hd_path,
+            public_key,
+            implicit_account_id,
+            account_ids,
+        });
+    }
+    save_ledger_hd_path(&ledger_keys)
+}
+
+/// Looks up the accounts which have the public key as an access key:
+/// the implicit account and the accounts known to the helper of the network
+async fn find_account_ids(
+    network_connection_config: &crate::common::ConnectionConfig,
+    public_key: &near_crypto::PublicKey,
+    implicit_account_id: &str,
+) -> color_eyre::eyre::Result<Vec<near_primitives::types::AccountId>> {
+    let mut account_ids: Vec<near_primitives::types::AccountId> = vec![];
+    if let Some(helper_url) = network_connection_config.helper_url() {
+        let url = helper_url.join(&format!("publicKey/{}/accounts", public_key))?;
+        match awc::Client::default().get(url.as_str()).send().await {
+            Ok(mut response) => match response.json::<Vec<String>>().await {
+                Ok(helper_account_ids) => {
+                    for account_id in helper_account_ids {
+                        if let Ok(account_id) =
+                            near_primitives::types::AccountId::try_from(account_id)
+                        {
+                            account_ids.push(account_id);
+                        }
+                    }
+                }
+                Err(err) => println!("Failed to parse the response of the helper: {}", err),
+            },
+            Err(err) => println!("Failed to look up the accounts with the helper: {}", err),
+        }
+    }
+    let implicit_account_id =
+        near_primitives::types::AccountId::try_from(implicit_account_id.to_string())?;
+    if !account_ids.contains(&implicit_account_id) {
+        let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: implicit_account_id.clone(),
+                    public_key: public_key.clone(),
+                },
+            })
+            .await;
+        if query_view_method_response.is_ok() {
+            account_ids.insert(0, implicit_account_id);
+        }
+    }
+    Ok(account_ids)
+}
+
+fn save_ledger_hd_path(ledger_keys: &[LedgerKey]) -> crate::CliResult {
+    if ledger_keys.is_empty() || !crate::interactive::is_interactive() {
+        return Ok(());
+    }
+    let mut choices: Vec<(
+        Option<&LedgerKey>,
+        Option<near_primitives::types::AccountId>,
+        String,
+    )> = vec![];
+    for ledger_key in ledger_keys {
+        for account_id in &ledger_key.account_ids {
+            choices.push((
+                Some(ledger_key),
+                Some(account_id.clone()),
+                format!(
+                    "Save the HD path {} for <{}>",
+                    ledger_key.seed_phrase_hd_path, account_id
+                ),
+            ));
+        }
+    }
+    choices.push((
+        None,
+        None,
+        "Save an HD path for another account ID".to_string(),
+    ));
+    choices.push((None, None, "Don't save the HD path".to_string()));
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Do you want to save the HD path for later Ledger signing?")
+        .items(
+            &choices
+                .iter()
+                .map(|(_, _, message)| message.as_str())
+                .collect::<Vec<_>>(),
+        )
+        .default(0)
+        .require_interactive_choice()?
+        .interact()
+        .unwrap();
+    let (seed_phrase_hd_path, account_id) = match &choices[selection] {
+        (Some(ledger_key), Some(account_id), _) => {
+            (ledger_key.seed_phrase_hd_path.clone(), account_id.clone())
+        }
+        _ if selection == choices.len() - 1 => return Ok(()),
+        _ => {
+            let key_selection = Select::with_theme(&ColorfulTheme::default())
+                .with_prompt("Which key do you want to use?")
+                .items(
+                    &ledger_keys
+                        .iter()
+                        .map(|ledger_key| {
+                            format!(
+                                "{} ({}, implicit account {})",
+                                ledger_key.seed_phrase_hd_path,
+                                ledger_key.public_key,
+                                ledger_key.implicit_account_id
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .default(0)
+                .require_interactive_choice()?
+                .interact()
+                .unwrap();
+            let account_id: near_primitives::types::AccountId = Input::new()
+                .with_prompt("What is the account ID?")
+                .require_interactive("<account-id>")?
+                .interact_text()
+                .unwrap();
+            (
+                ledger_keys[key_selection].seed_phrase_hd_path.clone(),
+                account_id,
+            )
+        }
+    };
+    let mut config = crate::config::get();
+    config.set_ledger_hd_path(&account_id, &seed_phrase_hd_path);
+    crate::config::set(config)?;
+    println!(
+        "The HD path {} is saved for <{}>; it will be used for Ledger signing with this account.",
+        seed_phrase_hd_path, account_id
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+/// аргументы, необходимые для создания транзакции в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    /// How many HD indices to walk, starting from 0
+    #[clap(long)]
+    count: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    count: u32,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(count) = &self.count {
+            args.push_front(count.to_string());
+            args.push_front("--count".to_string());
+        }
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+            count: Some(network_args.count),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let count = match item.count {
+            Some(count) => count,
+            None => NetworkArgs::input_count()?,
+        };
+        Ok(Self {
+            selected_server,
+            count,
+        })
+    }
+}
+
+impl NetworkArgs {
+    fn input_count() -> color_eyre::eyre::Result<u32> {
+        Ok(Input::new()
+            .with_prompt("How many HD indices do you want to check on the Ledger device?")
+            .default(5)
+            .require_interactive("--count")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        super::super::process(self.count, self.selected_server.connection_config).await
+    }
+}
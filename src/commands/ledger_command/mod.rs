@@ -0,0 +1,126 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod accounts;
+
+/// работа с ключами устройства Ledger
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliLedger {
+    #[clap(subcommand)]
+    action: Option<CliLedgerAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Ledger {
+    pub action: LedgerAction,
+}
+
+impl CliLedger {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Ledger> for CliLedger {
+    fn from(ledger: Ledger) -> Self {
+        Self {
+            action: Some(ledger.action.into()),
+        }
+    }
+}
+
+impl Ledger {
+    pub fn from(item: CliLedger) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => LedgerAction::from(cli_action)?,
+            None => LedgerAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+}
+
+impl Ledger {
+    pub async fn process(self) -> crate::CliResult {
+        self.action.process().await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliLedgerAction {
+    /// Walk the HD indices of the Ledger device, show the keys and their accounts, and save the HD path for an account
+    Accounts(self::accounts::operation_mode::CliOperationMode),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum LedgerAction {
+    #[strum_discriminants(strum(message = "Find the accounts of the Ledger keys"))]
+    Accounts(self::accounts::operation_mode::OperationMode),
+}
+
+impl CliLedgerAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Accounts(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("accounts".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<LedgerAction> for CliLedgerAction {
+    fn from(ledger_action: LedgerAction) -> Self {
+        match ledger_action {
+            LedgerAction::Accounts(operation_mode) => Self::Accounts(operation_mode.into()),
+        }
+    }
+}
+
+impl LedgerAction {
+    fn from(item: CliLedgerAction) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliLedgerAction::Accounts(cli_operation_mode) => Ok(LedgerAction::Accounts(
+                self::accounts::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+        }
+    }
+}
+
+impl LedgerAction {
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = LedgerActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selection] {
+            LedgerActionDiscriminants::Accounts => CliLedgerAction::Accounts(Default::default()),
+        };
+        Self::from(cli_action)
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        match self {
+            Self::Accounts(operation_mode) => operation_mode.process().await,
+        }
+    }
+}
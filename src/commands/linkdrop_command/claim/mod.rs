@@ -0,0 +1,365 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::str::FromStr;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+pub mod operation_mode;
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliClaimWith {
+    /// Specify the secret key of the drop
+    SecretKey(CliClaim),
+}
+
+#[derive(Debug, Clone)]
+pub enum ClaimWith {
+    SecretKey(Claim),
+}
+
+impl CliClaimWith {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SecretKey(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("secret-key".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<ClaimWith> for CliClaimWith {
+    fn from(claim_with: ClaimWith) -> Self {
+        match claim_with {
+            ClaimWith::SecretKey(claim) => Self::SecretKey(claim.into()),
+        }
+    }
+}
+
+impl ClaimWith {
+    pub fn from(
+        item: CliClaimWith,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliClaimWith::SecretKey(cli_claim) => {
+                Ok(Self::SecretKey(Claim::from(cli_claim, connection_config)?))
+            }
+        }
+    }
+
+    pub fn claim_with(
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        Self::from(
+            CliClaimWith::SecretKey(Default::default()),
+            connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            ClaimWith::SecretKey(claim) => {
+                claim
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// Specify the secret key of the drop (from the claim link)
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliClaim {
+    secret_key: Option<String>,
+    /// Create a new account with the drop
+    #[clap(long)]
+    new_account: Option<near_primitives::types::AccountId>,
+    /// Claim the drop to an existing account
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    /// The linkdrop contract (the top-level account of the network by default)
+    #[clap(long)]
+    contract_id: Option<near_primitives::types::AccountId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Claim {
+    secret_key: near_crypto::SecretKey,
+    contract_id: near_primitives::types::AccountId,
+    claim_to: ClaimTo,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum ClaimTo {
+    #[strum_discriminants(strum(message = "Create a new account with the drop"))]
+    NewAccount(near_primitives::types::AccountId),
+    #[strum_discriminants(strum(message = "Claim the drop to an existing account"))]
+    ExistingAccount(near_primitives::types::AccountId),
+}
+
+impl CliClaim {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(contract_id) = &self.contract_id {
+            args.push_front(contract_id.to_string());
+            args.push_front("--contract-id".to_owned());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_owned());
+        }
+        if let Some(new_account) = &self.new_account {
+            args.push_front(new_account.to_string());
+            args.push_front("--new-account".to_owned());
+        }
+        if self.secret_key.is_some() {
+            args.push_front(crate::consts::REDACTED_SECRET.to_owned());
+        }
+        args
+    }
+}
+
+impl From<Claim> for CliClaim {
+    fn from(claim: Claim) -> Self {
+        let (new_account, account_id) = match claim.claim_to {
+            ClaimTo::NewAccount(new_account_id) => (Some(new_account_id), None),
+            ClaimTo::ExistingAccount(account_id) => (None, Some(account_id)),
+        };
+        Self {
+            secret_key: Some(claim.secret_key.to_string()),
+            new_account,
+            account_id,
+            contract_id: Some(claim.contract_id),
+        }
+    }
+}
+
+impl Claim {
+    pub fn from(
+        item: CliClaim,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let secret_key = match item.secret_key {
+            Some(cli_secret_key) => parse_secret_key(&cli_secret_key)?,
+            None => Claim::input_secret_key()?,
+        };
+        let contract_id: near_primitives::types::AccountId = match item
+            .contract_id
+            .or_else(|| connection_config.linkdrop_account_id())
+        {
+            Some(contract_id) => contract_id,
+            None => Input::new()
+                .with_prompt("What is the account ID of the linkdrop contract?")
+                .require_interactive("--contract-id")?
+                .interact_text()
+                .unwrap(),
+        };
+        let claim_to = match (item.new_account, item.account_id) {
+            (Some(_), Some(_)) => {
+                return Err(color_eyre::Report::msg(
+                    "Specify either --new-account or --account-id, not both",
+                ))
+            }
+            (Some(new_account_id), None) => ClaimTo::NewAccount(new_account_id),
+            (None, Some(account_id)) => ClaimTo::ExistingAccount(account_id),
+            (None, None) => Claim::choose_claim_to()?,
+        };
+        Ok(Self {
+            secret_key,
+            contract_id,
+            claim_to,
+        })
+    }
+
+    fn input_secret_key() -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+        Ok(loop {
+            let secret_key: String = Input::new()
+                .with_prompt("Enter the secret key of the drop (the last part of the claim link)")
+                .require_interactive("<secret-key>")?
+                .interact_text()
+                .unwrap();
+            match parse_secret_key(&secret_key) {
+                Ok(secret_key) => break secret_key,
+                Err(err) => println!("{}", err),
+            }
+        })
+    }
+
+    fn choose_claim_to() -> color_eyre::eyre::Result<ClaimTo> {
+        let variants = ClaimToDiscriminants::iter().collect::<Vec<_>>();
+        let claim_to = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to claim the drop?")
+            .items(&claim_to)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        Ok(match variants[selection] {
+            ClaimToDiscriminants::NewAccount => {
+                ClaimTo::NewAccount(Claim::input_account_id(false)?)
+            }
+            ClaimToDiscriminants::ExistingAccount => {
+                ClaimTo::ExistingAccount(Claim::input_account_id(true)?)
+            }
+        })
+    }
+
+    fn input_account_id(
+        is_existing: bool,
+    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt(if is_existing {
+                "What is the account ID to receive the drop?"
+            } else {
+                "What is the new account ID? (example: alice.testnet)"
+            })
+            .require_interactive("--account-id")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let public_key = self.secret_key.public_key();
+        let (method_name, args, new_key_pair_properties) = match &self.claim_to {
+            ClaimTo::NewAccount(new_account_id) => {
+                crate::common::verify_account_does_not_exist(
+                    &network_connection_config,
+                    new_account_id,
+                )
+                .await?;
+                let key_pair_properties =
+                    crate::common::generate_keypair(near_crypto::KeyType::ED25519).await?;
+                let args = serde_json::json!({
+                    "new_account_id": new_account_id.to_string(),
+                    "new_public_key": key_pair_properties.public_key_str,
+                });
+                ("create_account_and_claim", args, Some(key_pair_properties))
+            }
+            ClaimTo::ExistingAccount(account_id) => {
+                crate::common::verify_account_exists(&network_connection_config, account_id)
+                    .await?;
+                let args = serde_json::json!({
+                    "account_id": account_id.to_string(),
+                });
+                ("claim", args, None)
+            }
+        };
+        // The drop key is a function-call key of the linkdrop contract, so the contract signs for itself
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.contract_id.clone(),
+            public_key: public_key.clone(),
+            receiver_id: self.contract_id.clone(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args: args.to_string().into_bytes(),
+                    gas: crate::consts::LINKDROP_CLAIM_GAS,
+                    deposit: 0,
+                },
+            )],
+            ..prepopulated_unsigned_transaction
+        };
+        let sign_with_private_key = crate::commands::construct_transaction_command::sign_transaction::sign_with_private_key::SignPrivateKey {
+            signer_public_key: public_key,
+            signer_private_key: self.secret_key.clone(),
+            signer_private_key_source: crate::common::SecretKeySource::Inline,
+            nonce: None,
+            block_hash: None,
+            submit: Some(crate::commands::construct_transaction_command::sign_transaction::Submit::Send),
+        };
+        let transaction_info = match sign_with_private_key
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => transaction_info,
+            None => return Ok(()),
+        };
+        let is_claimed = is_claimed(&transaction_info.status);
+        crate::common::print_transaction_status(
+            transaction_info,
+            Some(network_connection_config.clone()),
+        );
+        if !is_claimed {
+            return Err(color_eyre::Report::msg("The drop was not claimed"));
+        }
+        if let (ClaimTo::NewAccount(new_account_id), Some(key_pair_properties)) =
+            (&self.claim_to, new_key_pair_properties)
+        {
+            crate::common::save_access_key_to_keychain(
+                Some(network_connection_config),
+                key_pair_properties,
+                &new_account_id.to_string(),
+                None,
+            )
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The claim links carry the secret key without the key type
+fn parse_secret_key(secret_key: &str) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    let secret_key = if secret_key.contains(':') {
+        secret_key.to_string()
+    } else {
+        format!("ed25519:{}", secret_key)
+    };
+    near_crypto::SecretKey::from_str(&secret_key)
+        .map_err(|err| color_eyre::Report::msg(format!("Invalid secret key: {}", err)))
+}
+
+/// `create_account_and_claim` returns `false` when the new account is not created
+fn is_claimed(status: &near_primitives::views::FinalExecutionStatus) -> bool {
+    match status {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(value) => {
+            near_primitives::serialize::from_base64(value)
+                .map(|value| value != b"false")
+                .unwrap_or(true)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_secret_key_from_claim_link() {
+        let secret_key = "3D4YudUahN1nawWogh8pAKSj92sUNMdbZGjn7kERKzYoTy8tnFQuwoGUC51DowKqorvkr2pytJSnwuSbsNVfqygr";
+        assert_eq!(
+            parse_secret_key(secret_key).unwrap(),
+            parse_secret_key(&format!("ed25519:{}", secret_key)).unwrap()
+        );
+        assert!(!is_claimed(
+            &near_primitives::views::FinalExecutionStatus::SuccessValue(
+                near_primitives::serialize::to_base64(b"false")
+            )
+        ));
+    }
+}
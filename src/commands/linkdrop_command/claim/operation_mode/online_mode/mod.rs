@@ -0,0 +1,71 @@
+/// аргументы, необходимые для создания транзакции в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    #[clap(subcommand)]
+    pub claim_with: Option<super::super::CliClaimWith>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    pub claim_with: super::super::ClaimWith,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let args = self
+            .claim_with
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+            claim_with: Some(network_args.claim_with.into()),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let connection_config = selected_server.connection_config.clone();
+        let claim_with = match item.claim_with {
+            Some(cli_claim_with) => {
+                super::super::ClaimWith::from(cli_claim_with, connection_config.clone())?
+            }
+            None => super::super::ClaimWith::claim_with(connection_config.clone())?,
+        };
+        Ok(Self {
+            selected_server,
+            claim_with,
+        })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        self.claim_with
+            .process(
+                prepopulated_unsigned_transaction,
+                self.selected_server.connection_config,
+            )
+            .await
+    }
+}
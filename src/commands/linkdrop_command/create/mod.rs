@@ -0,0 +1,440 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+pub mod operation_mode;
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSendFrom {
+    /// Specify the account that funds the drops
+    Account(CliDrops),
+}
+
+#[derive(Debug, Clone)]
+pub enum SendFrom {
+    Account(Drops),
+}
+
+impl CliSendFrom {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<SendFrom> for CliSendFrom {
+    fn from(send_from: SendFrom) -> Self {
+        match send_from {
+            SendFrom::Account(drops) => Self::Account(drops.into()),
+        }
+    }
+}
+
+impl SendFrom {
+    pub fn from(
+        item: CliSendFrom,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendFrom::Account(cli_drops) => {
+                Ok(Self::Account(Drops::from(cli_drops, connection_config)?))
+            }
+        }
+    }
+
+    pub fn send_from(
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        Self::from(CliSendFrom::Account(Default::default()), connection_config)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendFrom::Account(drops) => {
+                drops
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// Specify the account that funds the drops
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliDrops {
+    signer_account_id: Option<near_primitives::types::AccountId>,
+    /// The linkdrop contract (the top-level account of the network by default)
+    #[clap(long)]
+    contract_id: Option<near_primitives::types::AccountId>,
+    /// The amount of every drop
+    #[clap(long)]
+    amount: Option<crate::common::NearBalance>,
+    /// How many drops to create
+    #[clap(long)]
+    count: Option<u32>,
+    /// The file to save the secret keys and the claim links to
+    #[clap(long)]
+    save_to: Option<std::path::PathBuf>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug, Clone)]
+pub struct Drops {
+    signer_account_id: near_primitives::types::AccountId,
+    contract_id: near_primitives::types::AccountId,
+    amount: crate::common::NearBalance,
+    count: u32,
+    save_to: Option<std::path::PathBuf>,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl CliDrops {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(save_to) = &self.save_to {
+            args.push_front(save_to.display().to_string());
+            args.push_front("--save-to".to_owned());
+        }
+        if let Some(count) = &self.count {
+            args.push_front(count.to_string());
+            args.push_front("--count".to_owned());
+        }
+        if let Some(amount) = &self.amount {
+            args.push_front(amount.to_string());
+            args.push_front("--amount".to_owned());
+        }
+        if let Some(contract_id) = &self.contract_id {
+            args.push_front(contract_id.to_string());
+            args.push_front("--contract-id".to_owned());
+        }
+        if let Some(signer_account_id) = &self.signer_account_id {
+            args.push_front(signer_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Drops> for CliDrops {
+    fn from(drops: Drops) -> Self {
+        Self {
+            signer_account_id: Some(drops.signer_account_id),
+            contract_id: Some(drops.contract_id),
+            amount: Some(drops.amount),
+            count: Some(drops.count),
+            save_to: drops.save_to,
+            sign_option: Some(drops.sign_option.into()),
+        }
+    }
+}
+
+impl Drops {
+    pub fn from(
+        item: CliDrops,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let signer_account_id: near_primitives::types::AccountId =
+            match item.signer_account_id.or_else(crate::network::signer) {
+                Some(cli_signer_account_id) => cli_signer_account_id,
+                None => Drops::input_signer_account_id()?,
+            };
+        let contract_id: near_primitives::types::AccountId = match item
+            .contract_id
+            .or_else(|| connection_config.linkdrop_account_id())
+        {
+            Some(contract_id) => contract_id,
+            None => Input::new()
+                .with_prompt("What is the account ID of the linkdrop contract?")
+                .require_interactive("--contract-id")?
+                .interact_text()
+                .unwrap(),
+        };
+        let amount: crate::common::NearBalance = match item.amount {
+            Some(cli_amount) if is_enough_for_drop(&cli_amount) => cli_amount,
+            Some(cli_amount) => {
+                println!(
+                    "The amount of a drop must be greater than the claim key allowance ({}), but {} was given",
+                    crate::common::NearBalance::from_yoctonear(
+                        crate::consts::LINKDROP_ACCESS_KEY_ALLOWANCE
+                    ),
+                    cli_amount
+                );
+                Drops::input_amount()?
+            }
+            None => Drops::input_amount()?,
+        };
+        let count: u32 = match item.count {
+            Some(cli_count) if cli_count > 0 => cli_count,
+            _ => loop {
+                let count: u32 = Input::new()
+                    .with_prompt("How many drops do you want to create?")
+                    .default(1)
+                    .require_interactive("--count")?
+                    .interact_text()
+                    .unwrap();
+                if count > 0 {
+                    break count;
+                }
+                println!("Create at least one drop");
+            },
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), signer_account_id.clone())?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), signer_account_id.clone())?,
+        };
+        Ok(Self {
+            signer_account_id,
+            contract_id,
+            amount,
+            count,
+            save_to: item.save_to,
+            sign_option,
+        })
+    }
+
+    fn input_signer_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID that funds the drops?")
+            .require_interactive("<signer-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    fn input_amount() -> color_eyre::eyre::Result<crate::common::NearBalance> {
+        Ok(loop {
+            let amount: crate::common::NearBalance = Input::new()
+                .with_prompt(format!(
+                    "How many NEAR Tokens do you want to put in every drop? (more than {})",
+                    crate::common::NearBalance::from_yoctonear(
+                        crate::consts::LINKDROP_ACCESS_KEY_ALLOWANCE
+                    )
+                ))
+                .require_interactive("--amount")?
+                .interact_text()
+                .unwrap();
+            if is_enough_for_drop(&amount) {
+                break amount;
+            }
+            println!("The amount of a drop must be greater than the claim key allowance");
+        })
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        crate::common::verify_account_exists(&network_connection_config, &self.signer_account_id)
+            .await?;
+        let mut key_pairs: Vec<crate::common::KeyPairProperties> = vec![];
+        for _ in 0..self.count {
+            key_pairs.push(crate::common::generate_keypair(near_crypto::KeyType::ED25519).await?);
+        }
+        let drops = key_pairs
+            .iter()
+            .map(|key_pair_properties| {
+                Ok(serde_json::json!({
+                    "public_key": key_pair_properties.public_key_str,
+                    "secret_key": key_pair_properties.secret_keypair_str,
+                    "link": claim_link(
+                        &network_connection_config,
+                        &self.contract_id,
+                        &key_pair_properties.secret_keypair_str,
+                    )?
+                    .to_string(),
+                }))
+            })
+            .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+        // The secret keys are saved before the transaction is sent, so the funds are never lost
+        let file_path = match &self.save_to {
+            Some(save_to) => save_to.clone(),
+            None => default_drops_file_path(&self.signer_account_id)?,
+        };
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(
+            &file_path,
+            serde_json::to_string_pretty(&serde_json::json!({
+                "contract_id": self.contract_id.to_string(),
+                "amount": self.amount.to_string(),
+                "drops": drops,
+            }))?,
+        )
+        .map_err(|err| color_eyre::Report::msg(format!("Failed to save the drops: {}", err)))?;
+        println!(
+            "The secret keys of the drops are saved in a file {}",
+            file_path.display()
+        );
+
+        let actions: Vec<near_primitives::transaction::Action> = key_pairs
+            .iter()
+            .map(|key_pair_properties| {
+                near_primitives::transaction::Action::FunctionCall(
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: "send".to_string(),
+                        args: serde_json::json!({
+                            "public_key": key_pair_properties.public_key_str,
+                        })
+                        .to_string()
+                        .into_bytes(),
+                        gas: crate::consts::LINKDROP_SEND_GAS,
+                        deposit: self.amount.to_yoctonear(),
+                    },
+                )
+            })
+            .collect();
+        // Every `send` call attaches LINKDROP_SEND_GAS, so the drops are split to stay within
+        // the gas limit of a transaction
+        let drops_per_transaction =
+            (crate::consts::MAX_TRANSACTION_GAS / crate::consts::LINKDROP_SEND_GAS) as usize;
+        let transactions_count =
+            (actions.len() + drops_per_transaction - 1) / drops_per_transaction;
+        // Only the drops of the transactions confirmed on chain can be claimed
+        let mut created_drops: Vec<serde_json::Value> = vec![];
+        for (index, (actions, batch_drops)) in actions
+            .chunks(drops_per_transaction)
+            .zip(drops.chunks(drops_per_transaction))
+            .enumerate()
+        {
+            if transactions_count > 1 {
+                println!(
+                    "\nCreating the drops {}-{} of {} (transaction {} of {}) ...",
+                    index * drops_per_transaction + 1,
+                    index * drops_per_transaction + actions.len(),
+                    self.count,
+                    index + 1,
+                    transactions_count
+                );
+            }
+            let unsigned_transaction = near_primitives::transaction::Transaction {
+                signer_id: self.signer_account_id.clone(),
+                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                receiver_id: self.contract_id.clone(),
+                actions: actions.to_vec(),
+                ..prepopulated_unsigned_transaction.clone()
+            };
+            let failure = match self
+                .sign_option
+                .clone()
+                .process(
+                    unsigned_transaction,
+                    Some(network_connection_config.clone()),
+                )
+                .await
+            {
+                Ok(Some(transaction_info)) => {
+                    let is_failed = matches!(
+                        transaction_info.status,
+                        near_primitives::views::FinalExecutionStatus::Failure(_)
+                    );
+                    crate::common::print_transaction_status(
+                        transaction_info,
+                        Some(network_connection_config.clone()),
+                    );
+                    if is_failed {
+                        Some("the transaction failed".to_string())
+                    } else {
+                        None
+                    }
+                }
+                // The transaction is only signed or displayed, or it could not be sent
+                Ok(None) => Some("the transaction was not sent".to_string()),
+                Err(err) => Some(err.to_string()),
+            };
+            match failure {
+                None => created_drops.extend_from_slice(batch_drops),
+                Some(reason) => {
+                    print_claim_links(&created_drops);
+                    return Err(color_eyre::Report::msg(format!(
+                        "{} of {} drops were created ({}), the other links in the file don't work",
+                        created_drops.len(),
+                        self.count,
+                        reason
+                    )));
+                }
+            }
+        }
+        print_claim_links(&created_drops);
+        Ok(())
+    }
+}
+
+fn print_claim_links(drops: &[serde_json::Value]) {
+    if drops.is_empty() {
+        return;
+    }
+    println!("\nClaim links:");
+    for linkdrop in drops {
+        println!("  {}", linkdrop["link"].as_str().unwrap_or_default());
+    }
+}
+
+fn is_enough_for_drop(amount: &crate::common::NearBalance) -> bool {
+    amount.to_yoctonear() > crate::consts::LINKDROP_ACCESS_KEY_ALLOWANCE
+}
+
+/// The wallet link that claims the drop with the secret key
+fn claim_link(
+    network_connection_config: &crate::common::ConnectionConfig,
+    contract_id: &near_primitives::types::AccountId,
+    secret_key: &str,
+) -> color_eyre::eyre::Result<url::Url> {
+    let secret_key = secret_key.rsplit(':').next().unwrap_or(secret_key);
+    Ok(network_connection_config
+        .wallet_url()
+        .join(&format!("linkdrop/{}/{}", contract_id, secret_key))?)
+}
+
+fn default_drops_file_path(
+    signer_account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+    let mut path = std::path::PathBuf::from(&home_dir);
+    path.push(crate::consts::DIR_NAME_LINKDROPS);
+    path.push(format!("{}-{}.json", signer_account_id, timestamp));
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn claim_link_uses_the_wallet_of_the_network() {
+        let contract_id = near_primitives::types::AccountId::from_str("testnet").unwrap();
+        let link = claim_link(
+            &crate::common::ConnectionConfig::Testnet,
+            &contract_id,
+            "ed25519:3D4YudUahN1nawWogh8pAKSj92sUNMdbZGjn7kERKzYoTy8tnFQuwoGUC51DowKqorvkr2pytJSnwuSbsNVfqygr",
+        )
+        .unwrap();
+        assert_eq!(
+            link.as_str(),
+            "https://wallet.testnet.near.org/linkdrop/testnet/3D4YudUahN1nawWogh8pAKSj92sUNMdbZGjn7kERKzYoTy8tnFQuwoGUC51DowKqorvkr2pytJSnwuSbsNVfqygr"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+mod claim;
+mod create;
+
+/// работа с дропами linkdrop-контракта
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliLinkdrop {
+    #[clap(subcommand)]
+    action: Option<CliLinkdropAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Linkdrop {
+    pub action: LinkdropAction,
+}
+
+impl CliLinkdrop {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        self.action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Linkdrop> for CliLinkdrop {
+    fn from(linkdrop: Linkdrop) -> Self {
+        Self {
+            action: Some(linkdrop.action.into()),
+        }
+    }
+}
+
+impl Linkdrop {
+    pub fn from(item: CliLinkdrop) -> color_eyre::eyre::Result<Self> {
+        let action = match item.action {
+            Some(cli_action) => LinkdropAction::from(cli_action)?,
+            None => LinkdropAction::choose_action()?,
+        };
+        Ok(Self { action })
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        self.action.process(prepopulated_unsigned_transaction).await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+enum CliLinkdropAction {
+    /// Generate the drop keys, fund them on the linkdrop contract and save the claim links
+    Create(self::create::operation_mode::CliOperationMode),
+    /// Claim a drop to a new or an existing account with the secret key of the drop
+    Claim(self::claim::operation_mode::CliOperationMode),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum LinkdropAction {
+    #[strum_discriminants(strum(message = "Create drops"))]
+    Create(self::create::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Claim a drop"))]
+    Claim(self::claim::operation_mode::OperationMode),
+}
+
+impl CliLinkdropAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Create(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("create".to_owned());
+                args
+            }
+            Self::Claim(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("claim".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<LinkdropAction> for CliLinkdropAction {
+    fn from(linkdrop_action: LinkdropAction) -> Self {
+        match linkdrop_action {
+            LinkdropAction::Create(operation_mode) => Self::Create(operation_mode.into()),
+            LinkdropAction::Claim(operation_mode) => Self::Claim(operation_mode.into()),
+        }
+    }
+}
+
+impl LinkdropAction {
+    fn from(item: CliLinkdropAction) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliLinkdropAction::Create(cli_operation_mode) => Ok(LinkdropAction::Create(
+                self::create::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+            CliLinkdropAction::Claim(cli_operation_mode) => Ok(LinkdropAction::Claim(
+                self::claim::operation_mode::OperationMode::from(cli_operation_mode)?,
+            )),
+        }
+    }
+
+    fn choose_action() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = LinkdropActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Choose your action")
+            .items(&actions)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selection] {
+            LinkdropActionDiscriminants::Create => CliLinkdropAction::Create(Default::default()),
+            LinkdropActionDiscriminants::Claim => CliLinkdropAction::Claim(Default::default()),
+        };
+        Self::from(cli_action)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> crate::CliResult {
+        match self {
+            Self::Create(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+            Self::Claim(operation_mode) => {
+                operation_mode
+                    .process(prepopulated_unsigned_transaction)
+                    .await
+            }
+        }
+    }
+}
@@ -1,53 +1,441 @@
-pub mod select_server;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use url_open::UrlOpen;
 
 /// аргументы, необходимые для создания транзакции в online mode
 #[derive(Debug, Default, Clone, clap::Clap)]
-#[clap(
-    setting(clap::AppSettings::ColoredHelp),
-    setting(clap::AppSettings::DisableHelpSubcommand),
-    setting(clap::AppSettings::VersionlessSubcommands)
-)]
 pub struct CliNetworkArgs {
-    #[clap(subcommand)]
-    selected_server: Option<self::select_server::CliSelectServer>,
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    /// Request a function-call access key for this contract instead of a full access key
+    #[clap(long)]
+    contract_id: Option<near_primitives::types::AccountId>,
+    /// Comma-separated method names the function-call key is allowed to call (any method if omitted)
+    #[clap(long)]
+    methods: Option<String>,
+    /// Allowance for the function-call key; the wallet may apply its own default
+    #[clap(long)]
+    allowance: Option<crate::common::NearBalance>,
 }
 
 #[derive(Debug, Clone)]
 pub struct NetworkArgs {
-    selected_server: self::select_server::SelectServer,
+    selected_server: crate::network::SelectServer,
+    pub function_call_key: Option<FunctionCallKey>,
+}
+
+/// The function-call access key requested from the wallet
+#[derive(Debug, Clone)]
+pub struct FunctionCallKey {
+    pub contract_id: near_primitives::types::AccountId,
+    pub method_names: Vec<String>,
+    pub allowance: Option<near_primitives::types::Balance>,
+}
+
+impl FunctionCallKey {
+    fn from(
+        contract_id: Option<near_primitives::types::AccountId>,
+        methods: Option<String>,
+        allowance: Option<crate::common::NearBalance>,
+    ) -> Option<Self> {
+        Some(Self {
+            contract_id: contract_id?,
+            method_names: methods
+                .map(|methods| {
+                    methods
+                        .split(',')
+                        .map(|method_name| method_name.trim().to_string())
+                        .filter(|method_name| !method_name.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            allowance: allowance.map(|allowance| allowance.to_yoctonear()),
+        })
+    }
+
+    fn to_cli_args(&self, args: &mut std::collections::VecDeque<String>) {
+        if let Some(allowance) = &self.allowance {
+            args.push_front(crate::common::NearBalance::from_yoctonear(*allowance).to_string());
+            args.push_front("--allowance".to_owned());
+        }
+        if !self.method_names.is_empty() {
+            args.push_front(self.method_names.join(","));
+            args.push_front("--methods".to_owned());
+        }
+        args.push_front(self.contract_id.to_string());
+        args.push_front("--contract-id".to_owned());
+    }
 }
 
 impl CliNetworkArgs {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.selected_server
-            .as_ref()
-            .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+        let mut args = std::collections::VecDeque::new();
+        if let Some(function_call_key) = FunctionCallKey::from(
+            self.contract_id.clone(),
+            self.methods.clone(),
+            self.allowance.clone(),
+        ) {
+            function_call_key.to_cli_args(&mut args);
+        }
+        self.selected_server.prepend_to_cli_args(args)
     }
 }
 
 impl From<NetworkArgs> for CliNetworkArgs {
     fn from(network_args: NetworkArgs) -> Self {
+        let function_call_key = network_args.function_call_key;
         Self {
-            selected_server: Some(network_args.selected_server.into()),
+            selected_server: network_args.selected_server.into(),
+            contract_id: function_call_key
+                .as_ref()
+                .map(|function_call_key| function_call_key.contract_id.clone()),
+            methods: function_call_key
+                .as_ref()
+                .filter(|function_call_key| !function_call_key.method_names.is_empty())
+                .map(|function_call_key| function_call_key.method_names.join(",")),
+            allowance: function_call_key
+                .as_ref()
+                .and_then(|function_call_key| function_call_key.allowance)
+                .map(crate::common::NearBalance::from_yoctonear),
         }
     }
 }
 
-impl From<CliNetworkArgs> for NetworkArgs {
-    fn from(item: CliNetworkArgs) -> Self {
-        let selected_server = match item.selected_server {
-            Some(cli_selected_server) => {
-                self::select_server::SelectServer::from(cli_selected_server)
-            }
-            None => self::select_server::SelectServer::choose_server(),
-        };
-        Self { selected_server }
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        Ok(Self {
+            selected_server,
+            function_call_key: FunctionCallKey::from(
+                item.contract_id,
+                item.methods,
+                item.allowance,
+            ),
+        })
     }
 }
 
 impl NetworkArgs {
     pub async fn process(self) -> crate::CliResult {
-        self.selected_server.process().await
+        self.selected_server.verify().await?;
+        let connection_config = self.selected_server.connection_config;
+        let key_pair_properties: crate::common::KeyPairProperties =
+            crate::common::generate_keypair(near_crypto::KeyType::ED25519).await?;
+        let mut url: url::Url = connection_config.wallet_url().join("login/")?;
+        url.query_pairs_mut()
+            .append_pair("title", "NEAR CLI")
+            .append_pair("public_key", &key_pair_properties.public_key_str);
+        if let Some(function_call_key) = &self.function_call_key {
+            url.query_pairs_mut()
+                .append_pair("contract_id", &function_call_key.contract_id.to_string());
+            for method_name in &function_call_key.method_names {
+                url.query_pairs_mut()
+                    .append_pair("methodNames", method_name);
+            }
+            if let Some(allowance) = &function_call_key.allowance {
+                url.query_pairs_mut()
+                    .append_pair("allowance", &allowance.to_string());
+            }
+        }
+        let callback_server = match crate::common::CallbackServer::start(login_callback) {
+            Ok(callback_server) => {
+                url.query_pairs_mut()
+                    .append_pair("success_url", callback_server.url("/success").as_str())
+                    .append_pair("failure_url", callback_server.url("/failure").as_str());
+                Some(callback_server)
+            }
+            Err(err) => {
+                println!(
+                    "Failed to start a local server to capture the login ({}). You will have to enter the account ID manually.",
+                    err
+                );
+                None
+            }
+        };
+        println!(
+            "If your browser doesn't automatically open, please visit this URL:\n {}\n",
+            &url.as_str()
+        );
+        url.open();
+
+        let public_key: near_crypto::PublicKey =
+            near_crypto::PublicKey::from_str(&key_pair_properties.public_key_str)?;
+
+        let account = match callback_server {
+            Some(callback_server) => {
+                get_account_from_callback(
+                    callback_server,
+                    public_key.clone(),
+                    connection_config.clone(),
+                )
+                .await
+            }
+            None => None,
+        };
+        let (account_id, access_key_view) = match account {
+            Some(account) => account,
+            None => get_account_from_cli(public_key, connection_config.clone()).await?,
+        };
+        if let Some(function_call_key) = &self.function_call_key {
+            match &access_key_view.permission {
+                near_primitives::views::AccessKeyPermissionView::FullAccess => println!(
+                    "WARNING! The wallet granted a full access key instead of a function-call key for <{}>.",
+                    function_call_key.contract_id
+                ),
+                near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                    allowance,
+                    receiver_id,
+                    method_names,
+                } => {
+                    if receiver_id.to_string() != function_call_key.contract_id.to_string()
+                        || method_names != &function_call_key.method_names
+                        || (function_call_key.allowance.is_some()
+                            && allowance != &function_call_key.allowance)
+                    {
+                        println!(
+                            "WARNING! The wallet granted a function-call key which differs from the requested one: receiver <{}>, methods [{}], allowance {}.",
+                            receiver_id,
+                            method_names.join(", "),
+                            match allowance {
+                                Some(allowance) =>
+                                    crate::common::NearBalance::from_yoctonear(*allowance)
+                                        .to_string(),
+                                None => "unlimited".to_string(),
+                            }
+                        );
+                    }
+                }
+            }
+        }
+        crate::common::save_access_key_to_keychain(
+            Some(connection_config),
+            key_pair_properties.clone(),
+            &account_id.to_string(),
+            Some(access_key_view.permission),
+        )
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to save a file with access key: {}", err))
+        })?;
+        Ok(())
+    }
+}
+
+/// The result of the wallet redirect to the local server
+#[derive(Debug, Clone, PartialEq)]
+enum LoginCallback {
+    Success {
+        account_id: near_primitives::types::AccountId,
+        public_key: Option<String>,
+    },
+    Failure,
+}
+
+/// Captures the `success_url`/`failure_url` redirects from the wallet
+fn login_callback(
+    path: &str,
+    query: &std::collections::HashMap<String, String>,
+) -> Option<(LoginCallback, actix_web::HttpResponse)> {
+    match path {
+        "/success" => {
+            let account_id = query.get("account_id").and_then(|account_id| {
+                near_primitives::types::AccountId::try_from(account_id.to_string()).ok()
+            });
+            Some(match account_id {
+                Some(account_id) => (
+                    LoginCallback::Success {
+                        account_id,
+                        public_key: query.get("public_key").cloned(),
+                    },
+                    actix_web::HttpResponse::Ok()
+                        .content_type("text/plain; charset=utf-8")
+                        .body("Login succeeded. You can close this page and return to the terminal."),
+                ),
+                None => (
+                    LoginCallback::Failure,
+                    actix_web::HttpResponse::BadRequest()
+                        .content_type("text/plain; charset=utf-8")
+                        .body(
+                            "The wallet did not provide a valid account ID. Please return to the terminal.",
+                        ),
+                ),
+            })
+        }
+        "/failure" => Some((
+            LoginCallback::Failure,
+            actix_web::HttpResponse::Ok()
+                .content_type("text/plain; charset=utf-8")
+                .body("Login failed. Please return to the terminal."),
+        )),
+        _ => None,
+    }
+}
+
+async fn get_account_from_callback(
+    callback_server: crate::common::CallbackServer<LoginCallback>,
+    public_key: near_crypto::PublicKey,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> Option<(
+    near_primitives::types::AccountId,
+    near_primitives::views::AccessKeyView,
+)> {
+    println!(
+        "Waiting for the wallet to redirect back (up to {} seconds) ...",
+        crate::consts::LOGIN_CALLBACK_TIMEOUT.as_secs()
+    );
+    match callback_server
+        .wait(crate::consts::LOGIN_CALLBACK_TIMEOUT)
+        .await
+    {
+        Some(LoginCallback::Success {
+            account_id,
+            public_key: callback_public_key,
+        }) => {
+            if let Some(callback_public_key) = callback_public_key {
+                if callback_public_key != public_key.to_string() {
+                    println!(
+                        "The wallet returned a different public key <{}>.",
+                        callback_public_key
+                    );
+                    return None;
+                }
+            }
+            match verify_account_id(account_id.clone(), public_key, network_connection_config).await
+            {
+                Ok(access_key_view) => {
+                    println!("Logged in as <{}>", account_id);
+                    Some((account_id, access_key_view))
+                }
+                Err(err) => {
+                    println!(
+                        "The access key is not found for the account <{}>: {}",
+                        account_id, err
+                    );
+                    None
+                }
+            }
+        }
+        Some(LoginCallback::Failure) => {
+            println!("The wallet reported that the login was not authorized.");
+            None
+        }
+        None => {
+            println!("The wallet did not redirect back in time.");
+            None
+        }
+    }
+}
+
+async fn get_account_from_cli(
+    public_key: near_crypto::PublicKey,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<(
+    near_primitives::types::AccountId,
+    near_primitives::views::AccessKeyView,
+)> {
+    let account_id = input_account_id()?;
+    let access_key_view =
+        verify_account_id(account_id.clone(), public_key, network_connection_config)
+            .await
+            .map_err(|err| color_eyre::Report::msg(format!("Failed account ID: {:?}", err)))?;
+    Ok((account_id, access_key_view))
+}
+
+fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+    Ok(Input::new()
+        .with_prompt("Enter account ID")
+        .require_interactive("<account-id>")?
+        .interact_text()
+        .unwrap())
+}
+
+async fn verify_account_id(
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    network_connection_config: crate::common::ConnectionConfig,
+) -> color_eyre::eyre::Result<near_primitives::views::AccessKeyView> {
+    let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccessKey {
+                account_id,
+                public_key,
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view access key: {:?}",
+                err
+            ))
+        })?;
+    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view) =
+        query_view_method_response.kind
+    {
+        Ok(access_key_view)
+    } else {
+        Err(color_eyre::Report::msg(format!("Error call result")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+    use std::io::{Read, Write};
+
+    fn send_request(url: url::Url) -> std::thread::JoinHandle<String> {
+        let port = url.port().unwrap();
+        let request = format!(
+            "GET {}?{} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n",
+            url.path(),
+            url.query().unwrap_or_default()
+        );
+        std::thread::spawn(move || {
+            let mut stream = std::net::TcpStream::connect(("127.0.0.1", port)).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        })
+    }
+
+    #[test]
+    fn capture_login_callback() {
+        actix::System::new().block_on(async {
+            let callback_server = crate::common::CallbackServer::start(login_callback).unwrap();
+            let request = send_request(callback_server.url(
+                "/success?account_id=alice.testnet&public_key=ed25519%3A6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp&all_keys=",
+            ));
+            let callback = callback_server
+                .wait(std::time::Duration::from_secs(10))
+                .await;
+            assert!(request.join().unwrap().starts_with("HTTP/1.1 200"));
+            assert_eq!(
+                callback,
+                Some(LoginCallback::Success {
+                    account_id: "alice.testnet".to_string().try_into().unwrap(),
+                    public_key: Some(
+                        "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp".to_string()
+                    ),
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn capture_login_failure() {
+        actix::System::new().block_on(async {
+            let callback_server = crate::common::CallbackServer::start(login_callback).unwrap();
+            let request = send_request(callback_server.url("/failure?errorCode=userRejected"));
+            let callback = callback_server
+                .wait(std::time::Duration::from_secs(10))
+                .await;
+            assert!(request.join().unwrap().starts_with("HTTP/1.1 200"));
+            assert_eq!(callback, Some(LoginCallback::Failure));
+        });
     }
 }
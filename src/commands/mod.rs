@@ -1,20 +1,33 @@
+use std::convert::TryInto;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 pub mod add_command;
+pub mod config_command;
 pub mod construct_transaction_command;
 pub mod delete_command;
 pub mod execute_command;
 pub mod generate_shell_completions_command;
+pub mod history_command;
+pub mod keys_command;
+pub mod ledger_command;
+pub mod linkdrop_command;
 pub mod login;
+pub mod multisig_command;
+pub mod run_command;
+pub mod template_command;
 pub mod transfer_command;
 pub mod utils_command;
 pub mod view_command;
 
 #[derive(Debug, Clone, clap::Clap)]
 pub enum CliTopLevelCommand {
-    /// Use these to add access key, contract code, stake proposal, sub-account, implicit-account
+    /// Use these to add access key, contract code, stake proposal, top-level account, sub-account, implicit-account
     Add(self::add_command::CliAddAction),
+    /// Use these to show the defaults from the global options, the environment and .near-cli.toml
+    Config(self::config_command::CliConfig),
     /// Prepare and, optionally, submit a new transaction
     ConstructTransaction(self::construct_transaction_command::operation_mode::CliOperationMode),
     /// Use these to delete access key, sub-account
@@ -23,8 +36,22 @@ pub enum CliTopLevelCommand {
     Execute(self::execute_command::CliOptionMethod),
     /// Use these to generate static shell completions
     GenerateShellCompletions(self::generate_shell_completions_command::CliGenerateShellCompletions),
+    /// Use these to list, show, resend and export the transactions recorded in the local journal
+    History(self::history_command::CliHistory),
+    /// Use these to find the accounts of the Ledger keys and save their HD paths
+    Ledger(self::ledger_command::CliLedger),
+    /// Use these to rotate the access keys in the keychain
+    Keys(self::keys_command::CliKeys),
+    /// Use these to create and claim the drops of a linkdrop contract
+    Linkdrop(self::linkdrop_command::CliLinkdrop),
     /// Use these to login with wallet authorization
     Login(self::login::operation_mode::CliOperationMode),
+    /// Use these to work with a multisig contract: add, confirm, delete and list the requests
+    Multisig(self::multisig_command::operation_mode::CliOperationMode),
+    /// Use these to run the near-cli commands from a plan file (YAML or JSON)
+    Run(self::run_command::CliRunPlan),
+    /// Use these to replay the saved console commands (templates)
+    Template(self::template_command::CliTemplate),
     /// Use these to transfer tokens
     Transfer(self::transfer_command::CliCurrency),
     /// Helpers
@@ -54,6 +81,18 @@ pub enum TopLevelCommand {
     Delete(self::delete_command::DeleteAction),
     #[strum_discriminants(strum(message = "Construct a new transaction"))]
     ConstructTransaction(self::construct_transaction_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Work with a multisig contract"))]
+    Multisig(self::multisig_command::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "Find the accounts of the Ledger keys"))]
+    Ledger(self::ledger_command::Ledger),
+    #[strum_discriminants(strum(message = "Rotate the access keys in the keychain"))]
+    Keys(self::keys_command::Keys),
+    #[strum_discriminants(strum(message = "Create or claim linkdrops"))]
+    Linkdrop(self::linkdrop_command::Linkdrop),
+    #[strum_discriminants(strum(message = "Show the effective defaults"))]
+    Config(self::config_command::Config),
+    #[strum_discriminants(strum(message = "Show, resend or export the recorded transactions"))]
+    History(self::history_command::History),
     #[strum_discriminants(strum(message = "Helpers"))]
     Utils(self::utils_command::Utils),
 }
@@ -96,12 +135,44 @@ impl CliTopLevelCommand {
                 args.push_front("construct-transaction".to_owned());
                 args
             }
+            Self::Multisig(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("multisig".to_owned());
+                args
+            }
+            Self::Ledger(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("ledger".to_owned());
+                args
+            }
+            Self::Keys(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("keys".to_owned());
+                args
+            }
+            Self::Linkdrop(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("linkdrop".to_owned());
+                args
+            }
+            Self::Config(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("config".to_owned());
+                args
+            }
+            Self::History(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("history".to_owned());
+                args
+            }
             Self::Utils(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("utils".to_owned());
                 args
             }
             Self::GenerateShellCompletions(_) => std::collections::VecDeque::new(),
+            Self::Run(_) => std::collections::VecDeque::new(),
+            Self::Template(_) => std::collections::VecDeque::new(),
         }
     }
 }
@@ -118,50 +189,81 @@ impl From<TopLevelCommand> for CliTopLevelCommand {
             TopLevelCommand::ConstructTransaction(operation_mode) => {
                 Self::ConstructTransaction(operation_mode.into())
             }
+            TopLevelCommand::Multisig(operation_mode) => Self::Multisig(operation_mode.into()),
+            TopLevelCommand::Ledger(ledger) => Self::Ledger(ledger.into()),
+            TopLevelCommand::Keys(keys) => Self::Keys(keys.into()),
+            TopLevelCommand::Linkdrop(linkdrop) => Self::Linkdrop(linkdrop.into()),
+            TopLevelCommand::Config(config) => Self::Config(config.into()),
+            TopLevelCommand::History(history) => Self::History(history.into()),
             TopLevelCommand::Utils(utils) => Self::Utils(utils.into()),
         }
     }
 }
 
-impl From<CliTopLevelCommand> for TopLevelCommand {
-    fn from(cli_top_level_command: CliTopLevelCommand) -> Self {
-        match cli_top_level_command {
+impl TopLevelCommand {
+    pub fn from(cli_top_level_command: CliTopLevelCommand) -> color_eyre::eyre::Result<Self> {
+        Ok(match cli_top_level_command {
             CliTopLevelCommand::Add(cli_add_action) => {
-                TopLevelCommand::Add(self::add_command::AddAction::from(cli_add_action).unwrap())
+                TopLevelCommand::Add(self::add_command::AddAction::from(cli_add_action)?)
             }
             CliTopLevelCommand::ConstructTransaction(cli_operation_mode) => {
                 TopLevelCommand::ConstructTransaction(
                     self::construct_transaction_command::operation_mode::OperationMode::from(
                         cli_operation_mode,
-                    )
-                    .unwrap(),
+                    )?,
                 )
             }
+            CliTopLevelCommand::Config(cli_config) => {
+                TopLevelCommand::Config(cli_config.try_into()?)
+            }
             CliTopLevelCommand::Delete(cli_delete_action) => TopLevelCommand::Delete(
-                self::delete_command::DeleteAction::from(cli_delete_action).unwrap(),
+                self::delete_command::DeleteAction::from(cli_delete_action)?,
             ),
             CliTopLevelCommand::Execute(cli_option_method) => TopLevelCommand::Execute(
-                self::execute_command::OptionMethod::from(cli_option_method).unwrap(),
+                self::execute_command::OptionMethod::from(cli_option_method)?,
             ),
             CliTopLevelCommand::GenerateShellCompletions(_) => {
                 unreachable!("This variant is handled in the main function")
             }
-            CliTopLevelCommand::Login(cli_option_method) => {
-                TopLevelCommand::Login(cli_option_method.into())
+            CliTopLevelCommand::History(cli_history) => {
+                TopLevelCommand::History(self::history_command::History::from(cli_history)?)
+            }
+            CliTopLevelCommand::Ledger(cli_ledger) => {
+                TopLevelCommand::Ledger(self::ledger_command::Ledger::from(cli_ledger)?)
+            }
+            CliTopLevelCommand::Keys(cli_keys) => {
+                TopLevelCommand::Keys(self::keys_command::Keys::from(cli_keys)?)
             }
-            CliTopLevelCommand::Transfer(cli_currency) => TopLevelCommand::Transfer(
-                self::transfer_command::Currency::from(cli_currency).unwrap(),
+            CliTopLevelCommand::Linkdrop(cli_linkdrop) => {
+                TopLevelCommand::Linkdrop(self::linkdrop_command::Linkdrop::from(cli_linkdrop)?)
+            }
+            CliTopLevelCommand::Multisig(cli_operation_mode) => TopLevelCommand::Multisig(
+                self::multisig_command::operation_mode::OperationMode::from(cli_operation_mode)?,
             ),
-            CliTopLevelCommand::Utils(cli_util) => TopLevelCommand::Utils(cli_util.into()),
-            CliTopLevelCommand::View(cli_view_query_request) => {
-                TopLevelCommand::View(cli_view_query_request.into())
+            CliTopLevelCommand::Run(_) => {
+                unreachable!("This variant is handled in the main function")
             }
-        }
+            CliTopLevelCommand::Template(_) => {
+                unreachable!("This variant is handled in the main function")
+            }
+            CliTopLevelCommand::Login(cli_operation_mode) => TopLevelCommand::Login(
+                self::login::operation_mode::OperationMode::from(cli_operation_mode)?,
+            ),
+            CliTopLevelCommand::Transfer(cli_currency) => {
+                TopLevelCommand::Transfer(self::transfer_command::Currency::from(cli_currency)?)
+            }
+            CliTopLevelCommand::Utils(cli_util) => {
+                TopLevelCommand::Utils(self::utils_command::Utils::from(cli_util)?)
+            }
+            CliTopLevelCommand::View(cli_view_query_request) => TopLevelCommand::View(
+                self::view_command::ViewQueryRequest::from(cli_view_query_request)?,
+            ),
+        })
     }
 }
 
 impl TopLevelCommand {
-    pub fn choose_command() -> Self {
+    pub fn choose_command() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = TopLevelCommandDiscriminants::iter().collect::<Vec<_>>();
         let commands = variants
@@ -172,6 +274,7 @@ impl TopLevelCommand {
             .with_prompt("Choose your action")
             .items(&commands)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_top_level_command = match variants[selection] {
@@ -179,11 +282,23 @@ impl TopLevelCommand {
             TopLevelCommandDiscriminants::ConstructTransaction => {
                 CliTopLevelCommand::ConstructTransaction(Default::default())
             }
+            TopLevelCommandDiscriminants::Config => CliTopLevelCommand::Config(Default::default()),
             TopLevelCommandDiscriminants::Delete => CliTopLevelCommand::Delete(Default::default()),
             TopLevelCommandDiscriminants::Execute => {
                 CliTopLevelCommand::Execute(Default::default())
             }
+            TopLevelCommandDiscriminants::History => {
+                CliTopLevelCommand::History(Default::default())
+            }
+            TopLevelCommandDiscriminants::Ledger => CliTopLevelCommand::Ledger(Default::default()),
+            TopLevelCommandDiscriminants::Keys => CliTopLevelCommand::Keys(Default::default()),
+            TopLevelCommandDiscriminants::Linkdrop => {
+                CliTopLevelCommand::Linkdrop(Default::default())
+            }
             TopLevelCommandDiscriminants::Login => CliTopLevelCommand::Login(Default::default()),
+            TopLevelCommandDiscriminants::Multisig => {
+                CliTopLevelCommand::Multisig(Default::default())
+            }
             TopLevelCommandDiscriminants::Transfer => {
                 CliTopLevelCommand::Transfer(Default::default())
             }
@@ -205,9 +320,15 @@ impl TopLevelCommand {
         match self {
             Self::Add(add_action) => add_action.process(unsigned_transaction).await,
             Self::ConstructTransaction(mode) => mode.process(unsigned_transaction).await,
+            Self::Config(config) => config.process().await,
             Self::Delete(delete_action) => delete_action.process(unsigned_transaction).await,
             Self::Execute(option_method) => option_method.process(unsigned_transaction).await,
+            Self::History(history) => history.process().await,
+            Self::Ledger(ledger) => ledger.process().await,
+            Self::Keys(keys) => keys.process().await,
+            Self::Linkdrop(linkdrop) => linkdrop.process(unsigned_transaction).await,
             Self::Login(mode) => mode.process().await,
+            Self::Multisig(mode) => mode.process(unsigned_transaction).await,
             Self::Transfer(currency) => currency.process(unsigned_transaction).await,
             Self::Utils(util_type) => util_type.process().await,
             Self::View(view_query_request) => view_query_request.process().await,
@@ -0,0 +1,394 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify the multisig account
+    Account(CliMultisigAccount),
+}
+
+#[derive(Debug, Clone)]
+pub enum SendTo {
+    Account(MultisigAccount),
+}
+
+impl CliSendTo {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Account(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<SendTo> for CliSendTo {
+    fn from(send_to: SendTo) -> Self {
+        match send_to {
+            SendTo::Account(multisig_account) => Self::Account(multisig_account.into()),
+        }
+    }
+}
+
+impl SendTo {
+    pub fn from(
+        item: CliSendTo,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSendTo::Account(cli_multisig_account) => Ok(Self::Account(MultisigAccount::from(
+                cli_multisig_account,
+                connection_config,
+            )?)),
+        }
+    }
+}
+
+impl SendTo {
+    pub fn send_to(
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        Self::from(CliSendTo::Account(Default::default()), connection_config)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Account(multisig_account) => {
+                multisig_account
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+        }
+    }
+}
+
+/// the account with the multisig contract
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliMultisigAccount {
+    multisig_account_id: Option<near_primitives::types::AccountId>,
+    #[clap(subcommand)]
+    action: Option<CliMultisigAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultisigAccount {
+    multisig_account_id: near_primitives::types::AccountId,
+    action: MultisigAction,
+}
+
+impl CliMultisigAccount {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(multisig_account_id) = &self.multisig_account_id {
+            args.push_front(multisig_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<MultisigAccount> for CliMultisigAccount {
+    fn from(multisig_account: MultisigAccount) -> Self {
+        Self {
+            multisig_account_id: Some(multisig_account.multisig_account_id),
+            action: Some(multisig_account.action.into()),
+        }
+    }
+}
+
+impl MultisigAccount {
+    fn from(
+        item: CliMultisigAccount,
+        connection_config: crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let multisig_account_id: near_primitives::types::AccountId = match item.multisig_account_id
+        {
+            Some(cli_multisig_account_id) => cli_multisig_account_id,
+            None => MultisigAccount::input_multisig_account_id()?,
+        };
+        let action = match item.action {
+            Some(cli_action) => {
+                MultisigAction::from(cli_action, connection_config, multisig_account_id.clone())?
+            }
+            None => MultisigAction::choose_action(connection_config, multisig_account_id.clone())?,
+        };
+        Ok(Self {
+            multisig_account_id,
+            action,
+        })
+    }
+}
+
+impl MultisigAccount {
+    fn input_multisig_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the multisig contract?")
+            .require_interactive("<multisig-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        crate::common::verify_account_exists(&network_connection_config, &self.multisig_account_id)
+            .await?;
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: self.multisig_account_id.clone(),
+            receiver_id: self.multisig_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        self.action
+            .process(
+                unsigned_transaction,
+                network_connection_config,
+                self.multisig_account_id,
+            )
+            .await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliMultisigAction {
+    /// Add a request with the transaction actions
+    AddRequest(super::add_request::CliAddRequest),
+    /// Confirm a request
+    Confirm(super::request::CliRequest),
+    /// Delete a request
+    DeleteRequest(super::request::CliRequest),
+    /// List the requests with their actions
+    ListRequests,
+    /// Show the number of confirmations required for a request
+    NumConfirmations,
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum MultisigAction {
+    #[strum_discriminants(strum(message = "Add a request"))]
+    AddRequest(super::add_request::AddRequest),
+    #[strum_discriminants(strum(message = "Confirm a request"))]
+    Confirm(super::request::Request),
+    #[strum_discriminants(strum(message = "Delete a request"))]
+    DeleteRequest(super::request::Request),
+    #[strum_discriminants(strum(message = "List the requests"))]
+    ListRequests,
+    #[strum_discriminants(strum(message = "Show the number of confirmations"))]
+    NumConfirmations,
+}
+
+impl CliMultisigAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::AddRequest(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("add-request".to_owned());
+                args
+            }
+            Self::Confirm(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("confirm".to_owned());
+                args
+            }
+            Self::DeleteRequest(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("delete-request".to_owned());
+                args
+            }
+            Self::ListRequests => {
+                let mut args = std::collections::VecDeque::new();
+                args.push_front("list-requests".to_owned());
+                args
+            }
+            Self::NumConfirmations => {
+                let mut args = std::collections::VecDeque::new();
+                args.push_front("num-confirmations".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<MultisigAction> for CliMultisigAction {
+    fn from(multisig_action: MultisigAction) -> Self {
+        match multisig_action {
+            MultisigAction::AddRequest(add_request) => Self::AddRequest(add_request.into()),
+            MultisigAction::Confirm(request) => Self::Confirm(request.into()),
+            MultisigAction::DeleteRequest(request) => Self::DeleteRequest(request.into()),
+            MultisigAction::ListRequests => Self::ListRequests,
+            MultisigAction::NumConfirmations => Self::NumConfirmations,
+        }
+    }
+}
+
+impl MultisigAction {
+    fn from(
+        item: CliMultisigAction,
+        connection_config: crate::common::ConnectionConfig,
+        multisig_account_id: near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliMultisigAction::AddRequest(cli_add_request) => {
+                Ok(Self::AddRequest(super::add_request::AddRequest::from(
+                    cli_add_request,
+                    connection_config,
+                    multisig_account_id,
+                )?))
+            }
+            CliMultisigAction::Confirm(cli_request) => Ok(Self::Confirm(
+                super::request::Request::from(cli_request, connection_config, multisig_account_id)?,
+            )),
+            CliMultisigAction::DeleteRequest(cli_request) => Ok(Self::DeleteRequest(
+                super::request::Request::from(cli_request, connection_config, multisig_account_id)?,
+            )),
+            CliMultisigAction::ListRequests => Ok(Self::ListRequests),
+            CliMultisigAction::NumConfirmations => Ok(Self::NumConfirmations),
+        }
+    }
+}
+
+impl MultisigAction {
+    fn choose_action(
+        connection_config: crate::common::ConnectionConfig,
+        multisig_account_id: near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = MultisigActionDiscriminants::iter().collect::<Vec<_>>();
+        let actions = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selected_action = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What do you want to do with the multisig contract?")
+            .items(&actions)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_action = match variants[selected_action] {
+            MultisigActionDiscriminants::AddRequest => {
+                CliMultisigAction::AddRequest(Default::default())
+            }
+            MultisigActionDiscriminants::Confirm => CliMultisigAction::Confirm(Default::default()),
+            MultisigActionDiscriminants::DeleteRequest => {
+                CliMultisigAction::DeleteRequest(Default::default())
+            }
+            MultisigActionDiscriminants::ListRequests => CliMultisigAction::ListRequests,
+            MultisigActionDiscriminants::NumConfirmations => CliMultisigAction::NumConfirmations,
+        };
+        Self::from(cli_action, connection_config, multisig_account_id)
+    }
+
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+        multisig_account_id: near_primitives::types::AccountId,
+    ) -> crate::CliResult {
+        match self {
+            MultisigAction::AddRequest(add_request) => {
+                add_request
+                    .process(prepopulated_unsigned_transaction, network_connection_config)
+                    .await
+            }
+            MultisigAction::Confirm(request) => {
+                request
+                    .process(
+                        "confirm",
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                    )
+                    .await
+            }
+            MultisigAction::DeleteRequest(request) => {
+                request
+                    .process(
+                        "delete_request",
+                        prepopulated_unsigned_transaction,
+                        network_connection_config,
+                    )
+                    .await
+            }
+            MultisigAction::ListRequests => {
+                list_requests(&network_connection_config, &multisig_account_id).await
+            }
+            MultisigAction::NumConfirmations => {
+                let num_confirmations: u32 = super::call_view_method(
+                    &network_connection_config,
+                    &multisig_account_id,
+                    "get_num_confirmations",
+                    serde_json::json!({}),
+                )
+                .await?;
+                println!(
+                    "The multisig contract <{}> requires {} confirmations for a request",
+                    multisig_account_id, num_confirmations
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+async fn list_requests(
+    network_connection_config: &crate::common::ConnectionConfig,
+    multisig_account_id: &near_primitives::types::AccountId,
+) -> crate::CliResult {
+    let num_confirmations: u32 = super::call_view_method(
+        network_connection_config,
+        multisig_account_id,
+        "get_num_confirmations",
+        serde_json::json!({}),
+    )
+    .await?;
+    let request_ids: Vec<u32> = super::call_view_method(
+        network_connection_config,
+        multisig_account_id,
+        "list_request_ids",
+        serde_json::json!({}),
+    )
+    .await?;
+    if request_ids.is_empty() {
+        println!(
+            "There are no requests in the multisig contract <{}>",
+            multisig_account_id
+        );
+        return Ok(());
+    }
+    for request_id in request_ids {
+        let request: super::MultisigRequest = super::call_view_method(
+            network_connection_config,
+            multisig_account_id,
+            "get_request",
+            serde_json::json!({ "request_id": request_id }),
+        )
+        .await?;
+        let confirmations: Vec<near_crypto::PublicKey> = super::call_view_method(
+            network_connection_config,
+            multisig_account_id,
+            "get_confirmations",
+            serde_json::json!({ "request_id": request_id }),
+        )
+        .await?;
+        println!();
+        super::print_multisig_request(request_id, &request, &confirmations, num_confirmations);
+    }
+    Ok(())
+}
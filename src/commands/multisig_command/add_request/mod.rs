@@ -0,0 +1,112 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+/// the request for the multisig contract: the receiver and the actions
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliAddRequest {
+    /// Confirm the request with the signer key right away (add_request_and_confirm)
+    #[clap(long)]
+    confirm: bool,
+    receiver_account_id: Option<near_primitives::types::AccountId>,
+    #[clap(subcommand)]
+    action:
+        Option<crate::commands::construct_transaction_command::transaction_actions::CliNextAction>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddRequest {
+    confirm: bool,
+    receiver_account_id: near_primitives::types::AccountId,
+    action: crate::commands::construct_transaction_command::transaction_actions::NextAction,
+}
+
+impl CliAddRequest {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .action
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(receiver_account_id) = &self.receiver_account_id {
+            args.push_front(receiver_account_id.to_string());
+        }
+        if self.confirm {
+            args.push_front("--confirm".to_owned());
+        }
+        args
+    }
+}
+
+impl From<AddRequest> for CliAddRequest {
+    fn from(add_request: AddRequest) -> Self {
+        Self {
+            confirm: add_request.confirm,
+            receiver_account_id: Some(add_request.receiver_account_id),
+            action: Some(add_request.action.into()),
+        }
+    }
+}
+
+impl AddRequest {
+    pub fn from(
+        item: CliAddRequest,
+        connection_config: crate::common::ConnectionConfig,
+        multisig_account_id: near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        let receiver_account_id: near_primitives::types::AccountId = match item.receiver_account_id
+        {
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => Input::new()
+                .with_prompt("What is the account ID of the request receiver?")
+                .require_interactive("<receiver-account-id>")?
+                .interact_text()
+                .unwrap(),
+        };
+        let action = match item.action {
+            Some(cli_next_action) => crate::commands::construct_transaction_command::transaction_actions::NextAction::from_cli_next_action(
+                cli_next_action,
+                Some(connection_config),
+                multisig_account_id,
+            )?,
+            None => crate::commands::construct_transaction_command::transaction_actions::NextAction::input_next_action(
+                Some(connection_config),
+                multisig_account_id,
+            )?,
+        };
+        Ok(Self {
+            confirm: item.confirm,
+            receiver_account_id,
+            action,
+        })
+    }
+}
+
+impl AddRequest {
+    pub async fn process(
+        self,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            receiver_id: self.receiver_account_id.clone(),
+            ..prepopulated_unsigned_transaction
+        };
+        let multisig_request_method = if self.confirm {
+            super::MultisigRequestMethod::AddRequestAndConfirm
+        } else {
+            super::MultisigRequestMethod::AddRequest
+        };
+        self.action
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config),
+                Some(multisig_request_method),
+            )
+            .await
+    }
+}
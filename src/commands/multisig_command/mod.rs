@@ -0,0 +1,368 @@
+use std::convert::TryFrom;
+
+pub mod account;
+mod add_request;
+pub mod operation_mode;
+mod request;
+
+/// The gas for the calls of the multisig contract (a confirmation may execute the request)
+const MULTISIG_GAS: near_primitives::types::Gas = 250_000_000_000_000;
+
+/// The method of the multisig contract, the transaction actions are wrapped into
+#[derive(Debug, Clone, Copy)]
+pub enum MultisigRequestMethod {
+    AddRequest,
+    AddRequestAndConfirm,
+}
+
+impl MultisigRequestMethod {
+    fn method_name(&self) -> &'static str {
+        match self {
+            Self::AddRequest => "add_request",
+            Self::AddRequestAndConfirm => "add_request_and_confirm",
+        }
+    }
+
+    /// Turns the transaction actions into a request for the multisig contract of the signer
+    pub fn wrap_actions(
+        self,
+        transaction: near_primitives::transaction::Transaction,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
+        let request = MultisigRequest {
+            receiver_id: transaction.receiver_id.clone(),
+            actions: transaction
+                .actions
+                .iter()
+                .cloned()
+                .map(MultisigRequestAction::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(color_eyre::Report::msg)?,
+        };
+        println!("\nMultisig request:\n");
+        println!("{:<13} {}", "receiver_id:", &request.receiver_id);
+        println!("actions:");
+        crate::common::print_actions(&transaction.receiver_id, &transaction.actions);
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: self.method_name().to_owned(),
+                args: serde_json::to_vec(&serde_json::json!({ "request": request }))?,
+                gas: MULTISIG_GAS,
+                deposit: 0,
+            },
+        );
+        Ok(near_primitives::transaction::Transaction {
+            receiver_id: transaction.signer_id.clone(),
+            actions: vec![action],
+            ..transaction
+        })
+    }
+}
+
+/// The request of the standard multisig contract
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MultisigRequest {
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<MultisigRequestAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum MultisigRequestAction {
+    Transfer {
+        amount: String,
+    },
+    CreateAccount,
+    DeployContract {
+        code: String,
+    },
+    AddKey {
+        public_key: near_crypto::PublicKey,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        permission: Option<MultisigFunctionCallPermission>,
+    },
+    DeleteKey {
+        public_key: near_crypto::PublicKey,
+    },
+    FunctionCall {
+        method_name: String,
+        args: String,
+        deposit: String,
+        gas: String,
+    },
+    SetNumConfirmations {
+        num_confirmations: u32,
+    },
+    SetActiveRequestsLimit {
+        active_requests_limit: u32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MultisigFunctionCallPermission {
+    pub allowance: Option<String>,
+    pub receiver_id: String,
+    pub method_names: Vec<String>,
+}
+
+impl TryFrom<near_primitives::transaction::Action> for MultisigRequestAction {
+    type Error = String;
+
+    fn try_from(action: near_primitives::transaction::Action) -> Result<Self, Self::Error> {
+        match action {
+            near_primitives::transaction::Action::CreateAccount(_) => Ok(Self::CreateAccount),
+            near_primitives::transaction::Action::DeployContract(deploy_contract_action) => {
+                Ok(Self::DeployContract {
+                    code: base64::encode(&deploy_contract_action.code),
+                })
+            }
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                Ok(Self::FunctionCall {
+                    method_name: function_call_action.method_name,
+                    args: base64::encode(&function_call_action.args),
+                    deposit: function_call_action.deposit.to_string(),
+                    gas: function_call_action.gas.to_string(),
+                })
+            }
+            near_primitives::transaction::Action::Transfer(transfer_action) => Ok(Self::Transfer {
+                amount: transfer_action.deposit.to_string(),
+            }),
+            near_primitives::transaction::Action::AddKey(add_key_action) => Ok(Self::AddKey {
+                public_key: add_key_action.public_key,
+                permission: match add_key_action.access_key.permission {
+                    near_primitives::account::AccessKeyPermission::FullAccess => None,
+                    near_primitives::account::AccessKeyPermission::FunctionCall(permission) => {
+                        Some(MultisigFunctionCallPermission {
+                            allowance: permission.allowance.map(|allowance| allowance.to_string()),
+                            receiver_id: permission.receiver_id,
+                            method_names: permission.method_names,
+                        })
+                    }
+                },
+            }),
+            near_primitives::transaction::Action::DeleteKey(delete_key_action) => {
+                Ok(Self::DeleteKey {
+                    public_key: delete_key_action.public_key,
+                })
+            }
+            near_primitives::transaction::Action::Stake(_) => {
+                Err("The multisig contract does not support the stake action".to_owned())
+            }
+            near_primitives::transaction::Action::DeleteAccount(_) => {
+                Err("The multisig contract does not support the delete account action".to_owned())
+            }
+        }
+    }
+}
+
+impl MultisigRequestAction {
+    /// The transaction action for this request action (None for the multisig settings)
+    fn to_action(&self) -> Result<Option<near_primitives::transaction::Action>, String> {
+        let parse_u128 = |value: &str| {
+            value
+                .parse::<u128>()
+                .map_err(|err| format!("Invalid amount <{}>: {}", value, err))
+        };
+        let decode_base64 = |value: &str| {
+            base64::decode(value).map_err(|err| format!("Invalid base64 data: {}", err))
+        };
+        let action = match self {
+            Self::Transfer { amount } => near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction {
+                    deposit: parse_u128(amount)?,
+                },
+            ),
+            Self::CreateAccount => near_primitives::transaction::Action::CreateAccount(
+                near_primitives::transaction::CreateAccountAction {},
+            ),
+            Self::DeployContract { code } => near_primitives::transaction::Action::DeployContract(
+                near_primitives::transaction::DeployContractAction {
+                    code: decode_base64(code)?,
+                },
+            ),
+            Self::AddKey {
+                public_key,
+                permission,
+            } => near_primitives::transaction::Action::AddKey(
+                near_primitives::transaction::AddKeyAction {
+                    public_key: public_key.clone(),
+                    access_key: near_primitives::account::AccessKey {
+                        nonce: 0,
+                        permission: match permission {
+                            None => near_primitives::account::AccessKeyPermission::FullAccess,
+                            Some(permission) => {
+                                near_primitives::account::AccessKeyPermission::FunctionCall(
+                                    near_primitives::account::FunctionCallPermission {
+                                        allowance: match &permission.allowance {
+                                            Some(allowance) => Some(parse_u128(allowance)?),
+                                            None => None,
+                                        },
+                                        receiver_id: permission.receiver_id.clone(),
+                                        method_names: permission.method_names.clone(),
+                                    },
+                                )
+                            }
+                        },
+                    },
+                },
+            ),
+            Self::DeleteKey { public_key } => near_primitives::transaction::Action::DeleteKey(
+                near_primitives::transaction::DeleteKeyAction {
+                    public_key: public_key.clone(),
+                },
+            ),
+            Self::FunctionCall {
+                method_name,
+                args,
+                deposit,
+                gas,
+            } => near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.clone(),
+                    args: decode_base64(args)?,
+                    deposit: parse_u128(deposit)?,
+                    gas: gas
+                        .parse::<u64>()
+                        .map_err(|err| format!("Invalid gas <{}>: {}", gas, err))?,
+                },
+            ),
+            Self::SetNumConfirmations { .. } | Self::SetActiveRequestsLimit { .. } => {
+                return Ok(None)
+            }
+        };
+        Ok(Some(action))
+    }
+}
+
+/// Prints the multisig request in the same way as the transaction
+pub fn print_multisig_request(
+    request_id: u32,
+    request: &MultisigRequest,
+    confirmations: &[near_crypto::PublicKey],
+    num_confirmations: u32,
+) {
+    println!("{:<13} {}", "request_id:", request_id);
+    println!("{:<13} {}", "receiver_id:", &request.receiver_id);
+    println!(
+        "{:<13} {}/{}",
+        "confirmations:",
+        confirmations.len(),
+        num_confirmations
+    );
+    for public_key in confirmations {
+        println!("{:>5} {}", "--", public_key);
+    }
+    println!("actions:");
+    for request_action in request.actions.iter() {
+        match request_action {
+            MultisigRequestAction::SetNumConfirmations { num_confirmations } => println!(
+                "{:>5} {:<20} {}",
+                "--", "set num confirmations:", num_confirmations
+            ),
+            MultisigRequestAction::SetActiveRequestsLimit {
+                active_requests_limit,
+            } => println!(
+                "{:>5} {:<20} {}",
+                "--", "set active requests limit:", active_requests_limit
+            ),
+            _ => match request_action.to_action() {
+                Ok(Some(action)) => crate::common::print_actions(&request.receiver_id, &[action]),
+                Ok(None) => {}
+                Err(err) => println!("{:>5} {:<20} {}", "--", "invalid action:", err),
+            },
+        }
+    }
+}
+
+/// Calls a view method of the multisig contract
+async fn call_view_method<T: serde::de::DeserializeOwned>(
+    network_connection_config: &crate::common::ConnectionConfig,
+    multisig_account_id: &near_primitives::types::AccountId,
+    method_name: &str,
+    args: serde_json::Value,
+) -> color_eyre::eyre::Result<T> {
+    let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: multisig_account_id.clone(),
+                method_name: method_name.to_owned(),
+                args: near_primitives::types::FunctionArgs::from(args.to_string().into_bytes()),
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to call the method <{}> of the multisig contract: {:?}",
+                method_name, err
+            ))
+        })?;
+    let call_result =
+        if let near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) =
+            query_view_method_response.kind
+        {
+            result.result
+        } else {
+            return Err(color_eyre::Report::msg(format!("Error call result")));
+        };
+    serde_json::from_slice(&call_result).map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Unexpected result of the method <{}>: {}",
+            method_name, err
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multisig_request_from_actions() {
+        let public_key = near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519);
+        let actions = vec![
+            near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction {
+                    deposit: 1_000_000_000_000_000_000_000_000,
+                },
+            ),
+            near_primitives::transaction::Action::AddKey(
+                near_primitives::transaction::AddKeyAction {
+                    public_key: public_key.clone(),
+                    access_key: near_primitives::account::AccessKey {
+                        nonce: 0,
+                        permission: near_primitives::account::AccessKeyPermission::FullAccess,
+                    },
+                },
+            ),
+        ];
+        let request_actions = actions
+            .iter()
+            .cloned()
+            .map(MultisigRequestAction::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request_actions).unwrap(),
+            serde_json::json!([
+                {"type": "Transfer", "amount": "1000000000000000000000000"},
+                {"type": "AddKey", "public_key": public_key.to_string()},
+            ])
+        );
+        let decoded_actions = request_actions
+            .iter()
+            .map(|request_action| request_action.to_action().unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(decoded_actions, actions);
+    }
+
+    #[test]
+    fn multisig_request_with_stake() {
+        let action = near_primitives::transaction::Action::Stake(
+            near_primitives::transaction::StakeAction {
+                stake: 1,
+                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            },
+        );
+        assert!(MultisigRequestAction::try_from(action).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+/// the request of the multisig contract to be confirmed or deleted
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRequest {
+    request_id: Option<u32>,
+    #[clap(subcommand)]
+    sign_option: Option<
+        crate::commands::construct_transaction_command::sign_transaction::CliSignTransaction,
+    >,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    request_id: u32,
+    sign_option: crate::commands::construct_transaction_command::sign_transaction::SignTransaction,
+}
+
+impl CliRequest {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(request_id) = &self.request_id {
+            args.push_front(request_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<Request> for CliRequest {
+    fn from(request: Request) -> Self {
+        Self {
+            request_id: Some(request.request_id),
+            sign_option: Some(request.sign_option.into()),
+        }
+    }
+}
+
+impl Request {
+    pub fn from(
+        item: CliRequest,
+        connection_config: crate::common::ConnectionConfig,
+        multisig_account_id: near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<Self> {
+        let request_id: u32 = match item.request_id {
+            Some(cli_request_id) => cli_request_id,
+            None => Input::new()
+                .with_prompt("What is the request ID?")
+                .require_interactive("<request-id>")?
+                .interact_text()
+                .unwrap(),
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_transaction) => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::from(cli_sign_transaction, Some(connection_config), multisig_account_id)?,
+            None => crate::commands::construct_transaction_command::sign_transaction::SignTransaction::choose_sign_option(Some(connection_config), multisig_account_id)?,
+        };
+        Ok(Self {
+            request_id,
+            sign_option,
+        })
+    }
+}
+
+impl Request {
+    pub async fn process(
+        self,
+        method_name: &str,
+        prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let action = near_primitives::transaction::Action::FunctionCall(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_owned(),
+                args: serde_json::json!({ "request_id": self.request_id })
+                    .to_string()
+                    .into_bytes(),
+                gas: super::MULTISIG_GAS,
+                deposit: 0,
+            },
+        );
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            actions: vec![action],
+            ..prepopulated_unsigned_transaction
+        };
+        match self
+            .sign_option
+            .process(
+                unsigned_transaction,
+                Some(network_connection_config.clone()),
+            )
+            .await?
+        {
+            Some(transaction_info) => {
+                crate::common::print_transaction_status(
+                    transaction_info,
+                    Some(network_connection_config),
+                );
+            }
+            None => {}
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+/// Run the near-cli commands from a plan file (YAML or JSON) one after another
+#[derive(Debug, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRunPlan {
+    /// The path to the plan file
+    plan_file: std::path::PathBuf,
+    /// Continue with the next steps if a step fails
+    #[clap(long)]
+    continue_on_error: bool,
+    /// The value for a plan variable (example: --set owner=alice.testnet)
+    #[clap(long = "set")]
+    values: Vec<crate::commands::template_command::TemplateValue>,
+}
+
+/// The plan is a list of steps. Each step is a near-cli console command, where
+/// {{variable}} and {{<step name>.<output>}} are replaced with their values.
+///
+/// ```yaml
+/// variables:
+///   owner: alice.testnet
+/// steps:
+///   - name: key
+///     command: utils generate-keypair
+///   - name: transfer
+///     command: transfer near network testnet sender {{owner}} receiver {{key.implicit_account_id}} amount '1 NEAR' sign-with-keychain send
+/// ```
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Plan {
+    #[serde(default)]
+    variables: std::collections::HashMap<String, String>,
+    #[serde(default)]
+    continue_on_error: bool,
+    steps: Vec<PlanStep>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct PlanStep {
+    name: Option<String>,
+    command: PlanStepCommand,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+enum PlanStepCommand {
+    Line(String),
+    Args(Vec<String>),
+}
+
+impl PlanStepCommand {
+    fn args(&self) -> Result<Vec<String>, String> {
+        match self {
+            Self::Line(line) => shell_words::split(line)
+                .map_err(|err| format!("Failed to split the command <{}>: {}", line, err)),
+            Self::Args(args) => Ok(args.clone()),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum StepStatus {
+    Succeeded,
+    Failed(String),
+    Skipped,
+}
+
+impl Plan {
+    fn from_file(path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to read the plan file {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        let is_json = path
+            .extension()
+            .map(|extension| extension == "json")
+            .unwrap_or(false);
+        if is_json {
+            serde_json::from_str(&data)
+                .map_err(|err| color_eyre::Report::msg(format!("Error reading the plan: {}", err)))
+        } else {
+            serde_yaml::from_str(&data)
+                .map_err(|err| color_eyre::Report::msg(format!("Error reading the plan: {}", err)))
+        }
+    }
+}
+
+impl CliRunPlan {
+    pub fn process(&self) -> crate::CliResult {
+        let plan = Plan::from_file(&self.plan_file)?;
+        let continue_on_error = self.continue_on_error || plan.continue_on_error;
+        let mut variables = plan.variables;
+        for template_value in self.values.iter() {
+            variables.insert(
+                template_value.placeholder.clone(),
+                template_value.value.clone(),
+            );
+        }
+        let mut report: Vec<(String, StepStatus)> = vec![];
+        let mut is_stopped = false;
+        for (index, step) in plan.steps.iter().enumerate() {
+            let step_name = step
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("step-{}", index + 1));
+            if is_stopped {
+                report.push((step_name, StepStatus::Skipped));
+                continue;
+            }
+            println!(
+                "\n===== Step {}/{}: {} =====",
+                index + 1,
+                plan.steps.len(),
+                step_name
+            );
+            match run_step(&step.command, &variables) {
+                Ok(outputs) => {
+                    for (name, value) in outputs {
+                        variables.insert(format!("{}.{}", step_name, name), value);
+                    }
+                    report.push((step_name, StepStatus::Succeeded));
+                }
+                Err(err) => {
+                    println!("The step <{}> failed: {}", step_name, err);
+                    report.push((step_name, StepStatus::Failed(err.to_string())));
+                    is_stopped = !continue_on_error;
+                }
+            }
+        }
+
+        println!("\n===== Report =====");
+        for (step_name, status) in report.iter() {
+            match status {
+                StepStatus::Succeeded => println!("{:<20} succeeded", step_name),
+                StepStatus::Failed(err) => println!("{:<20} failed: {}", step_name, err),
+                StepStatus::Skipped => println!("{:<20} skipped", step_name),
+            }
+        }
+        let failed_steps_count = report
+            .iter()
+            .filter(|(_, status)| matches!(status, StepStatus::Failed(_)))
+            .count();
+        if failed_steps_count > 0 {
+            return Err(color_eyre::Report::msg(format!(
+                "{} of {} steps failed",
+                failed_steps_count,
+                report.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Executes the step and returns the values it produced (transaction_hash, public_key, ...)
+fn run_step(
+    command: &PlanStepCommand,
+    variables: &std::collections::HashMap<String, String>,
+) -> color_eyre::eyre::Result<std::collections::HashMap<String, String>> {
+    let mut args = crate::commands::template_command::fill_placeholders(
+        &command.args().map_err(color_eyre::Report::msg)?,
+        variables,
+    )
+    .map_err(color_eyre::Report::msg)?;
+    args.insert(0, "./near-cli".to_owned());
+    let cli =
+        crate::CliArgs::try_parse_from(args.clone()).map_err(|err| color_eyre::eyre::eyre!(err))?;
+    match cli.top_level_command {
+        None
+        | Some(crate::commands::CliTopLevelCommand::GenerateShellCompletions(_))
+        | Some(crate::commands::CliTopLevelCommand::Run(_))
+        | Some(crate::commands::CliTopLevelCommand::Template(_)) => {
+            return Err(color_eyre::Report::msg(
+                "A step must be a single near-cli command",
+            ))
+        }
+        _ => {}
+    }
+    if cli.save_template.is_some() {
+        return Err(color_eyre::Report::msg(
+            "A step can't save a template: use --save-template with a single command",
+        ));
+    }
+    crate::interactive::set_command_args(args);
+    let no_interactive = cli.no_interactive;
+    let network = cli.network.clone();
+    let offline = cli.offline;
+    let signer = cli.signer.clone();
+    let process_result = crate::interactive::with_step_options(no_interactive, || {
+        crate::network::with_step_options(network, offline, signer, || {
+            // A missing argument in the non-interactive mode fails the step, not the whole plan
+            let args = crate::Args::from(cli)?;
+            let completed_cli = crate::CliArgs::from(args.clone());
+            println!("{}", shell_words::join(&completed_cli.to_cli_args()));
+            crate::common::take_command_outputs();
+            crate::common::block_on(args.process())
+        })
+    });
+    let outputs = crate::common::take_command_outputs();
+    process_result?;
+    match outputs.get("error") {
+        Some(err) => Err(color_eyre::Report::msg(err.clone())),
+        None => Ok(outputs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_plan_steps() {
+        let plan: Plan = serde_yaml::from_str(
+            "variables:\n  owner: alice.testnet\nsteps:\n  - name: key\n    command: utils generate-keypair\n  - command: [view, account-summary, '{{owner}}']\n",
+        )
+        .unwrap();
+        assert_eq!(plan.variables["owner"], "alice.testnet");
+        assert_eq!(plan.steps[0].name.as_deref(), Some("key"));
+        assert_eq!(
+            plan.steps[0].command.args(),
+            Ok(vec!["utils".to_owned(), "generate-keypair".to_owned()])
+        );
+        assert_eq!(
+            plan.steps[1].command.args(),
+            Ok(vec![
+                "view".to_owned(),
+                "account-summary".to_owned(),
+                "{{owner}}".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    fn apply_step_global_options() {
+        let plan_network = crate::network::network_setting();
+        let step_network = crate::network::with_step_options(
+            Some("mainnet".to_owned()),
+            false,
+            Some("alice.near".parse().unwrap()),
+            || Ok((crate::network::network_setting(), crate::network::signer())),
+        )
+        .unwrap();
+        assert_eq!(
+            step_network,
+            (
+                Some(("mainnet".to_owned(), "--network".to_owned())),
+                Some("alice.near".parse().unwrap())
+            )
+        );
+        assert_eq!(crate::network::network_setting(), plan_network);
+        assert!(
+            crate::network::with_step_options(Some("mainnet".to_owned()), true, None, || Ok(()))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, MultiSelect};
+use std::io::Write;
+
+/// Templates are the saved console commands with {{placeholders}} for the chosen fields
+#[derive(Debug, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliTemplate {
+    #[clap(subcommand)]
+    action: CliTemplateAction,
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliTemplateAction {
+    /// Replay a saved template
+    Run(CliRunTemplate),
+    /// List the saved templates
+    List,
+}
+
+/// Replay a saved template with the values for its placeholders
+#[derive(Debug, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliRunTemplate {
+    template_name: String,
+    /// The value for a placeholder (example: --set receiver=bob.near)
+    #[clap(long = "set")]
+    values: Vec<TemplateValue>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateValue {
+    pub placeholder: String,
+    pub value: String,
+}
+
+impl std::str::FromStr for TemplateValue {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(placeholder), Some(value)) if !placeholder.is_empty() => Ok(Self {
+                placeholder: placeholder.to_owned(),
+                value: value.to_owned(),
+            }),
+            _ => Err(format!(
+                "Template value <{}> must be in the format: placeholder=value",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct Template {
+    placeholders: Vec<String>,
+    args: Vec<String>,
+}
+
+/// The template name becomes a file name, so only `[A-Za-z0-9_-]` is allowed in it
+fn is_valid_template_name(template_name: &str) -> bool {
+    !template_name.is_empty()
+        && template_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn template_path(template_name: &str) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    if !is_valid_template_name(template_name) {
+        return Err(color_eyre::Report::msg(format!(
+            "Invalid template name <{}>: only letters, digits, '_' and '-' are allowed",
+            template_name
+        )));
+    }
+    let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+    path.push(crate::consts::DIR_NAME_TEMPLATES);
+    path.push(format!("{}.json", template_name));
+    Ok(path)
+}
+
+fn placeholder(name: &str) -> String {
+    format!("{{{{{}}}}}", name)
+}
+
+/// Replaces the {{placeholders}} in the template arguments with the given values
+pub fn fill_placeholders(
+    args: &[String],
+    values: &std::collections::HashMap<String, String>,
+) -> Result<Vec<String>, String> {
+    args.iter()
+        .map(|arg| {
+            let mut filled_arg = arg.clone();
+            for (name, value) in values {
+                filled_arg = filled_arg.replace(&placeholder(name), value);
+            }
+            match filled_arg.find("{{") {
+                Some(start) if filled_arg[start..].contains("}}") => Err(format!(
+                    "There is no value for the placeholder in <{}>",
+                    filled_arg
+                )),
+                _ => Ok(filled_arg),
+            }
+        })
+        .collect()
+}
+
+impl CliTemplate {
+    /// Returns the console command to be executed or None if there is nothing to execute
+    pub fn process(&self) -> color_eyre::eyre::Result<Option<Vec<String>>> {
+        match &self.action {
+            CliTemplateAction::Run(run_template) => run_template.process().map(Some),
+            CliTemplateAction::List => {
+                let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+                path.push(crate::consts::DIR_NAME_TEMPLATES);
+                let entries = match path.read_dir() {
+                    Ok(entries) => entries,
+                    Err(_) => {
+                        println!("There are no saved templates.");
+                        return Ok(None);
+                    }
+                };
+                for entry in entries.flatten() {
+                    let entry_path = entry.path();
+                    if let Some(template_name) = entry_path.file_stem().and_then(|s| s.to_str()) {
+                        let template: Template =
+                            serde_json::from_str(&std::fs::read_to_string(&entry_path)?)?;
+                        println!("{}: {}", template_name, shell_words::join(&template.args));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl CliRunTemplate {
+    fn process(&self) -> color_eyre::eyre::Result<Vec<String>> {
+        let path = template_path(&self.template_name)?;
+        let data = std::fs::read_to_string(&path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Template <{}> not found ({}): {}",
+                self.template_name,
+                path.display(),
+                err
+            ))
+        })?;
+        let template: Template = serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("Error reading the template: {}", err))
+        })?;
+        let mut values: std::collections::HashMap<String, String> = self
+            .values
+            .iter()
+            .map(|template_value| {
+                (
+                    template_value.placeholder.clone(),
+                    template_value.value.clone(),
+                )
+            })
+            .collect();
+        for name in template.placeholders.iter() {
+            if name == crate::consts::SECRET_KEY_PLACEHOLDER && !values.contains_key(name) {
+                return Err(color_eyre::Report::msg(format!(
+                    "The template <{}> needs the secret key: --set {}=...",
+                    self.template_name,
+                    crate::consts::SECRET_KEY_PLACEHOLDER
+                )));
+            }
+            if !values.contains_key(name) {
+                let value: String = Input::new()
+                    .with_prompt(format!("Enter the value for <{}>", name))
+                    .require_interactive("--set")?
+                    .interact_text()
+                    .unwrap();
+                values.insert(name.clone(), value);
+            }
+        }
+        let mut args =
+            fill_placeholders(&template.args, &values).map_err(color_eyre::Report::msg)?;
+        args.insert(0, "./near-cli".to_owned());
+        Ok(args)
+    }
+}
+
+/// The secret keys are never saved: the template asks for them with `--set signer_private_key=...`
+fn with_secret_key_placeholder(mut template: Template) -> Template {
+    let secret_key_placeholder = crate::consts::SECRET_KEY_PLACEHOLDER.to_owned();
+    if template
+        .args
+        .iter()
+        .any(|arg| arg == crate::consts::REDACTED_SECRET)
+        && !template.placeholders.contains(&secret_key_placeholder)
+    {
+        template.placeholders.push(secret_key_placeholder);
+    }
+    template
+}
+
+/// The template of the console command, prepared before the command is executed and saved
+/// only if it succeeds
+#[derive(Debug, Clone)]
+pub struct TemplateToSave {
+    name: String,
+    path: std::path::PathBuf,
+    template: Template,
+}
+
+impl TemplateToSave {
+    /// With `--save-template <name>` the user chooses which arguments become {{placeholders}};
+    /// `--save-template <name>:<placeholder>=<value>,...` replaces the arguments equal to the
+    /// values without prompts
+    pub fn prepare(
+        save_template: &str,
+        mut args: std::collections::VecDeque<String>,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut parts = save_template.splitn(2, ':');
+        let name = parts.next().unwrap_or_default().to_owned();
+        let path = template_path(&name)?;
+        args.pop_front();
+        let args: Vec<String> = args.into_iter().collect();
+        let template = match parts.next() {
+            Some(placeholders) => Self::template_from_values(
+                args,
+                &placeholders
+                    .split(',')
+                    .map(|value| value.parse())
+                    .collect::<Result<Vec<TemplateValue>, String>>()
+                    .map_err(color_eyre::Report::msg)?,
+            )
+            .map_err(color_eyre::Report::msg)?,
+            None => Self::choose_placeholders(args)?,
+        };
+        Ok(Self {
+            name,
+            path,
+            template: with_secret_key_placeholder(template),
+        })
+    }
+
+    fn template_from_values(
+        mut args: Vec<String>,
+        values: &[TemplateValue],
+    ) -> Result<Template, String> {
+        let mut placeholders: Vec<String> = vec![];
+        for template_value in values {
+            let mut is_found = false;
+            for arg in args.iter_mut().filter(|arg| **arg == template_value.value) {
+                *arg = placeholder(&template_value.placeholder);
+                is_found = true;
+            }
+            if !is_found {
+                return Err(format!(
+                    "There is no argument <{}> for the placeholder <{}>",
+                    template_value.value, template_value.placeholder
+                ));
+            }
+            if !placeholders.contains(&template_value.placeholder) {
+                placeholders.push(template_value.placeholder.clone());
+            }
+        }
+        Ok(Template { placeholders, args })
+    }
+
+    fn choose_placeholders(mut args: Vec<String>) -> color_eyre::eyre::Result<Template> {
+        println!();
+        let selected_args = MultiSelect::with_theme(&ColorfulTheme::default())
+            .with_prompt(
+                "Choose the arguments to be replaced with placeholders (press Space to select)",
+            )
+            .items(&args)
+            .require_interactive("--save-template")?
+            .interact()
+            .unwrap();
+        let mut placeholders: Vec<String> = vec![];
+        for index in selected_args {
+            let name: String = Input::new()
+                .with_prompt(format!("Enter the placeholder name for <{}>", args[index]))
+                .require_interactive("--save-template")?
+                .interact_text()
+                .unwrap();
+            args[index] = placeholder(&name);
+            if !placeholders.contains(&name) {
+                placeholders.push(name);
+            }
+        }
+        Ok(Template { placeholders, args })
+    }
+
+    pub fn save(&self) -> crate::CliResult {
+        std::fs::create_dir_all(self.path.parent().unwrap())?;
+        std::fs::File::create(&self.path)
+            .map_err(|err| color_eyre::Report::msg(format!("Failed to create file: {:?}", err)))?
+            .write_all(serde_json::to_string_pretty(&self.template)?.as_bytes())
+            .map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to write to file: {:?}", err))
+            })?;
+        println!(
+            "The template <{}> is saved in the file {}\nTo replay it: ./near-cli template run {}{}",
+            self.name,
+            self.path.display(),
+            self.name,
+            self.template
+                .placeholders
+                .iter()
+                .map(|name| format!(" --set {}=...", name))
+                .collect::<String>()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_template_placeholders() {
+        let args: Vec<String> = vec!["receiver".to_owned(), "{{receiver}}".to_owned()];
+        let mut values = std::collections::HashMap::new();
+        values.insert("receiver".to_owned(), "bob.near".to_owned());
+        assert_eq!(
+            fill_placeholders(&args, &values),
+            Ok(vec!["receiver".to_owned(), "bob.near".to_owned()])
+        );
+    }
+    #[test]
+    fn fill_template_placeholders_without_value() {
+        let args: Vec<String> = vec!["amount".to_owned(), "{{amount}} NEAR".to_owned()];
+        assert_eq!(
+            fill_placeholders(&args, &std::collections::HashMap::new()),
+            Err("There is no value for the placeholder in <{{amount}} NEAR>".to_owned())
+        );
+    }
+    #[test]
+    fn reject_template_names_with_path_components() {
+        assert!(is_valid_template_name("send-near_2"));
+        assert!(!is_valid_template_name(""));
+        assert!(!is_valid_template_name(".."));
+        assert!(!is_valid_template_name("../config"));
+        assert!(!is_valid_template_name("dir/name"));
+        assert!(!is_valid_template_name("dir\\name"));
+    }
+    #[test]
+    fn template_placeholders_from_values() {
+        let args: Vec<String> = vec![
+            "transfer".to_owned(),
+            "receiver".to_owned(),
+            "bob.near".to_owned(),
+        ];
+        let template = TemplateToSave::template_from_values(
+            args.clone(),
+            &["receiver=bob.near".parse().unwrap()],
+        )
+        .unwrap();
+        assert_eq!(template.placeholders, vec!["receiver".to_owned()]);
+        assert_eq!(
+            template.args,
+            vec![
+                "transfer".to_owned(),
+                "receiver".to_owned(),
+                "{{receiver}}".to_owned()
+            ]
+        );
+        assert!(
+            TemplateToSave::template_from_values(args, &["amount=1 NEAR".parse().unwrap()])
+                .is_err()
+        );
+    }
+    #[test]
+    fn template_with_secret_key_placeholder() {
+        let template = with_secret_key_placeholder(Template {
+            placeholders: vec![],
+            args: vec![
+                "sign-with-plaintext-private-key".to_owned(),
+                crate::consts::REDACTED_SECRET.to_owned(),
+            ],
+        });
+        assert_eq!(template.placeholders, vec!["signer_private_key".to_owned()]);
+        let mut values = std::collections::HashMap::new();
+        values.insert("signer_private_key".to_owned(), "ed25519:secret".to_owned());
+        assert_eq!(
+            fill_placeholders(&template.args, &values),
+            Ok(vec![
+                "sign-with-plaintext-private-key".to_owned(),
+                "ed25519:secret".to_owned()
+            ])
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -118,6 +119,7 @@ impl CurrencySelection {
             .with_prompt("What do you want to transfer?")
             .items(&currencies)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_currency = match variants[selected_currency] {
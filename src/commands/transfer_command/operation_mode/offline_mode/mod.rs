@@ -7,12 +7,12 @@
 )]
 pub struct CliOfflineArgs {
     #[clap(subcommand)]
-    pub send_from: Option<super::online_mode::select_server::server::CliSendFrom>,
+    pub send_from: Option<super::online_mode::CliSendFrom>,
 }
 
 #[derive(Debug, Clone)]
 pub struct OfflineArgs {
-    send_from: super::online_mode::select_server::server::SendFrom,
+    send_from: super::online_mode::SendFrom,
 }
 
 impl CliOfflineArgs {
@@ -35,10 +35,8 @@ impl From<OfflineArgs> for CliOfflineArgs {
 impl OfflineArgs {
     pub fn from(item: CliOfflineArgs) -> color_eyre::eyre::Result<Self> {
         let send_from = match item.send_from {
-            Some(cli_send_from) => {
-                super::online_mode::select_server::server::SendFrom::from(cli_send_from, None)?
-            }
-            None => super::online_mode::select_server::server::SendFrom::choose_send_from(None)?,
+            Some(cli_send_from) => super::online_mode::SendFrom::from(cli_send_from, None)?,
+            None => super::online_mode::SendFrom::choose_send_from(None)?,
         };
         Ok(Self { send_from })
     }
@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -125,24 +126,8 @@ impl Receiver {
     ) -> color_eyre::eyre::Result<Self> {
         let receiver_account_id: near_primitives::types::AccountId = match item.receiver_account_id
         {
-            Some(cli_receiver_account_id) => match &connection_config {
-                Some(network_connection_config) => match crate::common::get_account_state(
-                    network_connection_config,
-                    cli_receiver_account_id.clone(),
-                )? {
-                    Some(_) => cli_receiver_account_id,
-                    None => {
-                        if !crate::common::is_64_len_hex(&cli_receiver_account_id) {
-                            println!("Account <{}> doesn't exist", cli_receiver_account_id);
-                            Receiver::input_receiver_account_id(connection_config.clone())?
-                        } else {
-                            cli_receiver_account_id
-                        }
-                    }
-                },
-                None => cli_receiver_account_id,
-            },
-            None => Receiver::input_receiver_account_id(connection_config.clone())?,
+            Some(cli_receiver_account_id) => cli_receiver_account_id,
+            None => Receiver::input_receiver_account_id()?,
         };
         let transfer: super::transfer_near_tokens_type::Transfer = match item.transfer {
             Some(cli_transfer) => super::transfer_near_tokens_type::Transfer::from(
@@ -163,30 +148,12 @@ impl Receiver {
 }
 
 impl Receiver {
-    fn input_receiver_account_id(
-        connection_config: Option<crate::common::ConnectionConfig>,
-    ) -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
-        loop {
-            let account_id: near_primitives::types::AccountId = Input::new()
-                .with_prompt("What is the account ID of the receiver?")
-                .interact_text()
-                .unwrap();
-            if let Some(connection_config) = &connection_config {
-                if let Some(_) =
-                    crate::common::get_account_state(connection_config, account_id.clone())?
-                {
-                    break Ok(account_id);
-                } else {
-                    if !crate::common::is_64_len_hex(&account_id) {
-                        println!("Account <{}> doesn't exist", account_id.to_string());
-                    } else {
-                        break Ok(account_id);
-                    }
-                }
-            } else {
-                break Ok(account_id);
-            }
-        }
+    fn input_receiver_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the receiver?")
+            .require_interactive("<receiver-account-id>")?
+            .interact_text()
+            .unwrap())
     }
 
     pub async fn process(
@@ -194,6 +161,13 @@ impl Receiver {
         prepopulated_unsigned_transaction: near_primitives::transaction::Transaction,
         network_connection_config: Option<crate::common::ConnectionConfig>,
     ) -> crate::CliResult {
+        // An implicit account is created by the transfer
+        if let Some(connection_config) = &network_connection_config {
+            if !crate::common::is_64_len_hex(&self.receiver_account_id) {
+                crate::common::verify_account_exists(connection_config, &self.receiver_account_id)
+                    .await?;
+            }
+        }
         let unsigned_transaction = near_primitives::transaction::Transaction {
             receiver_id: self.receiver_account_id.clone(),
             ..prepopulated_unsigned_transaction
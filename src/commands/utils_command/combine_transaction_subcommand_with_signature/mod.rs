@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use near_primitives::borsh::BorshSerialize;
 
@@ -54,38 +57,43 @@ impl From<CombineTransactionSignature> for CliCombineTransactionSignature {
     }
 }
 
-impl From<CliCombineTransactionSignature> for CombineTransactionSignature {
-    fn from(item: CliCombineTransactionSignature) -> Self {
+impl TryFrom<CliCombineTransactionSignature> for CombineTransactionSignature {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliCombineTransactionSignature) -> Result<Self, Self::Error> {
         let signature: near_crypto::Signature = match item.signature {
             Some(cli_signature) => cli_signature,
-            None => CombineTransactionSignature::input_signature(),
+            None => CombineTransactionSignature::input_signature()?,
         };
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => CombineTransactionSignature::input_unsigned_transaction(),
+                None => CombineTransactionSignature::input_unsigned_transaction()?,
             };
-        Self {
+        Ok(Self {
             signature,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl CombineTransactionSignature {
-    pub fn input_signature() -> near_crypto::Signature {
-        Input::new()
+    pub fn input_signature() -> color_eyre::eyre::Result<near_crypto::Signature> {
+        Ok(Input::new()
             .with_prompt("Enter the signature")
+            .require_interactive("--signature")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
+            .require_interactive("--unsigned-transaction")?
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
     pub async fn process(self) -> crate::CliResult {
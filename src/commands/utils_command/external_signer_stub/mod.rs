@@ -0,0 +1,84 @@
+use std::io::BufRead;
+
+/// A reference external signer for `sign-with-external-signer --signer-command`: it answers one
+/// request from stdin with the private key. Use a wrapper script to pass the key options, e.g.
+/// `exec near-cli utils external-signer-stub --private-key-file key.json`.
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliExternalSignerStub {
+    /// Read the private key from the file (the key itself or a keychain access key file)
+    #[clap(long)]
+    private_key_file: Option<std::path::PathBuf>,
+    /// Read the private key from the environment variable
+    #[clap(long)]
+    private_key_env: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalSignerStub {
+    private_key: near_crypto::SecretKey,
+    private_key_source: crate::common::SecretKeySource,
+}
+
+impl CliExternalSignerStub {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(var) = &self.private_key_env {
+            args.push_front(var.clone());
+            args.push_front("--private-key-env".to_owned())
+        }
+        if let Some(path) = &self.private_key_file {
+            args.push_front(path.display().to_string());
+            args.push_front("--private-key-file".to_owned())
+        }
+        args
+    }
+}
+
+impl From<ExternalSignerStub> for CliExternalSignerStub {
+    fn from(external_signer_stub: ExternalSignerStub) -> Self {
+        match external_signer_stub.private_key_source {
+            crate::common::SecretKeySource::File(path) => Self {
+                private_key_file: Some(path),
+                private_key_env: None,
+            },
+            crate::common::SecretKeySource::Env(var) => Self {
+                private_key_file: None,
+                private_key_env: Some(var),
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+impl ExternalSignerStub {
+    pub fn from(item: CliExternalSignerStub) -> color_eyre::eyre::Result<Self> {
+        // stdin carries the request, so the key can't be read from there
+        match crate::common::read_secret_key(
+            None,
+            item.private_key_file,
+            item.private_key_env,
+            false,
+        )? {
+            Some((private_key, private_key_source)) => Ok(Self {
+                private_key,
+                private_key_source,
+            }),
+            None => Err(color_eyre::Report::msg(
+                "The private key is missing: use --private-key-file or --private-key-env",
+            )),
+        }
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let mut request_line = String::new();
+        std::io::stdin().lock().read_line(&mut request_line)?;
+        let response = crate::external_signer::respond(&request_line, &self.private_key);
+        println!("{}", serde_json::to_string(&response)?);
+        Ok(())
+    }
+}
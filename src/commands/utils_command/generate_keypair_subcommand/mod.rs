@@ -1,7 +1,6 @@
 use std::str::FromStr;
 
-/// Generate a key pair of private and public keys (use it anywhere you need
-/// Ed25519 keys)
+/// Generate a key pair of private and public keys (ed25519 unless `--key-type secp256k1`)
 #[derive(Debug, Clone, clap::Clap)]
 pub struct CliGenerateKeypair {
     #[clap(long)]
@@ -10,8 +9,12 @@ pub struct CliGenerateKeypair {
     pub new_master_seed_phrase_words_count: usize,
     #[clap(long, default_value = "m/44'/397'/0'")]
     pub seed_phrase_hd_path: slip10::BIP32Path,
-    #[clap(long, default_value = "plaintext")]
-    pub format: crate::common::OutputFormat,
+    /// ed25519 or secp256k1 (the secp256k1 keys are random, not derived from a seed phrase)
+    #[clap(long)]
+    pub key_type: Option<near_crypto::KeyType>,
+    /// plaintext or json (the output_format of the project defaults, or plaintext)
+    #[clap(long)]
+    pub format: Option<crate::common::OutputFormat>,
 }
 
 impl Default for CliGenerateKeypair {
@@ -20,21 +23,52 @@ impl Default for CliGenerateKeypair {
             master_seed_phrase: None,
             new_master_seed_phrase_words_count: 12,
             seed_phrase_hd_path: slip10::BIP32Path::from_str("m/44'/397'/0'").unwrap(),
-            format: crate::common::OutputFormat::Json,
+            key_type: None,
+            format: Some(crate::common::OutputFormat::Json),
         }
     }
 }
 
 impl CliGenerateKeypair {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(format) = &self.format {
+            args.push_front(format.to_string());
+            args.push_front("--format".to_owned());
+        }
+        if let Some(key_type) = &self.key_type {
+            args.push_front(key_type.to_string());
+            args.push_front("--key-type".to_owned());
+        }
+        args
+    }
+
     pub async fn process(self) -> crate::CliResult {
-        let key_pair_properties = crate::common::generate_keypair().await?;
-        match self.format {
+        let key_pair_properties =
+            crate::common::generate_keypair(self.key_type.unwrap_or(near_crypto::KeyType::ED25519))
+                .await?;
+        match self
+            .format
+            .or_else(crate::project_config::output_format)
+            .unwrap_or_default()
+        {
             crate::common::OutputFormat::Plaintext => {
+                if let (Some(master_seed_phrase), Some(seed_phrase_hd_path)) = (
+                    &key_pair_properties.master_seed_phrase,
+                    &key_pair_properties.seed_phrase_hd_path,
+                ) {
+                    println!(
+                        "Master Seed Phrase: {}\nSeed Phrase HD Path: {}",
+                        master_seed_phrase,
+                        seed_phrase_hd_path.to_string(),
+                    );
+                }
+                if let Some(implicit_account_id) = &key_pair_properties.implicit_account_id {
+                    println!("Implicit Account ID: {}", implicit_account_id);
+                }
                 println!(
-                    "Master Seed Phrase: {}\nSeed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
-                    key_pair_properties.master_seed_phrase,
-                    key_pair_properties.seed_phrase_hd_path.to_string(),
-                    key_pair_properties.implicit_account_id,
+                    "Key Type: {}\nPublic Key: {}\nSECRET KEYPAIR: {}",
+                    key_pair_properties.key_type,
                     key_pair_properties.public_key_str,
                     key_pair_properties.secret_keypair_str,
                 );
@@ -44,8 +78,11 @@ impl CliGenerateKeypair {
                     "{}",
                     serde_json::to_string_pretty(&serde_json::json!({
                         "master_seed_phrase": key_pair_properties.master_seed_phrase,
-                        "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
+                        "seed_phrase_hd_path": key_pair_properties
+                            .seed_phrase_hd_path
+                            .map(|seed_phrase_hd_path| seed_phrase_hd_path.to_string()),
                         "account_id": key_pair_properties.implicit_account_id,
+                        "key_type": key_pair_properties.key_type.to_string(),
                         "public_key": key_pair_properties.public_key_str,
                         "private_key": key_pair_properties.secret_keypair_str,
                     }))
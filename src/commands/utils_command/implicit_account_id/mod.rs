@@ -0,0 +1,118 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+use std::str::FromStr;
+
+/// Using this utility, you can convert a public key to the implicit account ID and back.
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliImplicitAccountId {
+    public_key_or_account_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImplicitAccountId {
+    public_key_or_account_id: String,
+}
+
+impl CliImplicitAccountId {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(public_key_or_account_id) = &self.public_key_or_account_id {
+            args.push_front(public_key_or_account_id.to_string());
+        }
+        args
+    }
+}
+
+impl From<ImplicitAccountId> for CliImplicitAccountId {
+    fn from(implicit_account_id: ImplicitAccountId) -> Self {
+        Self {
+            public_key_or_account_id: Some(implicit_account_id.public_key_or_account_id),
+        }
+    }
+}
+
+impl TryFrom<CliImplicitAccountId> for ImplicitAccountId {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliImplicitAccountId) -> Result<Self, Self::Error> {
+        let public_key_or_account_id: String = match item.public_key_or_account_id {
+            Some(public_key_or_account_id) => public_key_or_account_id,
+            None => ImplicitAccountId::input_public_key_or_account_id()?,
+        };
+        Ok(Self {
+            public_key_or_account_id,
+        })
+    }
+}
+
+impl ImplicitAccountId {
+    fn input_public_key_or_account_id() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Enter the public key (ed25519:...) or the implicit account ID")
+            .require_interactive("<public-key-or-account-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        if crate::common::is_64_len_hex(&self.public_key_or_account_id) {
+            let public_key = public_key_from_implicit_account_id(&self.public_key_or_account_id)?;
+            println!("Public key: {}", public_key);
+        } else {
+            let public_key = near_crypto::PublicKey::from_str(&self.public_key_or_account_id)
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "The value is neither a public key nor an implicit account ID: {}",
+                        err
+                    ))
+                })?;
+            println!(
+                "Implicit account ID: {}",
+                implicit_account_id_from_public_key(&public_key)?
+            );
+        }
+        Ok(())
+    }
+}
+
+fn implicit_account_id_from_public_key(
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<String> {
+    match public_key {
+        near_crypto::PublicKey::ED25519(ed25519_public_key) => {
+            Ok(hex::encode(&ed25519_public_key.0))
+        }
+        _ => Err(color_eyre::Report::msg(
+            "Only ED25519 public keys can be used for implicit accounts",
+        )),
+    }
+}
+
+fn public_key_from_implicit_account_id(
+    implicit_account_id: &str,
+) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+    let key_data = hex::decode(implicit_account_id)?;
+    near_crypto::PublicKey::from_str(&format!("ed25519:{}", bs58::encode(key_data).into_string()))
+        .map_err(|err| color_eyre::Report::msg(format!("Invalid public key: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_public_key_to_implicit_account_id_and_back() {
+        let public_key = near_crypto::PublicKey::from_str(
+            "ed25519:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+        )
+        .unwrap();
+        let implicit_account_id = implicit_account_id_from_public_key(&public_key).unwrap();
+        assert!(crate::common::is_64_len_hex(&implicit_account_id));
+        assert_eq!(
+            public_key_from_implicit_account_id(&implicit_account_id).unwrap(),
+            public_key
+        );
+    }
+}
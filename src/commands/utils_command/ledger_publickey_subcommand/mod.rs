@@ -2,39 +2,63 @@ use std::str::FromStr;
 
 /// Generate a key pair of private and public keys (use it anywhere you need
 /// Ed25519 keys)
-#[derive(Debug, Clone, clap::Clap)]
+#[derive(Debug, Default, Clone, clap::Clap)]
 pub struct CliLedgerPublicKey {
-    #[clap(long, default_value = "44'/397'/0'/0'/1'")]
-    pub seed_phrase_hd_path: slip10::BIP32Path,
-    #[clap(long, default_value = "plaintext")]
-    pub format: crate::common::OutputFormat,
-}
-
-impl Default for CliLedgerPublicKey {
-    fn default() -> Self {
-        Self {
-            seed_phrase_hd_path: slip10::BIP32Path::from_str("44'/397'/0'/0'/1'").unwrap(),
-            format: crate::common::OutputFormat::Plaintext,
-        }
-    }
+    /// The HD path of the key (the path saved for --account-id, or 44'/397'/0'/0'/1')
+    #[clap(long)]
+    pub seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    /// Use the HD path saved for the account with `ledger accounts`
+    #[clap(long)]
+    pub account_id: Option<near_primitives::types::AccountId>,
+    /// plaintext or json (the output_format of the project defaults, or plaintext)
+    #[clap(long)]
+    pub format: Option<crate::common::OutputFormat>,
 }
 
 impl CliLedgerPublicKey {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         let mut args = std::collections::VecDeque::new();
-        args.push_front(self.format.to_string().clone());
-        args.push_front("--format".to_string());
-        args.push_front(self.seed_phrase_hd_path.to_string());
-        args.push_front("--seed-phrase-hd-path".to_string());
+        if let Some(format) = &self.format {
+            args.push_front(format.to_string());
+            args.push_front("--format".to_string());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_string());
+        }
+        if let Some(seed_phrase_hd_path) = &self.seed_phrase_hd_path {
+            args.push_front(seed_phrase_hd_path.to_string());
+            args.push_front("--seed-phrase-hd-path".to_string());
+        }
         args
     }
 
+    fn resolve_seed_phrase_hd_path(&self) -> slip10::BIP32Path {
+        if let Some(seed_phrase_hd_path) = &self.seed_phrase_hd_path {
+            return seed_phrase_hd_path.clone();
+        }
+        if let Some(account_id) = &self.account_id {
+            match crate::config::get_saved_ledger_hd_path(account_id) {
+                Some(hd_path) => {
+                    println!("Using the HD Path {} saved for <{}>", hd_path, account_id);
+                    return hd_path;
+                }
+                None => println!(
+                    "There is no HD Path saved for <{}>, using the default one",
+                    account_id
+                ),
+            }
+        }
+        slip10::BIP32Path::from_str(crate::consts::DEFAULT_LEDGER_HD_PATH).unwrap()
+    }
+
     pub async fn process(self) -> crate::CliResult {
+        let seed_phrase_hd_path = self.resolve_seed_phrase_hd_path();
         println!(
             "Please allow getting the PublicKey on Ledger device (HD Path: {})",
-            self.seed_phrase_hd_path.to_string(),
+            seed_phrase_hd_path.to_string(),
         );
-        let public_key = near_ledger::get_public_key(self.seed_phrase_hd_path.clone())
+        let public_key = near_ledger::get_public_key(seed_phrase_hd_path.clone())
             .await
             .map_err(|near_ledger_error| {
                 color_eyre::Report::msg(format!(
@@ -45,11 +69,15 @@ impl CliLedgerPublicKey {
 
         let implicit_account_id = hex::encode(&public_key);
 
-        match self.format {
+        match self
+            .format
+            .or_else(crate::project_config::output_format)
+            .unwrap_or_default()
+        {
             crate::common::OutputFormat::Plaintext => {
                 println!(
                     "Seed Phrase HD Path: {}\nImplicit Account ID: {}\nPublic Key: {}",
-                    self.seed_phrase_hd_path.to_string(),
+                    seed_phrase_hd_path.to_string(),
                     implicit_account_id,
                     near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
                         public_key.to_bytes(),
@@ -60,7 +88,7 @@ impl CliLedgerPublicKey {
                 println!(
                     "{}",
                     serde_json::to_string_pretty(&serde_json::json!({
-                        "seed_phrase_hd_path": self.seed_phrase_hd_path.to_string(),
+                        "seed_phrase_hd_path": seed_phrase_hd_path.to_string(),
                         "account_id": implicit_account_id,
                         "public_key": near_crypto::PublicKey::ED25519(
                             near_crypto::ED25519PublicKey::from(
@@ -1,12 +1,20 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod combine_transaction_subcommand_with_signature;
+mod external_signer_stub;
 pub mod generate_keypair_subcommand;
+mod implicit_account_id;
 mod ledger_publickey_subcommand;
+mod relay_delegate_action;
 mod send_signed_transaction;
+mod sign_message_subcommand;
 mod sign_transaction_subcommand_with_secret_key;
 mod sign_transaction_with_ledger_subcommand;
+mod verify_message_subcommand;
 mod view_serialized_transaction;
 
 /// набор утилит-помощников
@@ -43,13 +51,13 @@ impl From<Utils> for CliUtils {
     }
 }
 
-impl From<CliUtils> for Utils {
-    fn from(item: CliUtils) -> Self {
+impl Utils {
+    pub fn from(item: CliUtils) -> color_eyre::eyre::Result<Self> {
         let util = match item.util {
-            Some(cli_util) => Util::from(cli_util),
-            None => Util::choose_util(),
+            Some(cli_util) => Util::from(cli_util)?,
+            None => Util::choose_util()?,
         };
-        Self { util }
+        Ok(Self { util })
     }
 }
 
@@ -81,6 +89,16 @@ enum CliUtil {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     /// Send signed transaction
     SendSignedTransaction(self::send_signed_transaction::operation_mode::CliOperationMode),
+    /// Convert a public key to the implicit account ID and back
+    ImplicitAccountId(self::implicit_account_id::CliImplicitAccountId),
+    /// Sign a message off chain (NEP-413) to prove the account ownership
+    SignMessage(self::sign_message_subcommand::CliSignMessage),
+    /// Verify a signed message (NEP-413) and the access key of the signer account
+    VerifyMessage(self::verify_message_subcommand::operation_mode::CliOperationMode),
+    /// Answer an external signer request with a private key (a reference signer for testing)
+    ExternalSignerStub(self::external_signer_stub::CliExternalSignerStub),
+    /// Submit a signed delegate action (NEP-366) through a relayer
+    RelayDelegateAction(self::relay_delegate_action::operation_mode::CliOperationMode),
 }
 
 #[derive(Debug, Clone, EnumDiscriminants)]
@@ -106,13 +124,27 @@ pub enum Util {
     LedgerPublicKey(self::ledger_publickey_subcommand::CliLedgerPublicKey),
     #[strum_discriminants(strum(message = "Send signed transaction"))]
     SendSignedTransaction(self::send_signed_transaction::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Convert a public key to the implicit account ID and back"
+    ))]
+    ImplicitAccountId(self::implicit_account_id::ImplicitAccountId),
+    #[strum_discriminants(strum(message = "Sign a message (NEP-413)"))]
+    SignMessage(self::sign_message_subcommand::SignMessage),
+    #[strum_discriminants(strum(message = "Verify a signed message (NEP-413)"))]
+    VerifyMessage(self::verify_message_subcommand::operation_mode::OperationMode),
+    #[strum_discriminants(strum(
+        message = "Answer an external signer request with a private key (for testing)"
+    ))]
+    ExternalSignerStub(self::external_signer_stub::ExternalSignerStub),
+    #[strum_discriminants(strum(message = "Relay a signed delegate action (NEP-366)"))]
+    RelayDelegateAction(self::relay_delegate_action::operation_mode::OperationMode),
 }
 
 impl CliUtil {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
         match self {
-            Self::GenerateKeypair(_) => {
-                let mut args = std::collections::VecDeque::new();
+            Self::GenerateKeypair(subcommand) => {
+                let mut args = subcommand.to_cli_args();
                 args.push_front("generate-keypair".to_owned());
                 args
             }
@@ -146,6 +178,31 @@ impl CliUtil {
                 args.push_front("send-signed-transaction".to_owned());
                 args
             }
+            Self::ImplicitAccountId(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("implicit-account-id".to_owned());
+                args
+            }
+            Self::SignMessage(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("sign-message".to_owned());
+                args
+            }
+            Self::VerifyMessage(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("verify-message".to_owned());
+                args
+            }
+            Self::ExternalSignerStub(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("external-signer-stub".to_owned());
+                args
+            }
+            Self::RelayDelegateAction(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("relay-delegate-action".to_owned());
+                args
+            }
         }
     }
 }
@@ -170,48 +227,80 @@ impl From<Util> for CliUtil {
             Util::SendSignedTransaction(operation_mode) => {
                 Self::SendSignedTransaction(operation_mode.into())
             }
+            Util::ImplicitAccountId(implicit_account_id) => {
+                Self::ImplicitAccountId(implicit_account_id.into())
+            }
+            Util::SignMessage(sign_message) => Self::SignMessage(sign_message.into()),
+            Util::VerifyMessage(operation_mode) => Self::VerifyMessage(operation_mode.into()),
+            Util::ExternalSignerStub(external_signer_stub) => {
+                Self::ExternalSignerStub(external_signer_stub.into())
+            }
+            Util::RelayDelegateAction(operation_mode) => {
+                Self::RelayDelegateAction(operation_mode.into())
+            }
         }
     }
 }
 
-impl From<CliUtil> for Util {
-    fn from(item: CliUtil) -> Self {
-        match item {
+impl Util {
+    fn from(item: CliUtil) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
             CliUtil::GenerateKeypair(generate_keypair) => Util::GenerateKeypair(generate_keypair),
             CliUtil::SignTransactionPrivateKey(cli_sign_transaction) => {
                 let sign_transaction =
-                    self::sign_transaction_subcommand_with_secret_key::SignTransactionPrivateKey::from(cli_sign_transaction);
+                    self::sign_transaction_subcommand_with_secret_key::SignTransactionPrivateKey::from(cli_sign_transaction)?;
                 Util::SignTransactionPrivateKey(sign_transaction)
             }
             CliUtil::SignTransactionWithLedger(cli_sign_transaction_with_ledger) => {
                 let sign_transaction =
-                    self::sign_transaction_with_ledger_subcommand::SignTransactionWithLedger::from(
+                    self::sign_transaction_with_ledger_subcommand::SignTransactionWithLedger::try_from(
                         cli_sign_transaction_with_ledger,
-                    );
+                    )?;
                 Util::SignTransactionWithLedger(sign_transaction)
             }
             CliUtil::CombineTransactionSignature(cli_combine_transaction) => {
                 let combine_transaction =
-                    self::combine_transaction_subcommand_with_signature::CombineTransactionSignature::from(cli_combine_transaction);
+                    self::combine_transaction_subcommand_with_signature::CombineTransactionSignature::try_from(cli_combine_transaction)?;
                 Util::CombineTransactionSignature(combine_transaction)
             }
             CliUtil::ViewSerializedTransaction(cli_view_serialized_transaction) => {
                 let view_serialized_transaction =
-                    self::view_serialized_transaction::ViewSerializedTransaction::from(
+                    self::view_serialized_transaction::ViewSerializedTransaction::try_from(
                         cli_view_serialized_transaction,
-                    );
+                    )?;
                 Util::ViewSerializedTransaction(view_serialized_transaction)
             }
             CliUtil::LedgerPublicKey(ledger_publickey) => Util::LedgerPublicKey(ledger_publickey),
-            CliUtil::SendSignedTransaction(cli_operation_mode) => {
-                Util::SendSignedTransaction(cli_operation_mode.into())
+            CliUtil::SendSignedTransaction(cli_operation_mode) => Util::SendSignedTransaction(
+                self::send_signed_transaction::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+            CliUtil::ImplicitAccountId(cli_implicit_account_id) => {
+                Util::ImplicitAccountId(cli_implicit_account_id.try_into()?)
             }
-        }
+            CliUtil::SignMessage(cli_sign_message) => Util::SignMessage(
+                self::sign_message_subcommand::SignMessage::from(cli_sign_message)?,
+            ),
+            CliUtil::VerifyMessage(cli_operation_mode) => Util::VerifyMessage(
+                self::verify_message_subcommand::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+            CliUtil::ExternalSignerStub(cli_external_signer_stub) => Util::ExternalSignerStub(
+                self::external_signer_stub::ExternalSignerStub::from(cli_external_signer_stub)?,
+            ),
+            CliUtil::RelayDelegateAction(cli_operation_mode) => Util::RelayDelegateAction(
+                self::relay_delegate_action::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+        })
     }
 }
 
 impl Util {
-    fn choose_util() -> Self {
+    fn choose_util() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = UtilDiscriminants::iter().collect::<Vec<_>>();
         let utils = variants
@@ -222,6 +311,7 @@ impl Util {
             .with_prompt("Choose your action")
             .items(&utils)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_util = match variants[selection] {
@@ -246,6 +336,15 @@ impl Util {
             UtilDiscriminants::SendSignedTransaction => {
                 CliUtil::SendSignedTransaction(Default::default())
             }
+            UtilDiscriminants::ImplicitAccountId => CliUtil::ImplicitAccountId(Default::default()),
+            UtilDiscriminants::SignMessage => CliUtil::SignMessage(Default::default()),
+            UtilDiscriminants::VerifyMessage => CliUtil::VerifyMessage(Default::default()),
+            UtilDiscriminants::ExternalSignerStub => {
+                CliUtil::ExternalSignerStub(Default::default())
+            }
+            UtilDiscriminants::RelayDelegateAction => {
+                CliUtil::RelayDelegateAction(Default::default())
+            }
         };
         Self::from(cli_util)
     }
@@ -263,6 +362,11 @@ impl Util {
             }
             Self::LedgerPublicKey(ledger_publickey) => ledger_publickey.process().await,
             Self::SendSignedTransaction(operation_mode) => operation_mode.process().await,
+            Self::ImplicitAccountId(implicit_account_id) => implicit_account_id.process().await,
+            Self::SignMessage(sign_message) => sign_message.process().await,
+            Self::VerifyMessage(operation_mode) => operation_mode.process().await,
+            Self::ExternalSignerStub(external_signer_stub) => external_signer_stub.process().await,
+            Self::RelayDelegateAction(operation_mode) => operation_mode.process().await,
         }
     }
 }
@@ -0,0 +1,278 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+pub mod operation_mode;
+
+/// Submit a signed delegate action through a relayer service or a relayer account
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliRelayDelegateAction {
+    /// The base64-encoded signed delegate action (`construct-transaction --as-delegate`)
+    signed_delegate_action: Option<String>,
+    /// The relayer endpoint to post the delegate action to (defaults to `relayer_urls` in ~/.near-cli/config.json)
+    #[clap(long)]
+    relayer_url: Option<url::Url>,
+    /// The account that signs and pays for the transaction with the delegate action
+    #[clap(long)]
+    relayer_account_id: Option<near_primitives::types::AccountId>,
+    /// The private key of the relayer account (the keychain is used if no key is given)
+    #[clap(long)]
+    relayer_private_key: Option<near_crypto::SecretKey>,
+    /// Read the private key of the relayer account from the file
+    #[clap(long)]
+    relayer_private_key_file: Option<std::path::PathBuf>,
+    /// Read the private key of the relayer account from the environment variable
+    #[clap(long)]
+    relayer_private_key_env: Option<String>,
+    /// Read the private key of the relayer account from the first line of stdin
+    #[clap(long)]
+    relayer_private_key_stdin: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelayDelegateAction {
+    signed_delegate_action: crate::delegate_action::SignedDelegateAction,
+    relayer: Relayer,
+}
+
+#[derive(Debug, Clone)]
+enum Relayer {
+    /// A relayer service that sends the transaction itself
+    Url(url::Url),
+    /// The relayer account signs the transaction here; without the private key it's taken from the keychain
+    Account {
+        account_id: near_primitives::types::AccountId,
+        private_key: Option<(near_crypto::SecretKey, crate::common::SecretKeySource)>,
+    },
+}
+
+impl CliRelayDelegateAction {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if self.relayer_private_key_stdin {
+            args.push_front("--relayer-private-key-stdin".to_string());
+        }
+        if let Some(var) = &self.relayer_private_key_env {
+            args.push_front(var.clone());
+            args.push_front("--relayer-private-key-env".to_string());
+        }
+        if let Some(path) = &self.relayer_private_key_file {
+            args.push_front(path.display().to_string());
+            args.push_front("--relayer-private-key-file".to_string());
+        }
+        if self.relayer_private_key.is_some() {
+            args.push_front(crate::consts::REDACTED_SECRET.to_string());
+            args.push_front("--relayer-private-key".to_string());
+        }
+        if let Some(relayer_account_id) = &self.relayer_account_id {
+            args.push_front(relayer_account_id.to_string());
+            args.push_front("--relayer-account-id".to_string());
+        }
+        if let Some(relayer_url) = &self.relayer_url {
+            args.push_front(relayer_url.to_string());
+            args.push_front("--relayer-url".to_string());
+        }
+        if let Some(signed_delegate_action) = &self.signed_delegate_action {
+            args.push_front(signed_delegate_action.to_string());
+        }
+        args
+    }
+}
+
+impl From<RelayDelegateAction> for CliRelayDelegateAction {
+    fn from(relay_delegate_action: RelayDelegateAction) -> Self {
+        let mut cli_relay_delegate_action = Self {
+            signed_delegate_action: Some(relay_delegate_action.signed_delegate_action.to_base64()),
+            ..Default::default()
+        };
+        match relay_delegate_action.relayer {
+            Relayer::Url(relayer_url) => cli_relay_delegate_action.relayer_url = Some(relayer_url),
+            Relayer::Account {
+                account_id,
+                private_key,
+            } => {
+                cli_relay_delegate_action.relayer_account_id = Some(account_id);
+                match private_key {
+                    Some((private_key, crate::common::SecretKeySource::Inline)) => {
+                        cli_relay_delegate_action.relayer_private_key = Some(private_key)
+                    }
+                    Some((_, crate::common::SecretKeySource::File(path))) => {
+                        cli_relay_delegate_action.relayer_private_key_file = Some(path)
+                    }
+                    Some((_, crate::common::SecretKeySource::Env(var))) => {
+                        cli_relay_delegate_action.relayer_private_key_env = Some(var)
+                    }
+                    Some((_, crate::common::SecretKeySource::Stdin)) => {
+                        cli_relay_delegate_action.relayer_private_key_stdin = true
+                    }
+                    None => {}
+                }
+            }
+        }
+        cli_relay_delegate_action
+    }
+}
+
+impl RelayDelegateAction {
+    pub fn from(
+        item: CliRelayDelegateAction,
+        connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        let signed_delegate_action = match item.signed_delegate_action {
+            Some(signed_delegate_action) => signed_delegate_action,
+            None => RelayDelegateAction::input_signed_delegate_action()?,
+        };
+        let signed_delegate_action =
+            crate::delegate_action::SignedDelegateAction::from_base64(&signed_delegate_action)?;
+        if !signed_delegate_action.verify() {
+            return Err(color_eyre::Report::msg(
+                "The signature of the delegate action doesn't match its access key",
+            ));
+        }
+        let private_key = crate::common::read_secret_key(
+            item.relayer_private_key,
+            item.relayer_private_key_file,
+            item.relayer_private_key_env,
+            item.relayer_private_key_stdin,
+        )?;
+        let relayer =
+            match (item.relayer_url, item.relayer_account_id) {
+                (Some(_), Some(_)) => {
+                    return Err(color_eyre::Report::msg(
+                        "The options --relayer-url and --relayer-account-id can't be used together",
+                    ))
+                }
+                (Some(_), None) if private_key.is_some() => return Err(color_eyre::Report::msg(
+                    "The private key of the relayer account is only used with --relayer-account-id",
+                )),
+                (Some(relayer_url), None) => Relayer::Url(relayer_url),
+                (None, Some(account_id)) => Relayer::Account {
+                    account_id,
+                    private_key,
+                },
+                (None, None) => match crate::config::get_relayer_url(connection_config) {
+                    Some(relayer_url) if private_key.is_none() => Relayer::Url(relayer_url),
+                    _ => Relayer::Account {
+                        account_id: RelayDelegateAction::input_relayer_account_id()?,
+                        private_key,
+                    },
+                },
+            };
+        Ok(Self {
+            signed_delegate_action,
+            relayer,
+        })
+    }
+}
+
+impl RelayDelegateAction {
+    fn input_signed_delegate_action() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Enter the signed delegate action (base64)")
+            .require_interactive("<signed-delegate-action>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    fn input_relayer_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the relayer?")
+            .require_interactive("--relayer-account-id")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let delegate_action = &self.signed_delegate_action.delegate_action;
+        println!(
+            "Relaying the delegate action of <{}> (nonce {}, valid until block {}):",
+            delegate_action.sender_id, delegate_action.nonce, delegate_action.max_block_height
+        );
+        crate::common::print_actions(&delegate_action.receiver_id, &delegate_action.actions);
+        match self.relayer {
+            Relayer::Url(relayer_url) => {
+                let response = crate::delegate_action::post_to_relayer(
+                    &relayer_url,
+                    &self.signed_delegate_action,
+                )
+                .await?;
+                println!("The relayer {} accepted the delegate action.", relayer_url);
+                if !response.trim().is_empty() {
+                    println!("{}", response.trim());
+                }
+            }
+            Relayer::Account {
+                account_id,
+                private_key,
+            } => {
+                let relayer_private_key = match private_key {
+                    Some((private_key, _)) => private_key,
+                    None => crate::common::get_secret_key_from_keychain(&account_id)?,
+                };
+                let relayer_public_key = relayer_private_key.public_key();
+                let access_key_response = crate::rpc::RpcClient::new(&network_connection_config)
+                    .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                        block_reference: near_primitives::types::Finality::Final.into(),
+                        request: near_primitives::views::QueryRequest::ViewAccessKey {
+                            account_id: account_id.clone(),
+                            public_key: relayer_public_key.clone(),
+                        },
+                    })
+                    .await
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to fetch public key information for nonce: {:?}",
+                            err
+                        ))
+                    })?;
+                let current_nonce =
+                    if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                        access_key,
+                    ) = access_key_response.kind
+                    {
+                        access_key.nonce
+                    } else {
+                        return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+                    };
+                if access_key_response.block_height > delegate_action.max_block_height {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The delegate action expired at block {} (the current block is {})",
+                        delegate_action.max_block_height, access_key_response.block_height
+                    )));
+                }
+                let nonce =
+                    crate::common::next_nonce(&account_id, &relayer_public_key, current_nonce);
+                let (relayer_transaction, transaction_hash) =
+                    crate::delegate_action::sign_relayer_transaction(
+                        self.signed_delegate_action,
+                        account_id.clone(),
+                        &relayer_private_key,
+                        nonce,
+                        access_key_response.block_hash,
+                    );
+                crate::rpc::RpcClient::new(&network_connection_config)
+                    .broadcast_tx_async(relayer_transaction)
+                    .await
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!(
+                            "Failed to send the transaction of the relayer: {:?}",
+                            err
+                        ))
+                    })?;
+                crate::common::record_command_output(
+                    "transaction_hash",
+                    transaction_hash.to_string(),
+                );
+                println!(
+                    "The delegate action was sent by <{}>.\nTransaction ID: {id}\nTo see the transaction in the transaction explorer, please open this url in your browser:\n{path}{id}\n",
+                    account_id,
+                    id = transaction_hash,
+                    path = network_connection_config.transaction_explorer()
+                );
+            }
+        }
+        Ok(())
+    }
+}
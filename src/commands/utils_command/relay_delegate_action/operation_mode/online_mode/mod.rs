@@ -0,0 +1,129 @@
+/// аргументы, необходимые для передачи delegate action в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    #[clap(subcommand)]
+    send: Option<CliSend>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    send: Send,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let args = self
+            .send
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+            send: Some(network_args.send.into()),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let connection_config = selected_server.connection_config.clone();
+        let send = match item.send {
+            Some(cli_send) => Send::from(cli_send, &connection_config)?,
+            None => Send::send(&connection_config)?,
+        };
+        Ok(Self {
+            selected_server,
+            send,
+        })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(self) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        self.send
+            .process(self.selected_server.connection_config)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSend {
+    /// Specify the signed delegate action
+    DelegateAction(super::super::CliRelayDelegateAction),
+}
+
+#[derive(Debug, Clone)]
+pub enum Send {
+    DelegateAction(super::super::RelayDelegateAction),
+}
+
+impl CliSend {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::DelegateAction(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("delegate-action".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Send> for CliSend {
+    fn from(send: Send) -> Self {
+        match send {
+            Send::DelegateAction(relay_delegate_action) => {
+                Self::DelegateAction(relay_delegate_action.into())
+            }
+        }
+    }
+}
+
+impl Send {
+    fn from(
+        item: CliSend,
+        connection_config: &crate::common::ConnectionConfig,
+    ) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliSend::DelegateAction(cli_relay_delegate_action) => Ok(Self::DelegateAction(
+                super::super::RelayDelegateAction::from(
+                    cli_relay_delegate_action,
+                    connection_config,
+                )?,
+            )),
+        }
+    }
+}
+
+impl Send {
+    fn send(connection_config: &crate::common::ConnectionConfig) -> color_eyre::eyre::Result<Self> {
+        Self::from(
+            CliSend::DelegateAction(Default::default()),
+            connection_config,
+        )
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Send::DelegateAction(relay_delegate_action) => {
+                relay_delegate_action
+                    .process(network_connection_config)
+                    .await
+            }
+        }
+    }
+}
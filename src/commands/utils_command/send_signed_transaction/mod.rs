@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 pub mod operation_mode;
@@ -30,22 +33,25 @@ impl From<Transaction> for CliTransaction {
     }
 }
 
-impl From<CliTransaction> for Transaction {
-    fn from(item: CliTransaction) -> Self {
+impl TryFrom<CliTransaction> for Transaction {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliTransaction) -> Result<Self, Self::Error> {
         let transaction = match item.transaction {
             Some(transaction) => transaction,
-            None => Transaction::input_transaction(),
+            None => Transaction::input_transaction()?,
         };
-        Self { transaction }
+        Ok(Self { transaction })
     }
 }
 
 impl Transaction {
-    fn input_transaction() -> String {
-        Input::new()
+    fn input_transaction() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
             .with_prompt("Enter the signed transaction hash you want to send")
+            .require_interactive("<transaction>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -53,8 +59,7 @@ impl Transaction {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         println!("Transaction sent ...");
-        let json_rcp_client =
-            near_jsonrpc_client::new_client(network_connection_config.rpc_url().as_str());
+        let json_rcp_client = crate::rpc::RpcClient::new(&network_connection_config);
         let transaction_info = loop {
             let transaction_info_result = json_rcp_client
                 .broadcast_tx_commit(self.transaction.clone())
@@ -87,10 +92,29 @@ impl Transaction {
                         }
                         _ => println!("Unexpected response: {:#?}", err),
                     }
+                    crate::journal::record_serialized_transaction(
+                        crate::journal::JournalStatus::Failed,
+                        Some(&network_connection_config),
+                        &self.transaction,
+                        Some(format!("{:?}", err)),
+                    );
                     return Ok(());
                 }
             };
         };
+        let (status, error) = match &transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => (
+                crate::journal::JournalStatus::Failed,
+                Some(format!("{:?}", tx_execution_error)),
+            ),
+            _ => (crate::journal::JournalStatus::Succeeded, None),
+        };
+        crate::journal::record_serialized_transaction(
+            status,
+            Some(&network_connection_config),
+            &self.transaction,
+            error,
+        );
         crate::common::print_transaction_status(transaction_info, Some(network_connection_config));
         Ok(())
     }
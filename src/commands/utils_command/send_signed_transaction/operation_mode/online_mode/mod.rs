@@ -1,53 +1,118 @@
-pub mod select_server;
+use std::convert::TryFrom;
 
 /// аргументы, необходимые для создания транзакции в online mode
 #[derive(Debug, Default, Clone, clap::Clap)]
-#[clap(
-    setting(clap::AppSettings::ColoredHelp),
-    setting(clap::AppSettings::DisableHelpSubcommand),
-    setting(clap::AppSettings::VersionlessSubcommands)
-)]
 pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
     #[clap(subcommand)]
-    selected_server: Option<self::select_server::CliSelectServer>,
+    send: Option<CliSend>,
 }
 
 #[derive(Debug, Clone)]
 pub struct NetworkArgs {
-    selected_server: self::select_server::SelectServer,
+    selected_server: crate::network::SelectServer,
+    send: Send,
 }
 
 impl CliNetworkArgs {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.selected_server
+        let args = self
+            .send
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
     }
 }
 
 impl From<NetworkArgs> for CliNetworkArgs {
     fn from(network_args: NetworkArgs) -> Self {
         Self {
-            selected_server: Some(network_args.selected_server.into()),
+            selected_server: network_args.selected_server.into(),
+            send: Some(network_args.send.into()),
         }
     }
 }
 
-impl From<CliNetworkArgs> for NetworkArgs {
-    fn from(item: CliNetworkArgs) -> Self {
-        let selected_server = match item.selected_server {
-            Some(cli_selected_server) => {
-                self::select_server::SelectServer::from(cli_selected_server)
-            }
-            None => self::select_server::SelectServer::choose_server(),
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let send = match item.send {
+            Some(cli_send) => Send::try_from(cli_send)?,
+            None => Send::send()?,
         };
-        Self { selected_server }
+        Ok(Self {
+            selected_server,
+            send,
+        })
     }
 }
 
 impl NetworkArgs {
     pub async fn process(self) -> crate::CliResult {
-        self.selected_server.process().await
+        self.selected_server.verify().await?;
+        self.send
+            .process(self.selected_server.connection_config)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSend {
+    /// Specify a transaction
+    Transaction(super::super::CliTransaction),
+}
+
+#[derive(Debug, Clone)]
+pub enum Send {
+    Transaction(super::super::Transaction),
+}
+
+impl CliSend {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Transaction(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("transaction".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Send> for CliSend {
+    fn from(send: Send) -> Self {
+        match send {
+            Send::Transaction(transaction) => Self::Transaction(transaction.into()),
+        }
+    }
+}
+
+impl TryFrom<CliSend> for Send {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSend) -> Result<Self, Self::Error> {
+        Ok(match item {
+            CliSend::Transaction(cli_transaction) => {
+                let transaction = super::super::Transaction::try_from(cli_transaction)?;
+                Self::Transaction(transaction)
+            }
+        })
+    }
+}
+
+impl Send {
+    fn send() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSend::Transaction(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Send::Transaction(transaction) => transaction.process(network_connection_config).await,
+        }
     }
 }
@@ -0,0 +1,452 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
+
+/// NEP-413 tag (2^31 + 413) prepended to the payload, so it can never be a valid transaction
+const NEP413_PAYLOAD_TAG: u32 = 2_147_484_061;
+
+/// 32-byte nonce of the NEP-413 message encoded in base64
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageNonce(pub [u8; 32]);
+
+impl std::str::FromStr for MessageNonce {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = base64::decode(s.trim())
+            .map_err(|err| format!("The nonce is not valid base64: {}", err))?;
+        let mut nonce = [0u8; 32];
+        if bytes.len() != nonce.len() {
+            return Err(format!(
+                "The nonce must be 32 bytes long, got {} bytes",
+                bytes.len()
+            ));
+        }
+        nonce.copy_from_slice(&bytes);
+        Ok(Self(nonce))
+    }
+}
+
+impl std::fmt::Display for MessageNonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", base64::encode(&self.0))
+    }
+}
+
+/// NEP-413 payload of the message signed off chain
+#[derive(Debug, Clone)]
+pub struct MessagePayload {
+    pub message: String,
+    pub nonce: MessageNonce,
+    pub recipient: String,
+    pub callback_url: Option<String>,
+}
+
+impl MessagePayload {
+    /// Borsh serialization of the tag and the payload
+    fn to_bytes(&self) -> Vec<u8> {
+        fn push_string(bytes: &mut Vec<u8>, value: &str) {
+            bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(value.as_bytes());
+        }
+        let mut bytes = NEP413_PAYLOAD_TAG.to_le_bytes().to_vec();
+        push_string(&mut bytes, &self.message);
+        bytes.extend_from_slice(&self.nonce.0);
+        push_string(&mut bytes, &self.recipient);
+        match &self.callback_url {
+            Some(callback_url) => {
+                bytes.push(1);
+                push_string(&mut bytes, callback_url);
+            }
+            None => bytes.push(0),
+        }
+        bytes
+    }
+
+    /// The hash which is signed by the key
+    pub fn hash(&self) -> near_primitives::hash::CryptoHash {
+        near_primitives::hash::hash(&self.to_bytes())
+    }
+
+    pub fn sign(
+        &self,
+        signer_private_key: &near_crypto::SecretKey,
+    ) -> color_eyre::eyre::Result<String> {
+        match signer_private_key.sign(self.hash().as_ref()) {
+            near_crypto::Signature::ED25519(signature) => {
+                Ok(base64::encode(&signature.to_bytes()[..]))
+            }
+            _ => Err(color_eyre::Report::msg(
+                "Only ED25519 keys can sign messages".to_string(),
+            )),
+        }
+    }
+
+    pub fn verify(&self, signature: &str, public_key: &near_crypto::PublicKey) -> bool {
+        let signature = match base64::decode(signature.trim()) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        match near_crypto::Signature::from_parts(near_crypto::KeyType::ED25519, &signature) {
+            Ok(signature) => signature.verify(self.hash().as_ref(), public_key),
+            Err(_) => false,
+        }
+    }
+
+    pub fn input_message() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Enter the message to sign")
+            .require_interactive("--message")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub fn input_recipient() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
+            .with_prompt("Who is the recipient of the message (e.g. the app domain or account ID)?")
+            .require_interactive("--recipient")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub fn input_callback_url() -> color_eyre::eyre::Result<Option<String>> {
+        let callback_url: String = Input::new()
+            .with_prompt("Enter the callback URL (leave blank for none)")
+            .allow_empty(true)
+            .require_interactive("--callback-url")?
+            .interact_text()
+            .unwrap();
+        Ok(if callback_url.is_empty() {
+            None
+        } else {
+            Some(callback_url)
+        })
+    }
+}
+
+/// утилита, позволяющая подписать сообщение (NEP-413)
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignMessage {
+    #[clap(long)]
+    message: Option<String>,
+    #[clap(long)]
+    recipient: Option<String>,
+    /// 32 bytes in base64 (random if omitted)
+    #[clap(long)]
+    nonce: Option<MessageNonce>,
+    #[clap(long)]
+    callback_url: Option<String>,
+    #[clap(long)]
+    signer_account_id: Option<near_primitives::types::AccountId>,
+    #[clap(subcommand)]
+    sign_option: Option<CliMessageSigner>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignMessage {
+    payload: MessagePayload,
+    signer_account_id: near_primitives::types::AccountId,
+    sign_option: MessageSigner,
+}
+
+impl CliSignMessage {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = self
+            .sign_option
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        if let Some(signer_account_id) = &self.signer_account_id {
+            args.push_front(signer_account_id.to_string());
+            args.push_front("--signer-account-id".to_string());
+        }
+        if let Some(callback_url) = &self.callback_url {
+            args.push_front(callback_url.to_string());
+            args.push_front("--callback-url".to_string());
+        }
+        if let Some(nonce) = &self.nonce {
+            args.push_front(nonce.to_string());
+            args.push_front("--nonce".to_string());
+        }
+        if let Some(recipient) = &self.recipient {
+            args.push_front(recipient.to_string());
+            args.push_front("--recipient".to_string());
+        }
+        if let Some(message) = &self.message {
+            args.push_front(message.to_string());
+            args.push_front("--message".to_string());
+        }
+        args
+    }
+}
+
+impl From<SignMessage> for CliSignMessage {
+    fn from(sign_message: SignMessage) -> Self {
+        Self {
+            message: Some(sign_message.payload.message),
+            recipient: Some(sign_message.payload.recipient),
+            nonce: Some(sign_message.payload.nonce),
+            callback_url: sign_message.payload.callback_url,
+            signer_account_id: Some(sign_message.signer_account_id),
+            sign_option: Some(sign_message.sign_option.into()),
+        }
+    }
+}
+
+impl SignMessage {
+    pub fn from(item: CliSignMessage) -> color_eyre::eyre::Result<Self> {
+        let message = match item.message {
+            Some(message) => message,
+            None => MessagePayload::input_message()?,
+        };
+        let recipient = match item.recipient {
+            Some(recipient) => recipient,
+            None => MessagePayload::input_recipient()?,
+        };
+        let nonce = match item.nonce {
+            Some(nonce) => nonce,
+            None => MessageNonce(rand::random()),
+        };
+        let callback_url = match item.callback_url {
+            Some(callback_url) => Some(callback_url),
+            None => MessagePayload::input_callback_url()?,
+        };
+        let signer_account_id = match item.signer_account_id {
+            Some(signer_account_id) => signer_account_id,
+            None => SignMessage::input_signer_account_id()?,
+        };
+        let sign_option = match item.sign_option {
+            Some(cli_sign_option) => MessageSigner::from(cli_sign_option)?,
+            None => MessageSigner::choose_sign_option()?,
+        };
+        Ok(Self {
+            payload: MessagePayload {
+                message,
+                nonce,
+                recipient,
+                callback_url,
+            },
+            signer_account_id,
+            sign_option,
+        })
+    }
+}
+
+impl SignMessage {
+    fn input_signer_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        Ok(Input::new()
+            .with_prompt("What is the account ID of the signer?")
+            .require_interactive("--signer-account-id")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(self) -> crate::CliResult {
+        let signer_private_key = match self.sign_option {
+            MessageSigner::SignWithPrivateKey(signer_private_key, _) => signer_private_key,
+            MessageSigner::SignWithKeychain => {
+                crate::common::get_secret_key_from_keychain(&self.signer_account_id)?
+            }
+        };
+        let signature = self.payload.sign(&signer_private_key)?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "accountId": self.signer_account_id,
+                "publicKey": signer_private_key.public_key(),
+                "signature": signature,
+                "message": self.payload.message,
+                "nonce": self.payload.nonce.to_string(),
+                "recipient": self.payload.recipient,
+                "callbackUrl": self.payload.callback_url,
+            }))?
+        );
+        Ok(())
+    }
+}
+
+/// данные для подписания сообщения личным ключом
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignWithPrivateKey {
+    /// The private key (it stays in the shell history, prefer the options below)
+    #[clap(long)]
+    signer_private_key: Option<near_crypto::SecretKey>,
+    /// Read the private key from the file (the key itself or a keychain access key file)
+    #[clap(long)]
+    signer_private_key_file: Option<std::path::PathBuf>,
+    /// Read the private key from the environment variable
+    #[clap(long)]
+    signer_private_key_env: Option<String>,
+    /// Read the private key from the first line of stdin
+    #[clap(long)]
+    signer_private_key_stdin: bool,
+}
+
+/// подписание сообщения ключом из keychain
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSignWithKeychain {}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliMessageSigner {
+    /// Provide the private key to sign the message
+    SignWithPrivateKey(CliSignWithPrivateKey),
+    /// Sign the message with the key of the signer account from the keychain
+    SignWithKeychain(CliSignWithKeychain),
+}
+
+#[derive(Debug, Clone, EnumDiscriminants)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+pub enum MessageSigner {
+    #[strum_discriminants(strum(message = "Yes, I want to sign the message with a private key"))]
+    SignWithPrivateKey(near_crypto::SecretKey, crate::common::SecretKeySource),
+    #[strum_discriminants(strum(message = "Yes, I want to sign the message with keychain"))]
+    SignWithKeychain,
+}
+
+impl CliMessageSigner {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SignWithPrivateKey(subcommand) => {
+                let mut args = std::collections::VecDeque::new();
+                if subcommand.signer_private_key_stdin {
+                    args.push_front("--signer-private-key-stdin".to_string());
+                }
+                if let Some(var) = &subcommand.signer_private_key_env {
+                    args.push_front(var.clone());
+                    args.push_front("--signer-private-key-env".to_string());
+                }
+                if let Some(path) = &subcommand.signer_private_key_file {
+                    args.push_front(path.display().to_string());
+                    args.push_front("--signer-private-key-file".to_string());
+                }
+                if subcommand.signer_private_key.is_some() {
+                    args.push_front(crate::consts::REDACTED_SECRET.to_string());
+                    args.push_front("--signer-private-key".to_string());
+                }
+                args.push_front("sign-with-private-key".to_owned());
+                args
+            }
+            Self::SignWithKeychain(_) => {
+                let mut args = std::collections::VecDeque::new();
+                args.push_front("sign-with-keychain".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<MessageSigner> for CliMessageSigner {
+    fn from(message_signer: MessageSigner) -> Self {
+        match message_signer {
+            MessageSigner::SignWithPrivateKey(signer_private_key, signer_private_key_source) => {
+                let mut cli_sign_with_private_key = CliSignWithPrivateKey::default();
+                match signer_private_key_source {
+                    crate::common::SecretKeySource::Inline => {
+                        cli_sign_with_private_key.signer_private_key = Some(signer_private_key)
+                    }
+                    crate::common::SecretKeySource::File(path) => {
+                        cli_sign_with_private_key.signer_private_key_file = Some(path)
+                    }
+                    crate::common::SecretKeySource::Env(var) => {
+                        cli_sign_with_private_key.signer_private_key_env = Some(var)
+                    }
+                    crate::common::SecretKeySource::Stdin => {
+                        cli_sign_with_private_key.signer_private_key_stdin = true
+                    }
+                }
+                Self::SignWithPrivateKey(cli_sign_with_private_key)
+            }
+            MessageSigner::SignWithKeychain => Self::SignWithKeychain(CliSignWithKeychain {}),
+        }
+    }
+}
+
+impl MessageSigner {
+    fn from(item: CliMessageSigner) -> color_eyre::eyre::Result<Self> {
+        match item {
+            CliMessageSigner::SignWithPrivateKey(cli_sign_with_private_key) => {
+                let (signer_private_key, signer_private_key_source) =
+                    match crate::common::read_secret_key(
+                        cli_sign_with_private_key.signer_private_key,
+                        cli_sign_with_private_key.signer_private_key_file,
+                        cli_sign_with_private_key.signer_private_key_env,
+                        cli_sign_with_private_key.signer_private_key_stdin,
+                    )? {
+                        Some(signer_private_key) => signer_private_key,
+                        None => (
+                            Input::new()
+                                .with_prompt("Enter the private key to sign the message")
+                                .require_interactive("--signer-private-key")?
+                                .interact_text()
+                                .unwrap(),
+                            crate::common::SecretKeySource::Inline,
+                        ),
+                    };
+                Ok(Self::SignWithPrivateKey(
+                    signer_private_key,
+                    signer_private_key_source,
+                ))
+            }
+            CliMessageSigner::SignWithKeychain(_) => Ok(Self::SignWithKeychain),
+        }
+    }
+}
+
+impl MessageSigner {
+    fn choose_sign_option() -> color_eyre::eyre::Result<Self> {
+        println!();
+        let variants = MessageSignerDiscriminants::iter().collect::<Vec<_>>();
+        let sign_options = variants
+            .iter()
+            .map(|p| p.get_message().unwrap().to_owned())
+            .collect::<Vec<_>>();
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("How do you want to sign the message?")
+            .items(&sign_options)
+            .default(0)
+            .require_interactive_choice()?
+            .interact()
+            .unwrap();
+        let cli_sign_option = match variants[selection] {
+            MessageSignerDiscriminants::SignWithPrivateKey => {
+                CliMessageSigner::SignWithPrivateKey(Default::default())
+            }
+            MessageSignerDiscriminants::SignWithKeychain => {
+                CliMessageSigner::SignWithKeychain(Default::default())
+            }
+        };
+        Self::from(cli_sign_option)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> MessagePayload {
+        MessagePayload {
+            message: "hi".to_string(),
+            nonce: MessageNonce([7; 32]),
+            recipient: "app".to_string(),
+            callback_url: None,
+        }
+    }
+
+    #[test]
+    fn message_payload_layout() {
+        let mut expected = vec![0x9d, 0x01, 0x00, 0x80, 2, 0, 0, 0, b'h', b'i'];
+        expected.extend_from_slice(&[7; 32]);
+        expected.extend_from_slice(&[3, 0, 0, 0, b'a', b'p', b'p', 0]);
+        assert_eq!(payload().to_bytes(), expected);
+    }
+
+    #[test]
+    fn sign_and_verify_message() {
+        let secret_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice.testnet");
+        let signature = payload().sign(&secret_key).unwrap();
+        assert!(payload().verify(&signature, &secret_key.public_key()));
+        let mut other_payload = payload();
+        other_payload.recipient = "other-app".to_string();
+        assert!(!other_payload.verify(&signature, &secret_key.public_key()));
+    }
+}
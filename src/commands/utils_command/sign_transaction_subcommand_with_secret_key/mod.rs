@@ -1,11 +1,22 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use near_primitives::borsh::BorshSerialize;
 
 /// утилита, позволяющая подписать транзакцию личным ключом
 #[derive(Debug, Default, Clone, clap::Clap)]
 pub struct CliSignTransactionPrivateKey {
+    /// The private key (it stays in the shell history, prefer the options below)
     #[clap(long)]
     signer_private_key: Option<near_crypto::SecretKey>,
+    /// Read the private key from the file (the key itself or a keychain access key file)
+    #[clap(long)]
+    signer_private_key_file: Option<std::path::PathBuf>,
+    /// Read the private key from the environment variable
+    #[clap(long)]
+    signer_private_key_env: Option<String>,
+    /// Read the private key from the first line of stdin
+    #[clap(long)]
+    signer_private_key_stdin: bool,
     #[clap(long)]
     unsigned_transaction: Option<crate::common::TransactionAsBase64>,
 }
@@ -13,6 +24,7 @@ pub struct CliSignTransactionPrivateKey {
 #[derive(Debug, Clone)]
 pub struct SignTransactionPrivateKey {
     pub signer_private_key: near_crypto::SecretKey,
+    pub signer_private_key_source: crate::common::SecretKeySource,
     pub unsigned_transaction: near_primitives::transaction::Transaction,
 }
 
@@ -29,8 +41,19 @@ impl CliSignTransactionPrivateKey {
             args.push_front(unsigned_transaction_serialized_to_base64);
             args.push_front("--unsigned-transaction".to_string());
         }
-        if let Some(signer_private_key) = &self.signer_private_key {
-            args.push_front(signer_private_key.to_string());
+        if self.signer_private_key_stdin {
+            args.push_front("--signer-private-key-stdin".to_string());
+        }
+        if let Some(var) = &self.signer_private_key_env {
+            args.push_front(var.clone());
+            args.push_front("--signer-private-key-env".to_string());
+        }
+        if let Some(path) = &self.signer_private_key_file {
+            args.push_front(path.display().to_string());
+            args.push_front("--signer-private-key-file".to_string());
+        }
+        if self.signer_private_key.is_some() {
+            args.push_front(crate::consts::REDACTED_SECRET.to_string());
             args.push_front("--signer-private-key".to_string());
         }
         args
@@ -39,47 +62,78 @@ impl CliSignTransactionPrivateKey {
 
 impl From<SignTransactionPrivateKey> for CliSignTransactionPrivateKey {
     fn from(sign_transacrion_private_key: SignTransactionPrivateKey) -> Self {
-        Self {
-            signer_private_key: Some(sign_transacrion_private_key.signer_private_key),
+        let mut cli_sign_transaction_private_key = Self {
+            signer_private_key: None,
+            signer_private_key_file: None,
+            signer_private_key_env: None,
+            signer_private_key_stdin: false,
             unsigned_transaction: Some(crate::common::TransactionAsBase64 {
                 inner: sign_transacrion_private_key.unsigned_transaction,
             }),
+        };
+        match sign_transacrion_private_key.signer_private_key_source {
+            crate::common::SecretKeySource::Inline => {
+                cli_sign_transaction_private_key.signer_private_key =
+                    Some(sign_transacrion_private_key.signer_private_key)
+            }
+            crate::common::SecretKeySource::File(path) => {
+                cli_sign_transaction_private_key.signer_private_key_file = Some(path)
+            }
+            crate::common::SecretKeySource::Env(var) => {
+                cli_sign_transaction_private_key.signer_private_key_env = Some(var)
+            }
+            crate::common::SecretKeySource::Stdin => {
+                cli_sign_transaction_private_key.signer_private_key_stdin = true
+            }
         }
+        cli_sign_transaction_private_key
     }
 }
 
-impl From<CliSignTransactionPrivateKey> for SignTransactionPrivateKey {
-    fn from(item: CliSignTransactionPrivateKey) -> Self {
-        let signer_private_key: near_crypto::SecretKey = match item.signer_private_key {
-            Some(cli_signer_private_key) => cli_signer_private_key,
-            None => SignTransactionPrivateKey::input_signer_private_key(),
+impl SignTransactionPrivateKey {
+    pub fn from(item: CliSignTransactionPrivateKey) -> color_eyre::eyre::Result<Self> {
+        let (signer_private_key, signer_private_key_source) = match crate::common::read_secret_key(
+            item.signer_private_key,
+            item.signer_private_key_file,
+            item.signer_private_key_env,
+            item.signer_private_key_stdin,
+        )? {
+            Some(signer_private_key) => signer_private_key,
+            None => (
+                SignTransactionPrivateKey::input_signer_private_key()?,
+                crate::common::SecretKeySource::Inline,
+            ),
         };
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => SignTransactionPrivateKey::input_unsigned_transaction(),
+                None => SignTransactionPrivateKey::input_unsigned_transaction()?,
             };
-        SignTransactionPrivateKey {
+        Ok(SignTransactionPrivateKey {
             signer_private_key,
+            signer_private_key_source,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl SignTransactionPrivateKey {
-    pub fn input_signer_private_key() -> near_crypto::SecretKey {
-        Input::new()
+    pub fn input_signer_private_key() -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+        Ok(Input::new()
             .with_prompt("Enter the private key")
+            .require_interactive("--signer-private-key")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
+            .require_interactive("--unsigned-transaction")?
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
     pub async fn process(self) -> crate::CliResult {
@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use near_primitives::borsh::BorshSerialize;
 
@@ -48,39 +51,55 @@ impl From<SignTransactionWithLedger> for CliSignTransactionWithLedger {
     }
 }
 
-impl From<CliSignTransactionWithLedger> for SignTransactionWithLedger {
-    fn from(item: CliSignTransactionWithLedger) -> Self {
-        let seed_phrase_hd_path = match item.seed_phrase_hd_path {
-            Some(hd_path) => hd_path,
-            None => SignTransactionWithLedger::input_seed_phrase_hd_path(),
-        };
+impl TryFrom<CliSignTransactionWithLedger> for SignTransactionWithLedger {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSignTransactionWithLedger) -> Result<Self, Self::Error> {
         let unsigned_transaction: near_primitives::transaction::Transaction =
             match item.unsigned_transaction {
                 Some(cli_unsigned_transaction) => cli_unsigned_transaction.inner,
-                None => SignTransactionWithLedger::input_unsigned_transaction(),
+                None => SignTransactionWithLedger::input_unsigned_transaction()?,
             };
-        SignTransactionWithLedger {
+        let seed_phrase_hd_path = match item.seed_phrase_hd_path {
+            Some(hd_path) => hd_path,
+            None => {
+                match crate::config::get_saved_ledger_hd_path(&unsigned_transaction.signer_id) {
+                    Some(hd_path) => {
+                        println!(
+                            "Using the HD Path {} saved for <{}>",
+                            hd_path, unsigned_transaction.signer_id
+                        );
+                        hd_path
+                    }
+                    None => SignTransactionWithLedger::input_seed_phrase_hd_path()?,
+                }
+            }
+        };
+        Ok(SignTransactionWithLedger {
             seed_phrase_hd_path,
             unsigned_transaction,
-        }
+        })
     }
 }
 
 impl SignTransactionWithLedger {
-    pub fn input_unsigned_transaction() -> near_primitives::transaction::Transaction {
+    pub fn input_unsigned_transaction(
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::Transaction> {
         let input: crate::common::TransactionAsBase64 = Input::new()
             .with_prompt("Enter an unsigned transaction")
+            .require_interactive("--unsigned-transaction")?
             .interact_text()
             .unwrap();
-        input.inner
+        Ok(input.inner)
     }
 
-    pub fn input_seed_phrase_hd_path() -> slip10::BIP32Path {
-        Input::new()
+    pub fn input_seed_phrase_hd_path() -> color_eyre::eyre::Result<slip10::BIP32Path> {
+        Ok(Input::new()
             .with_prompt("Enter seed phrase HD Path (if you not sure leave blank for default)")
-            .with_initial_text("44'/397'/0'/0'/1'")
+            .with_initial_text(crate::consts::DEFAULT_LEDGER_HD_PATH)
+            .require_interactive("--seed-phrase-hd-path")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self) -> crate::CliResult {
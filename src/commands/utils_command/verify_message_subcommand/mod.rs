@@ -0,0 +1,200 @@
+use std::convert::TryFrom;
+
+use dialoguer::Input;
+
+use super::sign_message_subcommand::{MessageNonce, MessagePayload};
+use crate::interactive::RequireInteractive;
+
+pub mod operation_mode;
+
+/// подписанное сообщение (NEP-413) для проверки
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliVerifyMessage {
+    #[clap(long)]
+    message: Option<String>,
+    #[clap(long)]
+    recipient: Option<String>,
+    /// 32 bytes in base64
+    #[clap(long)]
+    nonce: Option<MessageNonce>,
+    #[clap(long)]
+    callback_url: Option<String>,
+    #[clap(long)]
+    account_id: Option<near_primitives::types::AccountId>,
+    #[clap(long)]
+    public_key: Option<near_crypto::PublicKey>,
+    /// The signature in base64
+    #[clap(long)]
+    signature: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyMessage {
+    payload: MessagePayload,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    signature: String,
+}
+
+impl CliVerifyMessage {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(signature) = &self.signature {
+            args.push_front(signature.to_string());
+            args.push_front("--signature".to_string());
+        }
+        if let Some(public_key) = &self.public_key {
+            args.push_front(public_key.to_string());
+            args.push_front("--public-key".to_string());
+        }
+        if let Some(account_id) = &self.account_id {
+            args.push_front(account_id.to_string());
+            args.push_front("--account-id".to_string());
+        }
+        if let Some(callback_url) = &self.callback_url {
+            args.push_front(callback_url.to_string());
+            args.push_front("--callback-url".to_string());
+        }
+        if let Some(nonce) = &self.nonce {
+            args.push_front(nonce.to_string());
+            args.push_front("--nonce".to_string());
+        }
+        if let Some(recipient) = &self.recipient {
+            args.push_front(recipient.to_string());
+            args.push_front("--recipient".to_string());
+        }
+        if let Some(message) = &self.message {
+            args.push_front(message.to_string());
+            args.push_front("--message".to_string());
+        }
+        args
+    }
+}
+
+impl From<VerifyMessage> for CliVerifyMessage {
+    fn from(verify_message: VerifyMessage) -> Self {
+        Self {
+            message: Some(verify_message.payload.message),
+            recipient: Some(verify_message.payload.recipient),
+            nonce: Some(verify_message.payload.nonce),
+            callback_url: verify_message.payload.callback_url,
+            account_id: Some(verify_message.account_id),
+            public_key: Some(verify_message.public_key),
+            signature: Some(verify_message.signature),
+        }
+    }
+}
+
+impl TryFrom<CliVerifyMessage> for VerifyMessage {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliVerifyMessage) -> Result<Self, Self::Error> {
+        let message = match item.message {
+            Some(message) => message,
+            None => MessagePayload::input_message()?,
+        };
+        let recipient = match item.recipient {
+            Some(recipient) => recipient,
+            None => MessagePayload::input_recipient()?,
+        };
+        let nonce = match item.nonce {
+            Some(nonce) => nonce,
+            None => Input::new()
+                .with_prompt("Enter the nonce of the message (base64)")
+                .require_interactive("--nonce")?
+                .interact_text()
+                .unwrap(),
+        };
+        let callback_url = match item.callback_url {
+            Some(callback_url) => Some(callback_url),
+            None => MessagePayload::input_callback_url()?,
+        };
+        let account_id = match item.account_id {
+            Some(account_id) => account_id,
+            None => Input::new()
+                .with_prompt("What is the account ID of the signer?")
+                .require_interactive("--account-id")?
+                .interact_text()
+                .unwrap(),
+        };
+        let public_key = match item.public_key {
+            Some(public_key) => public_key,
+            None => Input::new()
+                .with_prompt("Enter the public key of the signer")
+                .require_interactive("--public-key")?
+                .interact_text()
+                .unwrap(),
+        };
+        let signature = match item.signature {
+            Some(signature) => signature,
+            None => Input::new()
+                .with_prompt("Enter the signature (base64)")
+                .require_interactive("--signature")?
+                .interact_text()
+                .unwrap(),
+        };
+        Ok(Self {
+            payload: MessagePayload {
+                message,
+                nonce,
+                recipient,
+                callback_url,
+            },
+            account_id,
+            public_key,
+            signature,
+        })
+    }
+}
+
+impl VerifyMessage {
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        if !self.payload.verify(&self.signature, &self.public_key) {
+            return Err(color_eyre::Report::msg(format!(
+                "The signature is not valid for the public key {}",
+                self.public_key
+            )));
+        }
+        println!(
+            "The signature is valid for the public key {}",
+            self.public_key
+        );
+        let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
+            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                block_reference: near_primitives::types::Finality::Final.into(),
+                request: near_primitives::views::QueryRequest::ViewAccessKey {
+                    account_id: self.account_id.clone(),
+                    public_key: self.public_key.clone(),
+                },
+            })
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The public key {} is not an access key of the account <{}>: {:?}",
+                    self.public_key, self.account_id, err
+                ))
+            })?;
+        let access_key_view =
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(result) =
+                query_view_method_response.kind
+            {
+                result
+            } else {
+                return Err(color_eyre::Report::msg(format!("Error call result")));
+            };
+        match access_key_view.permission {
+            near_primitives::views::AccessKeyPermissionView::FullAccess => println!(
+                "The public key is a full access key of the account <{}>",
+                self.account_id
+            ),
+            near_primitives::views::AccessKeyPermissionView::FunctionCall { .. } => println!(
+                "WARNING! The public key is only a function-call access key of the account <{}>",
+                self.account_id
+            ),
+        }
+        Ok(())
+    }
+}
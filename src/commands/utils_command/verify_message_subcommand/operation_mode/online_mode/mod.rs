@@ -0,0 +1,120 @@
+use std::convert::TryFrom;
+
+/// аргументы, необходимые для создания транзакции в online mode
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
+    #[clap(subcommand)]
+    verify: Option<CliVerify>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkArgs {
+    selected_server: crate::network::SelectServer,
+    verify: Verify,
+}
+
+impl CliNetworkArgs {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let args = self
+            .verify
+            .as_ref()
+            .map(|subcommand| subcommand.to_cli_args())
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
+    }
+}
+
+impl From<NetworkArgs> for CliNetworkArgs {
+    fn from(network_args: NetworkArgs) -> Self {
+        Self {
+            selected_server: network_args.selected_server.into(),
+            verify: Some(network_args.verify.into()),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let verify = match item.verify {
+            Some(cli_verify) => Verify::try_from(cli_verify)?,
+            None => Verify::verify()?,
+        };
+        Ok(Self {
+            selected_server,
+            verify,
+        })
+    }
+}
+
+impl NetworkArgs {
+    pub async fn process(self) -> crate::CliResult {
+        self.selected_server.verify().await?;
+        self.verify
+            .process(self.selected_server.connection_config)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliVerify {
+    /// Specify the signed message
+    SignedMessage(super::super::CliVerifyMessage),
+}
+
+#[derive(Debug, Clone)]
+pub enum Verify {
+    SignedMessage(super::super::VerifyMessage),
+}
+
+impl CliVerify {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::SignedMessage(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("signed-message".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<Verify> for CliVerify {
+    fn from(verify: Verify) -> Self {
+        match verify {
+            Verify::SignedMessage(verify_message) => Self::SignedMessage(verify_message.into()),
+        }
+    }
+}
+
+impl TryFrom<CliVerify> for Verify {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliVerify) -> Result<Self, Self::Error> {
+        Ok(match item {
+            CliVerify::SignedMessage(cli_verify_message) => {
+                let verify_message = super::super::VerifyMessage::try_from(cli_verify_message)?;
+                Self::SignedMessage(verify_message)
+            }
+        })
+    }
+}
+
+impl Verify {
+    fn verify() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliVerify::SignedMessage(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            Verify::SignedMessage(verify_message) => {
+                verify_message.process(network_connection_config).await
+            }
+        }
+    }
+}
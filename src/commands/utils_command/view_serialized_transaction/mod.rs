@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use near_primitives::borsh::BorshDeserialize;
 
@@ -30,22 +33,25 @@ impl From<ViewSerializedTransaction> for CliViewSerializedTransaction {
     }
 }
 
-impl From<CliViewSerializedTransaction> for ViewSerializedTransaction {
-    fn from(item: CliViewSerializedTransaction) -> Self {
+impl TryFrom<CliViewSerializedTransaction> for ViewSerializedTransaction {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliViewSerializedTransaction) -> Result<Self, Self::Error> {
         let transaction: String = match item.transaction {
             Some(transaction) => transaction,
-            None => ViewSerializedTransaction::input_transaction(),
+            None => ViewSerializedTransaction::input_transaction()?,
         };
-        Self { transaction }
+        Ok(Self { transaction })
     }
 }
 
 impl ViewSerializedTransaction {
-    fn input_transaction() -> String {
-        Input::new()
+    fn input_transaction() -> color_eyre::eyre::Result<String> {
+        Ok(Input::new()
             .with_prompt("Enter the hash of the transaction")
+            .require_interactive("<transaction>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self) -> crate::CliResult {
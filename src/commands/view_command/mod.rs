@@ -1,8 +1,10 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 mod view_account;
 mod view_contract_code;
+mod view_contract_methods;
 mod view_contract_state;
 mod view_nonce;
 mod view_recent_block_hash;
@@ -44,13 +46,13 @@ impl From<ViewQueryRequest> for CliViewQueryRequest {
     }
 }
 
-impl From<CliViewQueryRequest> for ViewQueryRequest {
-    fn from(item: CliViewQueryRequest) -> Self {
+impl ViewQueryRequest {
+    pub fn from(item: CliViewQueryRequest) -> color_eyre::eyre::Result<Self> {
         let query = match item.query {
-            Some(cli_query_request) => QueryRequest::from(cli_query_request),
-            None => QueryRequest::choose_query_request(),
+            Some(cli_query_request) => QueryRequest::from(cli_query_request)?,
+            None => QueryRequest::choose_query_request()?,
         };
-        ViewQueryRequest { query }
+        Ok(ViewQueryRequest { query })
     }
 }
 
@@ -64,8 +66,12 @@ impl ViewQueryRequest {
 pub enum CliQueryRequest {
     /// View properties for an account
     AccountSummary(self::view_account::operation_mode::CliOperationMode),
+    /// View properties for an account on the network given with --network (example: near --network testnet view account bob.testnet)
+    Account(self::view_account::sender::CliSender),
     /// View a contract code
     ContractCode(self::view_contract_code::operation_mode::CliOperationMode),
+    /// View the methods of a contract
+    ContractMethods(self::view_contract_methods::operation_mode::CliOperationMode),
     /// View a contract state
     ContractState(self::view_contract_state::operation_mode::CliOperationMode),
     /// View a transaction status
@@ -83,6 +89,8 @@ pub enum QueryRequest {
     AccountSummary(self::view_account::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract code"))]
     ContractCode(self::view_contract_code::operation_mode::OperationMode),
+    #[strum_discriminants(strum(message = "View the methods of a contract"))]
+    ContractMethods(self::view_contract_methods::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a contract state"))]
     ContractState(self::view_contract_state::operation_mode::OperationMode),
     #[strum_discriminants(strum(message = "View a transaction status"))]
@@ -101,11 +109,21 @@ impl CliQueryRequest {
                 args.push_front("account-summary".to_owned());
                 args
             }
+            Self::Account(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("account".to_owned());
+                args
+            }
             Self::ContractCode(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("contract-code".to_owned());
                 args
             }
+            Self::ContractMethods(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("contract-methods".to_owned());
+                args
+            }
             Self::ContractState(subcommand) => {
                 let mut args = subcommand.to_cli_args();
                 args.push_front("contract-state".to_owned());
@@ -137,6 +155,9 @@ impl From<QueryRequest> for CliQueryRequest {
                 Self::AccountSummary(operation_mode.into())
             }
             QueryRequest::ContractCode(operation_mode) => Self::ContractCode(operation_mode.into()),
+            QueryRequest::ContractMethods(operation_mode) => {
+                Self::ContractMethods(operation_mode.into())
+            }
             QueryRequest::ContractState(operation_mode) => {
                 Self::ContractState(operation_mode.into())
             }
@@ -149,33 +170,47 @@ impl From<QueryRequest> for CliQueryRequest {
     }
 }
 
-impl From<CliQueryRequest> for QueryRequest {
-    fn from(item: CliQueryRequest) -> Self {
-        match item {
-            CliQueryRequest::AccountSummary(cli_operation_mode) => {
-                QueryRequest::AccountSummary(cli_operation_mode.into())
-            }
-            CliQueryRequest::ContractCode(cli_operation_mode) => {
-                QueryRequest::ContractCode(cli_operation_mode.into())
-            }
-            CliQueryRequest::ContractState(cli_operation_mode) => {
-                QueryRequest::ContractState(cli_operation_mode.into())
-            }
-            CliQueryRequest::Transaction(cli_operation_mode) => {
-                QueryRequest::Transaction(cli_operation_mode.into())
-            }
-            CliQueryRequest::Nonce(cli_operation_mode) => {
-                QueryRequest::Nonce(cli_operation_mode.into())
-            }
-            CliQueryRequest::RecentBlockHash(cli_operation_mode) => {
-                QueryRequest::RecentBlockHash(cli_operation_mode.into())
-            }
-        }
+impl QueryRequest {
+    fn from(item: CliQueryRequest) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
+            CliQueryRequest::AccountSummary(cli_operation_mode) => QueryRequest::AccountSummary(
+                self::view_account::operation_mode::OperationMode::from(cli_operation_mode)?,
+            ),
+            CliQueryRequest::Account(cli_sender) => QueryRequest::AccountSummary(
+                self::view_account::operation_mode::OperationMode::from(
+                    self::view_account::sender::CliSendTo::Account(cli_sender).into(),
+                )?,
+            ),
+            CliQueryRequest::ContractCode(cli_operation_mode) => QueryRequest::ContractCode(
+                self::view_contract_code::operation_mode::OperationMode::from(cli_operation_mode)?,
+            ),
+            CliQueryRequest::ContractMethods(cli_operation_mode) => QueryRequest::ContractMethods(
+                self::view_contract_methods::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+            CliQueryRequest::ContractState(cli_operation_mode) => QueryRequest::ContractState(
+                self::view_contract_state::operation_mode::OperationMode::from(cli_operation_mode)?,
+            ),
+            CliQueryRequest::Transaction(cli_operation_mode) => QueryRequest::Transaction(
+                self::view_transaction_status::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+            CliQueryRequest::Nonce(cli_operation_mode) => QueryRequest::Nonce(
+                self::view_nonce::operation_mode::OperationMode::from(cli_operation_mode)?,
+            ),
+            CliQueryRequest::RecentBlockHash(cli_operation_mode) => QueryRequest::RecentBlockHash(
+                self::view_recent_block_hash::operation_mode::OperationMode::from(
+                    cli_operation_mode,
+                )?,
+            ),
+        })
     }
 }
 
 impl QueryRequest {
-    fn choose_query_request() -> Self {
+    fn choose_query_request() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = QueryRequestDiscriminants::iter().collect::<Vec<_>>();
         let requests = variants
@@ -186,6 +221,7 @@ impl QueryRequest {
             .with_prompt("Сhoose what you want to view")
             .items(&requests)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_request = match variants[selected_request] {
@@ -195,6 +231,9 @@ impl QueryRequest {
             QueryRequestDiscriminants::ContractCode => {
                 CliQueryRequest::ContractCode(Default::default())
             }
+            QueryRequestDiscriminants::ContractMethods => {
+                CliQueryRequest::ContractMethods(Default::default())
+            }
             QueryRequestDiscriminants::ContractState => {
                 CliQueryRequest::ContractState(Default::default())
             }
@@ -213,6 +252,7 @@ impl QueryRequest {
         match self {
             QueryRequest::AccountSummary(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractCode(operation_mode) => operation_mode.process().await,
+            QueryRequest::ContractMethods(operation_mode) => operation_mode.process().await,
             QueryRequest::ContractState(operation_mode) => operation_mode.process().await,
             QueryRequest::Transaction(operation_mode) => operation_mode.process().await,
             QueryRequest::Nonce(operation_mode) => operation_mode.process().await,
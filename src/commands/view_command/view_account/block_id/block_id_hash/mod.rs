@@ -1,7 +1,10 @@
+use std::convert::TryFrom;
+
 use dialoguer::Input;
 
 use crate::common::{display_access_key_list, display_account_info, ConnectionConfig};
 
+use crate::interactive::RequireInteractive;
 use near_primitives::types::{AccountId, BlockId, BlockReference};
 
 /// Specify the block_id hash for this account to view
@@ -33,22 +36,25 @@ impl From<BlockIdHash> for CliBlockIdHash {
     }
 }
 
-impl From<CliBlockIdHash> for BlockIdHash {
-    fn from(item: CliBlockIdHash) -> Self {
+impl TryFrom<CliBlockIdHash> for BlockIdHash {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockIdHash) -> Result<Self, Self::Error> {
         let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHash::input_block_id_hash(),
+            None => BlockIdHash::input_block_id_hash()?,
         };
-        Self { block_id_hash }
+        Ok(Self { block_id_hash })
     }
 }
 
 impl BlockIdHash {
-    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
-        Input::new()
+    pub fn input_block_id_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        Ok(Input::new()
             .with_prompt("Type the block ID hash for this account")
+            .require_interactive("<block-id-hash>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self, account_id: AccountId, conf: ConnectionConfig) -> crate::CliResult {
@@ -1,6 +1,9 @@
+use std::convert::TryFrom;
+
 use dialoguer::Input;
 
 use crate::common::{display_access_key_list, display_account_info, ConnectionConfig};
+use crate::interactive::RequireInteractive;
 use near_primitives::types::{AccountId, BlockId, BlockReference};
 
 /// Specify the block_id height for this account to view
@@ -32,22 +35,26 @@ impl From<BlockIdHeight> for CliBlockIdHeight {
     }
 }
 
-impl From<CliBlockIdHeight> for BlockIdHeight {
-    fn from(item: CliBlockIdHeight) -> Self {
+impl TryFrom<CliBlockIdHeight> for BlockIdHeight {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockIdHeight) -> Result<Self, Self::Error> {
         let block_id_height: near_primitives::types::BlockHeight = match item.block_id_height {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHeight::input_block_id_height(),
+            None => BlockIdHeight::input_block_id_height()?,
         };
-        Self { block_id_height }
+        Ok(Self { block_id_height })
     }
 }
 
 impl BlockIdHeight {
-    pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
-        Input::new()
+    pub fn input_block_id_height() -> color_eyre::eyre::Result<near_primitives::types::BlockHeight>
+    {
+        Ok(Input::new()
             .with_prompt("Type the block ID height for this account")
+            .require_interactive("<block-id-height>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(self, account_id: AccountId, conf: ConnectionConfig) -> crate::CliResult {
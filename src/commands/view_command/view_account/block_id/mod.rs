@@ -1,7 +1,10 @@
+use std::convert::{TryFrom, TryInto};
+
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 use crate::common::{display_access_key_list, display_account_info, ConnectionConfig};
+use crate::interactive::RequireInteractive;
 use near_primitives::types::{AccountId, Finality};
 
 mod block_id_hash;
@@ -60,22 +63,24 @@ impl From<BlockId> for CliBlockId {
     }
 }
 
-impl From<CliBlockId> for BlockId {
-    fn from(item: CliBlockId) -> Self {
-        match item {
+impl TryFrom<CliBlockId> for BlockId {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockId) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliBlockId::AtFinalBlock => Self::AtFinalBlock,
             CliBlockId::AtBlockHeight(cli_block_id_height) => {
-                Self::AtBlockHeight(cli_block_id_height.into())
+                Self::AtBlockHeight(cli_block_id_height.try_into()?)
             }
             CliBlockId::AtBlockHash(cli_block_id_hash) => {
-                Self::AtBlockHash(cli_block_id_hash.into())
+                Self::AtBlockHash(cli_block_id_hash.try_into()?)
             }
-        }
+        })
     }
 }
 
 impl BlockId {
-    pub fn choose_block_id() -> Self {
+    pub fn choose_block_id() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
         let blocks = variants
@@ -86,6 +91,7 @@ impl BlockId {
             .with_prompt("Choose your action")
             .items(&blocks)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_block_id = match variants[selection] {
@@ -93,7 +99,7 @@ impl BlockId {
             BlockIdDiscriminants::AtBlockHeight => CliBlockId::AtBlockHeight(Default::default()),
             BlockIdDiscriminants::AtBlockHash => CliBlockId::AtBlockHash(Default::default()),
         };
-        Self::from(cli_block_id)
+        Self::try_from(cli_block_id)
     }
 
     pub async fn process(self, account_id: AccountId, conf: ConnectionConfig) -> crate::CliResult {
@@ -1,3 +1,3 @@
 mod block_id;
 pub mod operation_mode;
-mod sender;
+pub mod sender;
@@ -36,13 +36,23 @@ impl From<OperationMode> for CliOperationMode {
     }
 }
 
-impl From<CliOperationMode> for OperationMode {
-    fn from(item: CliOperationMode) -> Self {
+/// `view account <account-id>` skips the network subcommands: the network is given with
+/// `--network` or asked for
+impl From<super::sender::CliSendTo> for CliOperationMode {
+    fn from(send_to: super::sender::CliSendTo) -> Self {
+        Self {
+            mode: Some(CliMode::Network(send_to.into())),
+        }
+    }
+}
+
+impl OperationMode {
+    pub fn from(item: CliOperationMode) -> color_eyre::eyre::Result<Self> {
         let mode = match item.mode {
-            Some(cli_mode) => Mode::from(cli_mode),
-            None => Mode::choose_mode(),
+            Some(cli_mode) => Mode::from(cli_mode)?,
+            None => Mode::choose_mode()?,
         };
-        Self { mode }
+        Ok(Self { mode })
     }
 }
 
@@ -87,16 +97,18 @@ impl From<Mode> for CliMode {
     }
 }
 
-impl From<CliMode> for Mode {
-    fn from(item: CliMode) -> Self {
+impl Mode {
+    pub fn from(item: CliMode) -> color_eyre::eyre::Result<Self> {
         match item {
-            CliMode::Network(cli_network_args) => Self::Network(cli_network_args.into()),
+            CliMode::Network(cli_network_args) => Ok(Self::Network(
+                self::online_mode::NetworkArgs::from(cli_network_args)?,
+            )),
         }
     }
 }
 
 impl Mode {
-    pub fn choose_mode() -> Self {
+    pub fn choose_mode() -> color_eyre::eyre::Result<Self> {
         Self::from(CliMode::Network(Default::default()))
     }
 
@@ -1,4 +1,4 @@
-pub mod select_server;
+use std::convert::TryInto;
 
 /// аргументы, необходимые для создания транзакции в online mode
 #[derive(Debug, Default, Clone, clap::Clap)]
@@ -8,46 +8,66 @@ pub mod select_server;
     setting(clap::AppSettings::VersionlessSubcommands)
 )]
 pub struct CliNetworkArgs {
+    #[clap(flatten)]
+    selected_server: crate::network::CliSelectServer,
     #[clap(subcommand)]
-    selected_server: Option<self::select_server::CliSelectServer>,
+    pub send_to: Option<super::super::sender::CliSendTo>,
 }
 
 #[derive(Debug, Clone)]
 pub struct NetworkArgs {
-    selected_server: self::select_server::SelectServer,
+    selected_server: crate::network::SelectServer,
+    pub send_to: super::super::sender::SendTo,
 }
 
 impl CliNetworkArgs {
     pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
-        self.selected_server
+        let args = self
+            .send_to
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        self.selected_server.prepend_to_cli_args(args)
     }
 }
 
 impl From<NetworkArgs> for CliNetworkArgs {
     fn from(network_args: NetworkArgs) -> Self {
         Self {
-            selected_server: Some(network_args.selected_server.into()),
+            selected_server: network_args.selected_server.into(),
+            send_to: Some(network_args.send_to.into()),
         }
     }
 }
 
-impl From<CliNetworkArgs> for NetworkArgs {
-    fn from(item: CliNetworkArgs) -> Self {
-        let selected_server = match item.selected_server {
-            Some(cli_selected_server) => {
-                self::select_server::SelectServer::from(cli_selected_server)
-            }
-            None => self::select_server::SelectServer::choose_server(),
+impl From<super::super::sender::CliSendTo> for CliNetworkArgs {
+    fn from(send_to: super::super::sender::CliSendTo) -> Self {
+        Self {
+            selected_server: Default::default(),
+            send_to: Some(send_to),
+        }
+    }
+}
+
+impl NetworkArgs {
+    pub fn from(item: CliNetworkArgs) -> color_eyre::eyre::Result<Self> {
+        let selected_server = crate::network::SelectServer::from(item.selected_server)?;
+        let send_to = match item.send_to {
+            Some(cli_send_to) => cli_send_to.try_into()?,
+            None => super::super::sender::SendTo::send_to()?,
         };
-        Self { selected_server }
+        Ok(Self {
+            selected_server,
+            send_to,
+        })
     }
 }
 
 impl NetworkArgs {
     pub async fn process(self) -> crate::CliResult {
-        self.selected_server.process().await
+        self.selected_server.verify().await?;
+        self.send_to
+            .process(self.selected_server.connection_config)
+            .await
     }
 }
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -31,20 +34,22 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
-        match item {
+impl TryFrom<CliSendTo> for SendTo {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSendTo) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliSendTo::Account(cli_sender) => {
-                let sender = Sender::from(cli_sender);
+                let sender = Sender::try_from(cli_sender)?;
                 Self::Account(sender)
             }
-        }
+        })
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
-        Self::from(CliSendTo::Account(Default::default()))
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSendTo::Account(Default::default()))
     }
 
     pub async fn process(
@@ -99,30 +104,34 @@ impl From<Sender> for CliSender {
     }
 }
 
-impl From<CliSender> for Sender {
-    fn from(item: CliSender) -> Self {
+impl TryFrom<CliSender> for Sender {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSender) -> Result<Self, Self::Error> {
         let sender_account_id: near_primitives::types::AccountId = match item.sender_account_id {
             Some(cli_sender_account_id) => cli_sender_account_id,
-            None => Sender::input_sender_account_id(),
+            None => Sender::input_sender_account_id()?,
         };
         let selected_block_id: super::block_id::BlockId = match item.selected_block_id {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => cli_block_id.try_into()?,
+            None => super::block_id::BlockId::choose_block_id()?,
         };
-        Self {
+        Ok(Self {
             sender_account_id,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl Sender {
-    pub fn input_sender_account_id() -> near_primitives::types::AccountId {
+    pub fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("What Account ID do you need to view?")
+            .require_interactive("<sender-account-id>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
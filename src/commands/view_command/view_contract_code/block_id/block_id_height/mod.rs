@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 use std::io::Write;
 
@@ -30,26 +33,26 @@ impl From<BlockIdHeight> for CliBlockIdHeight {
     }
 }
 
-impl From<CliBlockIdHeight> for BlockIdHeight {
-    fn from(item: CliBlockIdHeight) -> Self {
+impl TryFrom<CliBlockIdHeight> for BlockIdHeight {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockIdHeight) -> Result<Self, Self::Error> {
         let block_id_height: near_primitives::types::BlockHeight = match item.block_id_height {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHeight::input_block_id_height(),
+            None => BlockIdHeight::input_block_id_height()?,
         };
-        Self { block_id_height }
+        Ok(Self { block_id_height })
     }
 }
 
 impl BlockIdHeight {
-    pub fn input_block_id_height() -> near_primitives::types::BlockHeight {
-        Input::new()
+    pub fn input_block_id_height() -> color_eyre::eyre::Result<near_primitives::types::BlockHeight>
+    {
+        Ok(Input::new()
             .with_prompt("Type the block ID height for this contract")
+            .require_interactive("<block-id-height>")?
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -58,23 +61,23 @@ impl BlockIdHeight {
         network_connection_config: crate::common::ConnectionConfig,
         file_path: Option<std::path::PathBuf>,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::BlockReference::BlockId(
-                    near_primitives::types::BlockId::Height(self.block_id_height.clone()),
-                ),
-                request: near_primitives::views::QueryRequest::ViewCode {
-                    account_id: contract_id,
-                },
-            })
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view contract: {:?}",
-                    err
-                ))
-            })?;
+        let query_view_method_response =
+            crate::rpc::RpcClient::archival(&network_connection_config)
+                .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::BlockReference::BlockId(
+                        near_primitives::types::BlockId::Height(self.block_id_height.clone()),
+                    ),
+                    request: near_primitives::views::QueryRequest::ViewCode {
+                        account_id: contract_id,
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view contract: {:?}",
+                        err
+                    ))
+                })?;
         let call_access_view =
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
                 query_view_method_response.kind
@@ -1,3 +1,6 @@
+use std::convert::TryInto;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 // download contract file
@@ -43,32 +46,33 @@ impl From<ContractFile> for CliContractFile {
 }
 
 impl ContractFile {
-    pub fn from(item: CliContractFile, contract_id: &str) -> Self {
+    pub fn from(item: CliContractFile, contract_id: &str) -> color_eyre::eyre::Result<Self> {
         let file_path = match item.file_path {
             Some(cli_file_path) => Some(cli_file_path),
-            None => ContractFile::input_file_path(contract_id),
+            None => ContractFile::input_file_path(contract_id)?,
         };
         let selected_block_id: super::super::super::block_id::BlockId = match item.selected_block_id
         {
-            Some(cli_block_id) => cli_block_id.into(),
-            None => super::super::super::block_id::BlockId::choose_block_id(),
+            Some(cli_block_id) => cli_block_id.try_into()?,
+            None => super::super::super::block_id::BlockId::choose_block_id()?,
         };
-        ContractFile {
+        Ok(ContractFile {
             file_path,
             selected_block_id,
-        }
+        })
     }
 }
 
 impl ContractFile {
-    fn input_file_path(contract_id: &str) -> Option<std::path::PathBuf> {
+    fn input_file_path(contract_id: &str) -> color_eyre::eyre::Result<Option<std::path::PathBuf>> {
         println!();
         let input_file_path: String = Input::new()
             .with_prompt("Where to download the contract file?")
             .with_initial_text(format!("{}.wasm", contract_id))
+            .require_interactive("<file-path>")?
             .interact_text()
             .unwrap();
-        Some(input_file_path.into())
+        Ok(Some(input_file_path.into()))
     }
 
     pub async fn process(
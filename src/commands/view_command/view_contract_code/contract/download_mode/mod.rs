@@ -1,3 +1,4 @@
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -48,20 +49,20 @@ impl From<DownloadMode> for CliDownloadMode {
 }
 
 impl DownloadMode {
-    pub fn from(item: CliDownloadMode, contract_id: &str) -> Self {
-        match item {
+    pub fn from(item: CliDownloadMode, contract_id: &str) -> color_eyre::eyre::Result<Self> {
+        Ok(match item {
             CliDownloadMode::Download(cli_contract_file) => DownloadMode::Download(
-                self::download_contract::ContractFile::from(cli_contract_file, contract_id),
+                self::download_contract::ContractFile::from(cli_contract_file, contract_id)?,
             ),
             CliDownloadMode::Hash(cli_contract_hash) => {
-                DownloadMode::Hash(self::hash_contract::ContractHash::from(cli_contract_hash))
+                DownloadMode::Hash(self::hash_contract::ContractHash::from(cli_contract_hash)?)
             }
-        }
+        })
     }
 }
 
 impl DownloadMode {
-    pub fn choose_download_mode(contract_id: &str) -> Self {
+    pub fn choose_download_mode(contract_id: &str) -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = DownloadModeDiscriminants::iter().collect::<Vec<_>>();
         let modes = variants
@@ -72,6 +73,7 @@ impl DownloadMode {
             .with_prompt("To view contract code you will need to choose next action")
             .items(&modes)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_mode = match variants[selected_mode] {
@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 mod download_mode;
@@ -33,20 +36,22 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
-        match item {
+impl TryFrom<CliSendTo> for SendTo {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSendTo) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliSendTo::Contract(cli_sender) => {
-                let sender = Contract::from(cli_sender);
+                let sender = Contract::try_from(cli_sender)?;
                 Self::Contract(sender)
             }
-        }
+        })
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
-        Self::from(CliSendTo::Contract(Default::default()))
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSendTo::Contract(Default::default()))
     }
 
     pub async fn process(
@@ -101,34 +106,38 @@ impl From<Contract> for CliContract {
     }
 }
 
-impl From<CliContract> for Contract {
-    fn from(item: CliContract) -> Self {
+impl TryFrom<CliContract> for Contract {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliContract) -> Result<Self, Self::Error> {
         let contract_id: near_primitives::types::AccountId = match item.contract_id {
             Some(cli_contract_id) => cli_contract_id,
-            None => Contract::input_contract_id(),
+            None => Contract::input_contract_id()?,
         };
         let download_mode = match item.download_mode {
-            Some(cli_download_mode) => {
-                self::download_mode::DownloadMode::from(cli_download_mode, &contract_id.to_string())
-            }
+            Some(cli_download_mode) => self::download_mode::DownloadMode::from(
+                cli_download_mode,
+                &contract_id.to_string(),
+            )?,
             None => {
-                self::download_mode::DownloadMode::choose_download_mode(&contract_id.to_string())
+                self::download_mode::DownloadMode::choose_download_mode(&contract_id.to_string())?
             }
         };
-        Self {
+        Ok(Self {
             contract_id,
             download_mode,
-        }
+        })
     }
 }
 
 impl Contract {
-    pub fn input_contract_id() -> near_primitives::types::AccountId {
+    pub fn input_contract_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("What contract do you need to view?")
+            .require_interactive("<contract-id>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -0,0 +1,171 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
+use dialoguer::Input;
+
+#[derive(Debug, Clone, clap::Clap)]
+pub enum CliSendTo {
+    /// Specify a contract
+    Contract(CliContract),
+}
+
+#[derive(Debug, Clone)]
+pub enum SendTo {
+    Contract(Contract),
+}
+
+impl CliSendTo {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        match self {
+            Self::Contract(subcommand) => {
+                let mut args = subcommand.to_cli_args();
+                args.push_front("contract".to_owned());
+                args
+            }
+        }
+    }
+}
+
+impl From<SendTo> for CliSendTo {
+    fn from(send_to: SendTo) -> Self {
+        match send_to {
+            SendTo::Contract(contract) => Self::Contract(contract.into()),
+        }
+    }
+}
+
+impl TryFrom<CliSendTo> for SendTo {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSendTo) -> Result<Self, Self::Error> {
+        Ok(match item {
+            CliSendTo::Contract(cli_contract) => Self::Contract(cli_contract.try_into()?),
+        })
+    }
+}
+
+impl SendTo {
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSendTo::Contract(Default::default()))
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        match self {
+            SendTo::Contract(contract) => contract.process(network_connection_config).await,
+        }
+    }
+}
+
+/// Specify a contract
+#[derive(Debug, Default, Clone, clap::Clap)]
+#[clap(
+    setting(clap::AppSettings::ColoredHelp),
+    setting(clap::AppSettings::DisableHelpSubcommand),
+    setting(clap::AppSettings::VersionlessSubcommands)
+)]
+pub struct CliContract {
+    pub contract_id: Option<near_primitives::types::AccountId>,
+    /// Path to the contract ABI (metadata) file with the argument names and the view methods
+    #[clap(long)]
+    abi_file: Option<std::path::PathBuf>,
+    /// Path to the contract wasm file (the contract code is downloaded from the network otherwise)
+    #[clap(long)]
+    wasm_file: Option<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Contract {
+    pub contract_id: near_primitives::types::AccountId,
+    abi_file: Option<std::path::PathBuf>,
+    wasm_file: Option<std::path::PathBuf>,
+}
+
+impl CliContract {
+    pub fn to_cli_args(&self) -> std::collections::VecDeque<String> {
+        let mut args = std::collections::VecDeque::new();
+        if let Some(wasm_file) = &self.wasm_file {
+            args.push_front(wasm_file.as_path().display().to_string());
+            args.push_front("--wasm-file".to_owned());
+        };
+        if let Some(abi_file) = &self.abi_file {
+            args.push_front(abi_file.as_path().display().to_string());
+            args.push_front("--abi-file".to_owned());
+        };
+        if let Some(contract_id) = &self.contract_id {
+            args.push_front(contract_id.to_string());
+        };
+        args
+    }
+}
+
+impl From<Contract> for CliContract {
+    fn from(contract: Contract) -> Self {
+        Self {
+            contract_id: Some(contract.contract_id),
+            abi_file: contract.abi_file,
+            wasm_file: contract.wasm_file,
+        }
+    }
+}
+
+impl TryFrom<CliContract> for Contract {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliContract) -> Result<Self, Self::Error> {
+        let contract_id: near_primitives::types::AccountId = match item.contract_id {
+            Some(cli_contract_id) => cli_contract_id,
+            None => Contract::input_contract_id()?,
+        };
+        Ok(Self {
+            contract_id,
+            abi_file: item.abi_file,
+            wasm_file: item.wasm_file,
+        })
+    }
+}
+
+impl Contract {
+    pub fn input_contract_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
+        println!();
+        Ok(Input::new()
+            .with_prompt("What contract do you need to view?")
+            .require_interactive("<contract-id>")?
+            .interact_text()
+            .unwrap())
+    }
+
+    pub async fn process(
+        self,
+        network_connection_config: crate::common::ConnectionConfig,
+    ) -> crate::CliResult {
+        let code: Vec<u8> = match &self.wasm_file {
+            Some(wasm_file) => std::fs::read(wasm_file).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to read the wasm file: {:?}", err))
+            })?,
+            None => {
+                crate::common::fetch_contract_code(
+                    &network_connection_config,
+                    self.contract_id.clone(),
+                )
+                .await?
+            }
+        };
+        let contract_abi = match &self.abi_file {
+            Some(abi_file) => Some(crate::common::ContractAbi::from_file(abi_file)?),
+            None => None,
+        };
+        let methods = crate::common::get_contract_methods(&code, contract_abi.as_ref())?;
+        println!(
+            "\nContract <{}> has {} methods:",
+            self.contract_id,
+            methods.len()
+        );
+        for method in methods {
+            println!("  {}", method);
+        }
+        Ok(())
+    }
+}
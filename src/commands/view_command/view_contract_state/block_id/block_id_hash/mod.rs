@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 /// Specify the block_id hash for this contract to view
@@ -29,26 +32,25 @@ impl From<BlockIdHash> for CliBlockIdHash {
     }
 }
 
-impl From<CliBlockIdHash> for BlockIdHash {
-    fn from(item: CliBlockIdHash) -> Self {
+impl TryFrom<CliBlockIdHash> for BlockIdHash {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockIdHash) -> Result<Self, Self::Error> {
         let block_id_hash: near_primitives::hash::CryptoHash = match item.block_id_hash {
             Some(cli_block_id_hash) => cli_block_id_hash,
-            None => BlockIdHash::input_block_id_hash(),
+            None => BlockIdHash::input_block_id_hash()?,
         };
-        Self { block_id_hash }
+        Ok(Self { block_id_hash })
     }
 }
 
 impl BlockIdHash {
-    pub fn input_block_id_hash() -> near_primitives::hash::CryptoHash {
-        Input::new()
+    pub fn input_block_id_hash() -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        Ok(Input::new()
             .with_prompt("Type the block ID hash for this contract")
+            .require_interactive("<block-id-hash>")?
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -56,24 +58,24 @@ impl BlockIdHash {
         sender_account_id: near_primitives::types::AccountId,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::BlockReference::BlockId(
-                    near_primitives::types::BlockId::Hash(self.block_id_hash.clone()),
-                ),
-                request: near_primitives::views::QueryRequest::ViewState {
-                    account_id: sender_account_id,
-                    prefix: near_primitives::types::StoreKey::from(vec![]),
-                },
-            })
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view account: {:?}",
-                    err
-                ))
-            })?;
+        let query_view_method_response =
+            crate::rpc::RpcClient::archival(&network_connection_config)
+                .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::BlockReference::BlockId(
+                        near_primitives::types::BlockId::Hash(self.block_id_hash.clone()),
+                    ),
+                    request: near_primitives::views::QueryRequest::ViewState {
+                        account_id: sender_account_id,
+                        prefix: near_primitives::types::StoreKey::from(vec![]),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view account: {:?}",
+                        err
+                    ))
+                })?;
         let call_access_view =
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
                 query_view_method_response.kind
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::{theme::ColorfulTheme, Select};
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
@@ -57,22 +60,24 @@ impl From<BlockId> for CliBlockId {
     }
 }
 
-impl From<CliBlockId> for BlockId {
-    fn from(item: CliBlockId) -> Self {
-        match item {
+impl TryFrom<CliBlockId> for BlockId {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliBlockId) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliBlockId::AtFinalBlock => Self::AtFinalBlock,
             CliBlockId::AtBlockHeight(cli_block_id_height) => {
-                Self::AtBlockHeight(cli_block_id_height.into())
+                Self::AtBlockHeight(cli_block_id_height.try_into()?)
             }
             CliBlockId::AtBlockHash(cli_block_id_hash) => {
-                Self::AtBlockHash(cli_block_id_hash.into())
+                Self::AtBlockHash(cli_block_id_hash.try_into()?)
             }
-        }
+        })
     }
 }
 
 impl BlockId {
-    pub fn choose_block_id() -> Self {
+    pub fn choose_block_id() -> color_eyre::eyre::Result<Self> {
         println!();
         let variants = BlockIdDiscriminants::iter().collect::<Vec<_>>();
         let blocks = variants
@@ -83,6 +88,7 @@ impl BlockId {
             .with_prompt("Choose your action")
             .items(&blocks)
             .default(0)
+            .require_interactive_choice()?
             .interact()
             .unwrap();
         let cli_block_id = match variants[selection] {
@@ -90,7 +96,7 @@ impl BlockId {
             BlockIdDiscriminants::AtBlockHeight => CliBlockId::AtBlockHeight(Default::default()),
             BlockIdDiscriminants::AtBlockHash => CliBlockId::AtBlockHash(Default::default()),
         };
-        Self::from(cli_block_id)
+        Self::try_from(cli_block_id)
     }
 
     pub async fn process(
@@ -117,17 +123,12 @@ impl BlockId {
         }
     }
 
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
-    }
-
     async fn at_final_block(
         self,
         sender_account_id: near_primitives::types::AccountId,
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
-        let query_view_method_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
+        let query_view_method_response = crate::rpc::RpcClient::new(&network_connection_config)
             .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
                 block_reference: near_primitives::types::Finality::Final.into(),
                 request: near_primitives::views::QueryRequest::ViewState {
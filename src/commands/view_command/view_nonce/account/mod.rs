@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -31,20 +34,22 @@ impl From<SendTo> for CliSendTo {
     }
 }
 
-impl From<CliSendTo> for SendTo {
-    fn from(item: CliSendTo) -> Self {
-        match item {
+impl TryFrom<CliSendTo> for SendTo {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSendTo) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliSendTo::Account(cli_account) => {
-                let account = Account::from(cli_account);
+                let account = Account::try_from(cli_account)?;
                 Self::Account(account)
             }
-        }
+        })
     }
 }
 
 impl SendTo {
-    pub fn send_to() -> Self {
-        Self::from(CliSendTo::Account(Default::default()))
+    pub fn send_to() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSendTo::Account(Default::default()))
     }
 
     pub async fn process(
@@ -99,30 +104,33 @@ impl From<Account> for CliAccount {
     }
 }
 
-impl From<CliAccount> for Account {
-    fn from(item: CliAccount) -> Self {
+impl TryFrom<CliAccount> for Account {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliAccount) -> Result<Self, Self::Error> {
         let account_id: near_primitives::types::AccountId = match item.account_id {
             Some(cli_account_id) => cli_account_id,
-            None => Account::input_account_id(),
+            None => Account::input_account_id()?,
         };
         let public_key = match item.public_key {
-            Some(cli_public_key) => super::public_key::AccessKey::from(cli_public_key),
-            None => super::public_key::AccessKey::choose_key(),
+            Some(cli_public_key) => super::public_key::AccessKey::try_from(cli_public_key)?,
+            None => super::public_key::AccessKey::choose_key()?,
         };
-        Self {
+        Ok(Self {
             account_id,
             public_key,
-        }
+        })
     }
 }
 
 impl Account {
-    fn input_account_id() -> near_primitives::types::AccountId {
+    fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter your account ID")
+            .require_interactive("<account-id>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -31,19 +34,21 @@ impl From<AccessKey> for CliAccessKey {
     }
 }
 
-impl From<CliAccessKey> for AccessKey {
-    fn from(item: CliAccessKey) -> Self {
-        match item {
+impl TryFrom<CliAccessKey> for AccessKey {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliAccessKey) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliAccessKey::PublicKey(cli_delete_access_key_type) => {
-                Self::PublicKey(cli_delete_access_key_type.into())
+                Self::PublicKey(cli_delete_access_key_type.try_into()?)
             }
-        }
+        })
     }
 }
 
 impl AccessKey {
-    pub fn choose_key() -> Self {
-        Self::from(CliAccessKey::PublicKey(Default::default()))
+    pub fn choose_key() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliAccessKey::PublicKey(Default::default()))
     }
 
     pub async fn process(
@@ -90,26 +95,25 @@ impl From<AccessKeyType> for CliAccessKeyType {
     }
 }
 
-impl From<CliAccessKeyType> for AccessKeyType {
-    fn from(item: CliAccessKeyType) -> Self {
+impl TryFrom<CliAccessKeyType> for AccessKeyType {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliAccessKeyType) -> Result<Self, Self::Error> {
         let public_key: near_crypto::PublicKey = match item.public_key {
             Some(cli_public_key) => cli_public_key,
-            None => AccessKeyType::input_public_key(),
+            None => AccessKeyType::input_public_key()?,
         };
-        Self { public_key }
+        Ok(Self { public_key })
     }
 }
 
 impl AccessKeyType {
-    pub fn input_public_key() -> near_crypto::PublicKey {
-        Input::new()
+    pub fn input_public_key() -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        Ok(Input::new()
             .with_prompt("Enter a public key for this access key")
+            .require_interactive("<public-key>")?
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -118,22 +122,22 @@ impl AccessKeyType {
         network_connection_config: crate::common::ConnectionConfig,
     ) -> crate::CliResult {
         let public_key = self.public_key.clone();
-        let online_signer_access_key_response = self
-            .rpc_client(network_connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewAccessKey {
-                    account_id,
-                    public_key: public_key.clone(),
-                },
-            })
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch public key information for nonce: {:?}",
-                    err
-                ))
-            })?;
+        let online_signer_access_key_response =
+            crate::rpc::RpcClient::new(&network_connection_config)
+                .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::Finality::Final.into(),
+                    request: near_primitives::views::QueryRequest::ViewAccessKey {
+                        account_id,
+                        public_key: public_key.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch public key information for nonce: {:?}",
+                        err
+                    ))
+                })?;
         let current_nonce =
             if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
                 online_signer_access_key,
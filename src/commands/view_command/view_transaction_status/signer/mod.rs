@@ -1,3 +1,6 @@
+use std::convert::TryFrom;
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -31,20 +34,22 @@ impl From<SendFrom> for CliSendFrom {
     }
 }
 
-impl From<CliSendFrom> for SendFrom {
-    fn from(item: CliSendFrom) -> Self {
-        match item {
+impl TryFrom<CliSendFrom> for SendFrom {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSendFrom) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliSendFrom::Signer(cli_sender) => {
-                let sender = Sender::from(cli_sender);
+                let sender = Sender::try_from(cli_sender)?;
                 Self::Signer(sender)
             }
-        }
+        })
     }
 }
 
 impl SendFrom {
-    pub fn send_from() -> Self {
-        Self::from(CliSendFrom::Signer(Default::default()))
+    pub fn send_from() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliSendFrom::Signer(Default::default()))
     }
 
     pub async fn process(
@@ -91,27 +96,27 @@ impl From<Sender> for CliSender {
     }
 }
 
-impl From<CliSender> for Sender {
-    fn from(item: CliSender) -> Self {
+impl TryFrom<CliSender> for Sender {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliSender) -> Result<Self, Self::Error> {
         let account_id: near_primitives::types::AccountId = match item.account_id {
             Some(cli_account_id) => cli_account_id,
-            None => Sender::input_sender_account_id(),
+            None => Sender::input_sender_account_id()?,
         };
-        Self { account_id }
+        Ok(Self { account_id })
     }
 }
 
 impl Sender {
-    pub fn input_sender_account_id() -> near_primitives::types::AccountId {
+    pub fn input_sender_account_id() -> color_eyre::eyre::Result<near_primitives::types::AccountId>
+    {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Specify the account that signed the transaction")
+            .require_interactive("<account-id>")?
             .interact_text()
-            .unwrap()
-    }
-
-    fn rpc_client(&self, selected_server_url: &str) -> near_jsonrpc_client::JsonRpcClient {
-        near_jsonrpc_client::new_client(&selected_server_url)
+            .unwrap())
     }
 
     pub async fn process(
@@ -120,16 +125,16 @@ impl Sender {
         transaction_hash: String,
     ) -> crate::CliResult {
         let account_id = self.account_id.clone();
-        let query_view_transaction_status = self
-            .rpc_client(network_connection_config.archival_rpc_url().as_str())
-            .tx(transaction_hash, account_id)
-            .await
-            .map_err(|err| {
-                color_eyre::Report::msg(format!(
-                    "Failed to fetch query for view transaction: {:?}",
-                    err
-                ))
-            })?;
+        let query_view_transaction_status =
+            crate::rpc::RpcClient::archival(&network_connection_config)
+                .tx(transaction_hash, account_id)
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch query for view transaction: {:?}",
+                        err
+                    ))
+                })?;
         println!("Transactiion status: {:#?}", query_view_transaction_status);
         Ok(())
     }
@@ -1,3 +1,6 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::interactive::RequireInteractive;
 use dialoguer::Input;
 
 #[derive(Debug, Clone, clap::Clap)]
@@ -33,19 +36,21 @@ impl From<Transaction> for CliTransaction {
     }
 }
 
-impl From<CliTransaction> for Transaction {
-    fn from(item: CliTransaction) -> Self {
-        match item {
+impl TryFrom<CliTransaction> for Transaction {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliTransaction) -> Result<Self, Self::Error> {
+        Ok(match item {
             CliTransaction::TransactionHash(cli_transaction_type) => {
-                Transaction::TransactionHash(cli_transaction_type.into())
+                Transaction::TransactionHash(cli_transaction_type.try_into()?)
             }
-        }
+        })
     }
 }
 
 impl Transaction {
-    pub fn transaction() -> Self {
-        Self::from(CliTransaction::TransactionHash(Default::default()))
+    pub fn transaction() -> color_eyre::eyre::Result<Self> {
+        Self::try_from(CliTransaction::TransactionHash(Default::default()))
     }
 
     pub async fn process(
@@ -102,30 +107,33 @@ impl From<TransactionType> for CliTransactionType {
     }
 }
 
-impl From<CliTransactionType> for TransactionType {
-    fn from(item: CliTransactionType) -> Self {
+impl TryFrom<CliTransactionType> for TransactionType {
+    type Error = color_eyre::eyre::Report;
+
+    fn try_from(item: CliTransactionType) -> Result<Self, Self::Error> {
         let transaction_hash = match item.transaction_hash {
             Some(cli_transaction_hash) => cli_transaction_hash,
-            None => TransactionType::input_transaction_hash(),
+            None => TransactionType::input_transaction_hash()?,
         };
         let send_from = match item.send_from {
-            Some(cli_send_from) => super::signer::SendFrom::from(cli_send_from),
-            None => super::signer::SendFrom::send_from(),
+            Some(cli_send_from) => super::signer::SendFrom::try_from(cli_send_from)?,
+            None => super::signer::SendFrom::send_from()?,
         };
-        Self {
+        Ok(Self {
             transaction_hash,
             send_from,
-        }
+        })
     }
 }
 
 impl TransactionType {
-    fn input_transaction_hash() -> String {
+    fn input_transaction_hash() -> color_eyre::eyre::Result<String> {
         println!();
-        Input::new()
+        Ok(Input::new()
             .with_prompt("Enter the hash of the transaction you need to view")
+            .require_interactive("<transaction-hash>")?
             .interact_text()
-            .unwrap()
+            .unwrap())
     }
 
     pub async fn process(
@@ -1,7 +1,7 @@
 use std::convert::{TryFrom, TryInto};
 use std::io::Write;
 
-use near_jsonrpc_client::new_client;
+use crate::interactive::RequireInteractive;
 use near_jsonrpc_primitives::types::query::{QueryResponseKind, RpcQueryRequest};
 use near_primitives::{
     borsh::BorshDeserialize,
@@ -12,6 +12,17 @@ use near_primitives::{
 
 pub type CliResult = color_eyre::eyre::Result<()>;
 
+thread_local! {
+    static RUNTIME: actix::SystemRunner = actix::System::new();
+}
+
+/// Runs the future on the runtime shared by the whole process. The argument
+/// conversions don't query the network, the RPC checks run in the async `process`.
+/// It must not be called from an async `process`, await the future there instead.
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    RUNTIME.with(|runtime| runtime.block_on(future))
+}
+
 #[derive(
     Debug,
     Clone,
@@ -94,17 +105,39 @@ impl std::str::FromStr for AvailableRpcServerUrl {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let url: url::Url =
             url::Url::parse(s).map_err(|err| format!("URL is not parsed: {}", err))?;
-        actix::System::new()
-            .block_on(async {
-                near_jsonrpc_client::new_client(&url.as_str())
-                    .status()
-                    .await
-            })
-            .map_err(|err| format!("AvailableRpcServerUrl: {:?}", err))?;
         Ok(Self { inner: url })
     }
 }
 
+impl AvailableRpcServerUrl {
+    /// Checks that the RPC server responds
+    pub async fn verify(&self) -> CliResult {
+        crate::rpc::RpcClient::from_url(self.inner.clone())
+            .status()
+            .await
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The RPC server {} is not available: {:?}",
+                    self.inner, err
+                ))
+            })?;
+        Ok(())
+    }
+
+    /// The URL of the custom network given in the command line or entered by the user.
+    /// The RPC server is checked later with [`AvailableRpcServerUrl::verify`].
+    pub fn resolve(url: Option<Self>) -> color_eyre::eyre::Result<Self> {
+        Ok(match url.or_else(crate::network::custom_rpc_url) {
+            Some(url) => url,
+            None => dialoguer::Input::new()
+                .with_prompt("What is the RPC endpoint?")
+                .require_interactive("--url")?
+                .interact_text()
+                .unwrap(),
+        })
+    }
+}
+
 impl std::fmt::Display for AvailableRpcServerUrl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.inner.fmt(f)
@@ -309,6 +342,24 @@ impl TransferAmount {
     pub fn to_yoctonear(&self) -> u128 {
         self.amount.to_yoctonear()
     }
+
+    /// Warns if the account can't transfer the amount, the transaction is sent anyway
+    pub async fn check_transfer_allowance(
+        &self,
+        connection_config: &ConnectionConfig,
+        account_id: &near_primitives::types::AccountId,
+    ) -> CliResult {
+        let account_transfer_allowance =
+            get_account_transfer_allowance(connection_config, account_id.clone()).await?;
+        if Self::from(self.amount.clone(), &account_transfer_allowance).is_err() {
+            println!("{}", &account_transfer_allowance);
+            println!(
+                "\nWARNING! There is only {} available for transfer.",
+                account_transfer_allowance.transfer_allowance()
+            );
+        }
+        Ok(())
+    }
 }
 
 impl From<TransferAmount> for NearBalance {
@@ -368,6 +419,24 @@ impl ConnectionConfig {
         }
     }
 
+    pub fn helper_url(&self) -> Option<url::Url> {
+        match self {
+            Self::Testnet => Some(crate::consts::TESTNET_HELPER_URL.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_HELPER_URL.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_HELPER_URL.parse().unwrap()),
+            Self::Custom { url: _ } => None,
+        }
+    }
+
+    pub fn linkdrop_account_id(&self) -> Option<near_primitives::types::AccountId> {
+        match self {
+            Self::Testnet => Some(crate::consts::TESTNET_LINKDROP_ACCOUNT_ID.parse().unwrap()),
+            Self::Mainnet => Some(crate::consts::MAINNET_LINKDROP_ACCOUNT_ID.parse().unwrap()),
+            Self::Betanet => Some(crate::consts::BETANET_LINKDROP_ACCOUNT_ID.parse().unwrap()),
+            Self::Custom { url: _ } => None,
+        }
+    }
+
     pub fn dir_name(&self) -> &str {
         match self {
             Self::Testnet => crate::consts::DIR_NAME_TESTNET,
@@ -418,35 +487,32 @@ impl AccountTransferAllowance {
     }
 }
 
-pub fn get_account_transfer_allowance(
+pub async fn get_account_transfer_allowance(
     connection_config: &ConnectionConfig,
     account_id: near_primitives::types::AccountId,
 ) -> color_eyre::eyre::Result<AccountTransferAllowance> {
-    let account_view =
-        if let Some(account_view) = get_account_state(connection_config, account_id.clone())? {
-            account_view
-        } else {
-            return Ok(AccountTransferAllowance {
-                account_id,
-                account_liquid_balance: NearBalance::from_yoctonear(0),
-                account_locked_balance: NearBalance::from_yoctonear(0),
-                storage_stake: NearBalance::from_yoctonear(0),
-                pessimistic_transaction_fee: NearBalance::from_yoctonear(0),
-            });
-        };
-    let storage_amount_per_byte = actix::System::new()
-        .block_on(async {
-            near_jsonrpc_client::new_client(connection_config.rpc_url().as_str())
-                .EXPERIMENTAL_protocol_config(
-                    near_jsonrpc_primitives::types::config::RpcProtocolConfigRequest {
-                        block_reference:
-                            near_jsonrpc_primitives::types::blocks::BlockReference::Finality(
-                                near_primitives::types::Finality::Final,
-                            ),
-                    },
-                )
-                .await
-        })
+    let account_view = if let Some(account_view) =
+        get_account_state(connection_config, account_id.clone()).await?
+    {
+        account_view
+    } else {
+        return Ok(AccountTransferAllowance {
+            account_id,
+            account_liquid_balance: NearBalance::from_yoctonear(0),
+            account_locked_balance: NearBalance::from_yoctonear(0),
+            storage_stake: NearBalance::from_yoctonear(0),
+            pessimistic_transaction_fee: NearBalance::from_yoctonear(0),
+        });
+    };
+    let storage_amount_per_byte = crate::rpc::RpcClient::new(&connection_config)
+        .EXPERIMENTAL_protocol_config(
+            near_jsonrpc_primitives::types::config::RpcProtocolConfigRequest {
+                block_reference: near_jsonrpc_primitives::types::blocks::BlockReference::Finality(
+                    near_primitives::types::Finality::Final,
+                ),
+            },
+        )
+        .await
         .map_err(|err| color_eyre::Report::msg(format!("RpcError: {:?}", err)))?
         .config_view
         .runtime_config
@@ -466,18 +532,16 @@ pub fn get_account_transfer_allowance(
     })
 }
 
-pub fn get_account_state(
+pub async fn get_account_state(
     connection_config: &ConnectionConfig,
     account_id: near_primitives::types::AccountId,
 ) -> color_eyre::eyre::Result<Option<near_primitives::views::AccountView>> {
-    let query_view_method_response = actix::System::new().block_on(async {
-        near_jsonrpc_client::new_client(connection_config.rpc_url().as_str())
-            .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
-                block_reference: near_primitives::types::Finality::Final.into(),
-                request: near_primitives::views::QueryRequest::ViewAccount { account_id },
-            })
-            .await
-    });
+    let query_view_method_response = crate::rpc::RpcClient::new(&connection_config)
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewAccount { account_id },
+        })
+        .await;
     match query_view_method_response {
         Ok(rpc_query_response) => {
             let account_view =
@@ -495,6 +559,160 @@ pub fn get_account_state(
     }
 }
 
+/// Fails if the account does not exist on the network
+pub async fn verify_account_exists(
+    connection_config: &ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> CliResult {
+    match get_account_state(connection_config, account_id.clone()).await? {
+        Some(_) => Ok(()),
+        None => Err(color_eyre::Report::msg(format!(
+            "Account <{}> doesn't exist",
+            account_id
+        ))),
+    }
+}
+
+/// Fails if the account to be created already exists on the network
+pub async fn verify_account_does_not_exist(
+    connection_config: &ConnectionConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> CliResult {
+    match get_account_state(connection_config, account_id.clone()).await? {
+        Some(_) => Err(color_eyre::Report::msg(format!(
+            "Account <{}> already exists",
+            account_id
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the list of NEP-141 token contracts to be checked before an account is deleted.
+/// The list can be overridden in ~/.near-cli/tokens.json, e.g. `{"mainnet": ["wrap.near"]}`.
+pub fn get_token_list(
+    connection_config: &ConnectionConfig,
+) -> color_eyre::eyre::Result<Vec<near_primitives::types::AccountId>> {
+    let network_name = match connection_config {
+        ConnectionConfig::Testnet => "testnet",
+        ConnectionConfig::Mainnet => "mainnet",
+        ConnectionConfig::Betanet => "betanet",
+        ConnectionConfig::Custom { url: _ } => "custom",
+    };
+    let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+    path.push(crate::consts::FILE_NAME_TOKEN_LIST);
+    if path.exists() {
+        let data = std::fs::read_to_string(&path).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", &path, err))
+        })?;
+        let token_lists: std::collections::HashMap<String, Vec<near_primitives::types::AccountId>> =
+            serde_json::from_str(&data).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", &path, err))
+            })?;
+        if let Some(token_list) = token_lists.get(network_name) {
+            return Ok(token_list.clone());
+        }
+    }
+    let token_list: &[&str] = match connection_config {
+        ConnectionConfig::Testnet => crate::consts::TESTNET_TOKEN_LIST,
+        ConnectionConfig::Mainnet => crate::consts::MAINNET_TOKEN_LIST,
+        _ => &[],
+    };
+    token_list
+        .iter()
+        .map(|token| {
+            near_primitives::types::AccountId::try_from(token.to_string())
+                .map_err(|err| color_eyre::Report::msg(format!("{}: {}", token, err)))
+        })
+        .collect()
+}
+
+/// Returns the NEP-141 balance of the account or None if the token contract can not be queried.
+pub async fn get_ft_balance(
+    connection_config: &ConnectionConfig,
+    token_account_id: &near_primitives::types::AccountId,
+    account_id: &near_primitives::types::AccountId,
+) -> Option<u128> {
+    let query_view_method_response = crate::rpc::RpcClient::new(&connection_config)
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::CallFunction {
+                account_id: token_account_id.clone(),
+                method_name: "ft_balance_of".to_string(),
+                args: near_primitives::types::FunctionArgs::from(
+                    serde_json::json!({ "account_id": account_id })
+                        .to_string()
+                        .into_bytes(),
+                ),
+            },
+        })
+        .await;
+    match query_view_method_response.ok()?.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::CallResult(result) => {
+            serde_json::from_slice::<String>(&result.result)
+                .ok()?
+                .parse()
+                .ok()
+        }
+        _ => None,
+    }
+}
+
+/// Pre-flight check before deleting an account: warns about the staked balance,
+/// the deployed contract and the NEP-141 tokens which will be lost, and asks
+/// to type the account ID to confirm the deletion.
+pub async fn confirm_account_deletion(
+    connection_config: Option<&ConnectionConfig>,
+    account_id: &near_primitives::types::AccountId,
+    skip_confirmation: bool,
+) -> crate::CliResult {
+    if let Some(connection_config) = connection_config {
+        if let Some(account_view) = get_account_state(connection_config, account_id.clone()).await?
+        {
+            if account_view.locked > 0 {
+                println!(
+                    "WARNING! The account <{}> has {} staked. The staked tokens will be lost.",
+                    account_id,
+                    NearBalance::from_yoctonear(account_view.locked)
+                );
+            }
+            if account_view.code_hash != CryptoHash::default() {
+                println!(
+                    "WARNING! The account <{}> has a deployed contract. The contract and its state will be deleted.",
+                    account_id
+                );
+            }
+        }
+        for token_account_id in get_token_list(connection_config)? {
+            match get_ft_balance(connection_config, &token_account_id, account_id).await {
+                Some(0) | None => {}
+                Some(balance) => println!(
+                    "WARNING! The account <{}> holds {} of the token <{}>. The tokens will be lost.",
+                    account_id, balance, token_account_id
+                ),
+            }
+        }
+    }
+    if skip_confirmation {
+        return Ok(());
+    }
+    let confirmation: String = dialoguer::Input::new()
+        .with_prompt(format!(
+            "To confirm the deletion, type the account ID <{}>",
+            account_id
+        ))
+        .allow_empty(true)
+        .require_interactive("--yes")?
+        .interact_text()
+        .unwrap();
+    if confirmation.trim() != account_id.to_string() {
+        return Err(color_eyre::Report::msg(format!(
+            "The account ID does not match. The account <{}> is not deleted.",
+            account_id
+        )));
+    }
+    Ok(())
+}
+
 /// Returns true if the account ID length is 64 characters and it's a hex representation. This is used to check the implicit account.
 pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
     let account_id = account_id.as_ref();
@@ -507,14 +725,33 @@ pub fn is_64_len_hex(account_id: impl AsRef<str>) -> bool {
 
 #[derive(Debug, Clone)]
 pub struct KeyPairProperties {
-    pub seed_phrase_hd_path: slip10::BIP32Path,
-    pub master_seed_phrase: String,
-    pub implicit_account_id: near_primitives::types::AccountId,
+    pub key_type: near_crypto::KeyType,
+    /// Only the ed25519 keys are derived from a seed phrase
+    pub seed_phrase_hd_path: Option<slip10::BIP32Path>,
+    pub master_seed_phrase: Option<String>,
+    /// Only the ed25519 keys have an implicit account
+    pub implicit_account_id: Option<near_primitives::types::AccountId>,
     pub public_key_str: String,
     pub secret_keypair_str: String,
 }
 
-pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
+pub async fn generate_keypair(
+    key_type: near_crypto::KeyType,
+) -> color_eyre::eyre::Result<KeyPairProperties> {
+    if key_type == near_crypto::KeyType::SECP256K1 {
+        // SLIP-10 derivation is only implemented for ed25519, so the secp256k1 keys are random
+        let secret_key = near_crypto::SecretKey::from_random(key_type);
+        let public_key_str = secret_key.public_key().to_string();
+        record_command_output("public_key", public_key_str.clone());
+        return Ok(KeyPairProperties {
+            key_type,
+            seed_phrase_hd_path: None,
+            master_seed_phrase: None,
+            implicit_account_id: None,
+            public_key_str,
+            secret_keypair_str: secret_key.to_string(),
+        });
+    }
     let generate_keypair: crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair =
         crate::commands::utils_command::generate_keypair_subcommand::CliGenerateKeypair::default();
     let (master_seed_phrase, master_seed) =
@@ -558,10 +795,13 @@ pub async fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
         "ed25519:{}",
         bs58::encode(secret_keypair.to_bytes()).into_string()
     );
+    record_command_output("public_key", public_key_str.clone());
+    record_command_output("implicit_account_id", implicit_account_id.to_string());
     let key_pair_properties: KeyPairProperties = KeyPairProperties {
-        seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
-        master_seed_phrase,
-        implicit_account_id,
+        key_type,
+        seed_phrase_hd_path: Some(generate_keypair.seed_phrase_hd_path),
+        master_seed_phrase: Some(master_seed_phrase),
+        implicit_account_id: Some(implicit_account_id),
         public_key_str,
         secret_keypair_str,
     };
@@ -575,14 +815,17 @@ pub fn print_transaction(transaction: near_primitives::transaction::Transaction)
     println!("{:<13} {}", "receiver_id:", &transaction.receiver_id);
     println!("{:<13} {}", "block_hash:", &transaction.block_hash);
     println!("actions:");
-    let actions = transaction.actions.clone();
-    for action in actions {
+    print_actions(&transaction.receiver_id, &transaction.actions);
+}
+
+pub fn print_actions(
+    receiver_id: &near_primitives::types::AccountId,
+    actions: &[near_primitives::transaction::Action],
+) {
+    for action in actions.iter().cloned() {
         match action {
             near_primitives::transaction::Action::CreateAccount(_) => {
-                println!(
-                    "{:>5} {:<20} {}",
-                    "--", "create account:", &transaction.receiver_id
-                )
+                println!("{:>5} {:<20} {}", "--", "create account:", receiver_id)
             }
             near_primitives::transaction::Action::DeployContract(_) => {
                 println!("{:>5} {:<20}", "--", "deploy contract")
@@ -656,10 +899,7 @@ pub fn print_transaction(transaction: near_primitives::transaction::Transaction)
                 );
             }
             near_primitives::transaction::Action::DeleteAccount(delete_account_action) => {
-                println!(
-                    "{:>5} {:<20} {}",
-                    "--", "delete account:", &transaction.receiver_id
-                );
+                println!("{:>5} {:<20} {}", "--", "delete account:", receiver_id);
                 println!(
                     "{:>5} {:<20} {}",
                     "", "beneficiary id:", &delete_account_action.beneficiary_id
@@ -933,12 +1173,17 @@ pub fn print_transaction_status(
         near_primitives::views::FinalExecutionStatus::NotStarted
         | near_primitives::views::FinalExecutionStatus::Started => unreachable!(),
         near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+            record_command_output("error", format!("{:?}", tx_execution_error));
             print_transaction_error(tx_execution_error)
         }
         near_primitives::views::FinalExecutionStatus::SuccessValue(_) => {
             print_value_successful_transaction(transaction_info.clone())
         }
     };
+    record_command_output(
+        "transaction_hash",
+        transaction_info.transaction_outcome.id.to_string(),
+    );
     let transaction_explorer: url::Url = match network_connection_config {
         Some(connection_config) => connection_config.transaction_explorer(),
         None => unreachable!("Error"),
@@ -949,20 +1194,78 @@ pub fn print_transaction_status(
     );
 }
 
+thread_local! {
+    /// The values produced by the executed command (the `run` command uses them as the step outputs)
+    static COMMAND_OUTPUTS: std::cell::RefCell<std::collections::HashMap<String, String>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+    /// The last nonce used for each access key within this process
+    static USED_NONCES: std::cell::RefCell<std::collections::HashMap<String, u64>> =
+        std::cell::RefCell::new(std::collections::HashMap::new());
+}
+
+pub fn record_command_output(name: &str, value: String) {
+    COMMAND_OUTPUTS.with(|outputs| outputs.borrow_mut().insert(name.to_owned(), value));
+}
+
+/// Returns the values recorded since the previous call
+pub fn take_command_outputs() -> std::collections::HashMap<String, String> {
+    COMMAND_OUTPUTS.with(|outputs| std::mem::take(&mut *outputs.borrow_mut()))
+}
+
+/// The nonce for the next transaction. The nonce fetched at `Final` may lag behind
+/// the transactions that were just sent with the same access key, so the nonces used
+/// earlier in this process are taken into account.
+pub fn next_nonce(
+    signer_id: &AccountId,
+    public_key: &near_crypto::PublicKey,
+    current_nonce: u64,
+) -> u64 {
+    let key = format!("{}:{}", signer_id, public_key);
+    USED_NONCES.with(|used_nonces| {
+        let mut used_nonces = used_nonces.borrow_mut();
+        let nonce = std::cmp::max(current_nonce, used_nonces.get(&key).copied().unwrap_or(0)) + 1;
+        used_nonces.insert(key, nonce);
+        nonce
+    })
+}
+
+/// The content of the keychain files of the access key
+fn keychain_key_data(
+    key_pair_properties: &crate::common::KeyPairProperties,
+    account_id: &str,
+    access_key_permission: Option<AccessKeyPermissionView>,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let mut key_data = serde_json::json!({
+        "account_id": account_id,
+        "key_type": key_pair_properties.key_type.to_string(),
+        "public_key": key_pair_properties.public_key_str,
+        "private_key": key_pair_properties.secret_keypair_str,
+    });
+    if let Some(master_seed_phrase) = &key_pair_properties.master_seed_phrase {
+        key_data["master_seed_phrase"] = serde_json::json!(master_seed_phrase);
+    }
+    if let Some(seed_phrase_hd_path) = &key_pair_properties.seed_phrase_hd_path {
+        key_data["seed_phrase_hd_path"] = serde_json::json!(seed_phrase_hd_path.to_string());
+    }
+    if let Some(access_key_permission) = access_key_permission {
+        key_data["permission"] = serde_json::to_value(access_key_permission)?;
+    }
+    Ok(key_data)
+}
+
 pub async fn save_access_key_to_keychain(
     network_connection_config: Option<crate::common::ConnectionConfig>,
     key_pair_properties: crate::common::KeyPairProperties,
     account_id: &str,
+    access_key_permission: Option<AccessKeyPermissionView>,
 ) -> crate::CliResult {
+    let is_function_call_key = match &access_key_permission {
+        Some(AccessKeyPermissionView::FunctionCall { .. }) => true,
+        _ => false,
+    };
     let buf = format!(
         "{}",
-        serde_json::json!({
-            "master_seed_phrase": key_pair_properties.master_seed_phrase,
-            "seed_phrase_hd_path": key_pair_properties.seed_phrase_hd_path.to_string(),
-            "account_id": account_id,
-            "public_key": key_pair_properties.public_key_str,
-            "private_key": key_pair_properties.secret_keypair_str,
-        })
+        keychain_key_data(&key_pair_properties, account_id, access_key_permission)?
     );
     let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
     let dir_name = match &network_connection_config {
@@ -992,7 +1295,12 @@ pub async fn save_access_key_to_keychain(
     let mut path_with_account_name = std::path::PathBuf::from(&home_dir);
     path_with_account_name.push(dir_name);
     path_with_account_name.push(file_with_account_name);
-    if path_with_account_name.exists() {
+    if is_function_call_key {
+        println!(
+            "The function-call access key is not saved as the default key in {}",
+            &path_with_account_name.display()
+        );
+    } else if path_with_account_name.exists() {
         println!(
             "The file: {} already exists! Therefore it was not overwritten.",
             &path_with_account_name.display()
@@ -1012,6 +1320,232 @@ pub async fn save_access_key_to_keychain(
     Ok(())
 }
 
+/// Writes the file next to it first and renames it over the target, so the target is never
+/// left half-written
+fn write_file_atomically(path: &std::path::Path, data: &str) -> crate::CliResult {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = std::path::PathBuf::from(tmp_path);
+    std::fs::write(&tmp_path, data).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to write the file {:?}: {}", &tmp_path, err))
+    })?;
+    std::fs::rename(&tmp_path, path).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to replace the file {:?}: {}", path, err))
+    })?;
+    Ok(())
+}
+
+/// Saves the full access key to `<keychain_dir>/<account_id>/<public_key>.json` without making it
+/// the default key of the account
+pub fn save_access_key_file(
+    keychain_dir: &std::path::Path,
+    key_pair_properties: &crate::common::KeyPairProperties,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<std::path::PathBuf> {
+    let data = format!(
+        "{}",
+        keychain_key_data(
+            key_pair_properties,
+            &account_id.to_string(),
+            Some(AccessKeyPermissionView::FullAccess)
+        )?
+    );
+    let account_keys_dir = keychain_dir.join(account_id.to_string());
+    std::fs::create_dir_all(&account_keys_dir)?;
+    let path = account_keys_dir.join(format!(
+        "{}.json",
+        key_pair_properties.public_key_str.replace(":", "_")
+    ));
+    write_file_atomically(&path, &data)?;
+    Ok(path)
+}
+
+/// Replaces the rotated full access key of the account in the keychain directory of the network
+/// (the files written by `save_access_key_to_keychain`). The previous files are kept with the
+/// `.<timestamp>.bak` suffix; the backup paths are returned.
+pub fn replace_access_key_in_keychain(
+    keychain_dir: &std::path::Path,
+    key_pair_properties: &crate::common::KeyPairProperties,
+    account_id: &near_primitives::types::AccountId,
+    old_public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<Vec<std::path::PathBuf>> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let backup_path = |path: &std::path::Path| {
+        let mut backup_path = path.as_os_str().to_owned();
+        backup_path.push(format!(".{}.bak", timestamp));
+        std::path::PathBuf::from(backup_path)
+    };
+    let mut backups = vec![];
+
+    let account_path = keychain_dir.join(format!("{}.json", account_id));
+    if account_path.exists() {
+        let account_backup_path = backup_path(&account_path);
+        std::fs::copy(&account_path, &account_backup_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to back up the file {:?}: {}",
+                &account_path, err
+            ))
+        })?;
+        backups.push(account_backup_path);
+    }
+    let key_path = save_access_key_file(keychain_dir, key_pair_properties, account_id)?;
+    write_file_atomically(&account_path, &std::fs::read_to_string(&key_path)?)?;
+
+    let old_key_path = keychain_dir.join(account_id.to_string()).join(format!(
+        "{}.json",
+        old_public_key.to_string().replace(":", "_")
+    ));
+    if old_key_path.exists() {
+        let old_key_backup_path = backup_path(&old_key_path);
+        std::fs::rename(&old_key_path, &old_key_backup_path)?;
+        backups.push(old_key_backup_path);
+    }
+    Ok(backups)
+}
+
+/// Finds the access key of the account in the keychain of any network
+pub fn get_secret_key_from_keychain(
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<near_crypto::SecretKey> {
+    #[derive(serde::Deserialize)]
+    struct KeychainAccessKey {
+        private_key: near_crypto::SecretKey,
+    }
+    let home_dir = dirs::home_dir().expect("Impossible to get your home dir!");
+    for dir_name in [
+        crate::consts::DIR_NAME_TESTNET,
+        crate::consts::DIR_NAME_MAINNET,
+        crate::consts::DIR_NAME_BETANET,
+        crate::consts::DIR_NAME_KEY_CHAIN,
+    ]
+    .iter()
+    {
+        let mut path = std::path::PathBuf::from(&home_dir);
+        path.push(dir_name);
+        path.push(format!("{}.json", account_id));
+        if path.exists() {
+            let data = std::fs::read_to_string(&path).map_err(|err| {
+                color_eyre::Report::msg(format!("Access key file not found! Error: {}", err))
+            })?;
+            let access_key: KeychainAccessKey = serde_json::from_str(&data)
+                .map_err(|err| color_eyre::Report::msg(format!("Error reading data: {}", err)))?;
+            return Ok(access_key.private_key);
+        }
+    }
+    Err(color_eyre::Report::msg(format!(
+        "There are no access keys found in the keychain for the account <{}>. Log in first.",
+        account_id
+    )))
+}
+
+/// Where a secret key comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum SecretKeySource {
+    /// Given in the command line, typed into a prompt or found by near-cli: it is redacted in the console command
+    Inline,
+    File(std::path::PathBuf),
+    Env(String),
+    Stdin,
+}
+
+impl std::fmt::Display for SecretKeySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "the command line"),
+            Self::File(path) => write!(f, "the file {:?}", path),
+            Self::Env(var) => write!(f, "the environment variable {}", var),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// Reads the secret key given with one of the options `<flag>`, `<flag>-file`, `<flag>-env` and `<flag>-stdin`.
+/// The file holds the key itself or a keychain access key (JSON with `private_key`).
+pub fn read_secret_key(
+    secret_key: Option<near_crypto::SecretKey>,
+    file: Option<std::path::PathBuf>,
+    env: Option<String>,
+    stdin: bool,
+) -> color_eyre::eyre::Result<Option<(near_crypto::SecretKey, SecretKeySource)>> {
+    let source = match (secret_key, file, env, stdin) {
+        (None, None, None, false) => return Ok(None),
+        (Some(secret_key), None, None, false) => {
+            return Ok(Some((secret_key, SecretKeySource::Inline)))
+        }
+        (None, Some(path), None, false) => SecretKeySource::File(path),
+        (None, None, Some(var), false) => SecretKeySource::Env(var),
+        (None, None, None, true) => SecretKeySource::Stdin,
+        _ => {
+            return Err(color_eyre::Report::msg(
+                "The secret key can be given in one way only: in the command line, in a file, in an environment variable or in stdin",
+            ))
+        }
+    };
+    let data = match &source {
+        SecretKeySource::File(path) => std::fs::read_to_string(path).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", path, err))
+        })?,
+        SecretKeySource::Env(var) => std::env::var(var).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to read the environment variable {}: {}",
+                var, err
+            ))
+        })?,
+        SecretKeySource::Stdin => {
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line)?;
+            line
+        }
+        SecretKeySource::Inline => unreachable!("An inline secret key is returned above"),
+    };
+    let data = data.trim();
+    let secret_key = match serde_json::from_str::<serde_json::Value>(data) {
+        Ok(serde_json::Value::Object(access_key)) => access_key
+            .get("private_key")
+            .and_then(|private_key| private_key.as_str())
+            .unwrap_or_default()
+            .parse::<near_crypto::SecretKey>(),
+        _ => data.parse::<near_crypto::SecretKey>(),
+    }
+    .map_err(|err| {
+        color_eyre::Report::msg(format!(
+            "Failed to parse the secret key from {}: {}",
+            source, err
+        ))
+    })?;
+    Ok(Some((secret_key, source)))
+}
+
+/// Returns true if a key with this permission can sign the transaction:
+/// a function-call key only signs a single call without deposit to its receiver and methods.
+pub fn is_access_key_suitable(
+    access_key_permission: &AccessKeyPermissionView,
+    transaction: &near_primitives::transaction::Transaction,
+) -> bool {
+    match access_key_permission {
+        AccessKeyPermissionView::FullAccess => true,
+        AccessKeyPermissionView::FunctionCall {
+            allowance: _,
+            receiver_id,
+            method_names,
+        } => {
+            transaction.receiver_id.to_string() == receiver_id.to_string()
+                && transaction.actions.len() == 1
+                && transaction.actions.iter().all(|action| match action {
+                    near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                        function_call_action.deposit == 0
+                            && (method_names.is_empty()
+                                || method_names.contains(&function_call_action.method_name))
+                    }
+                    _ => false,
+                })
+        }
+    }
+}
+
 pub fn try_external_subcommand_execution() -> CliResult {
     let (subcommand, args) = {
         let mut args = std::env::args().skip(1);
@@ -1077,7 +1611,7 @@ pub async fn display_account_info(
     conf: &ConnectionConfig,
     block_ref: BlockReference,
 ) -> crate::CliResult {
-    let resp = new_client(&conf.archival_rpc_url().as_str())
+    let resp = crate::rpc::RpcClient::archival(&conf)
         .query(RpcQueryRequest {
             block_reference: block_ref,
             request: QueryRequest::ViewAccount {
@@ -1123,7 +1657,7 @@ pub async fn display_access_key_list(
     conf: &ConnectionConfig,
     block_ref: BlockReference,
 ) -> crate::CliResult {
-    let resp = new_client(&conf.archival_rpc_url().as_str())
+    let resp = crate::rpc::RpcClient::archival(&conf)
         .query(RpcQueryRequest {
             block_reference: block_ref,
             request: QueryRequest::ViewAccessKeyList { account_id },
@@ -1165,9 +1699,10 @@ pub async fn display_access_key_list(
         };
 
         println!(
-            "{: >4}. {} (nonce: {}) is granted to {}",
+            "{: >4}. {} ({}, nonce: {}) is granted to {}",
             index + 1,
             access_key.public_key,
+            access_key.public_key.key_type(),
             access_key.access_key.nonce,
             permissions_message
         );
@@ -1175,6 +1710,365 @@ pub async fn display_access_key_list(
     Ok(())
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractMethodKind {
+    View,
+    Change,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractMethod {
+    pub name: String,
+    pub kind: ContractMethodKind,
+    pub arg_names: Vec<String>,
+}
+
+impl std::fmt::Display for ContractMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            ContractMethodKind::View => "view",
+            ContractMethodKind::Change => "change",
+            ContractMethodKind::Unknown => "view or change",
+        };
+        write!(f, "{}({}) [{}]", self.name, self.arg_names.join(", "), kind)
+    }
+}
+
+impl ContractMethod {
+    /// Returns the JSON object with the argument names of the method as keys (used as a template for the method args)
+    pub fn args_template(&self) -> Option<String> {
+        if self.arg_names.is_empty() {
+            return None;
+        }
+        let args: serde_json::Map<String, serde_json::Value> = self
+            .arg_names
+            .iter()
+            .map(|arg_name| (arg_name.clone(), serde_json::Value::String(String::new())))
+            .collect();
+        Some(serde_json::Value::Object(args).to_string())
+    }
+}
+
+/// Contract metadata (ABI) file:
+/// {"methods": [{"name": "get_status", "is_view": true, "args": ["account_id"]}]}
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct ContractAbi {
+    #[serde(default)]
+    pub methods: Vec<ContractAbiMethod>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ContractAbiMethod {
+    pub name: String,
+    #[serde(default)]
+    pub is_view: bool,
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+impl ContractAbi {
+    pub fn from_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(file_path).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to read the contract ABI file {:?}: {}",
+                file_path, err
+            ))
+        })?;
+        serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("The contract ABI file is not valid: {}", err))
+        })
+    }
+}
+
+fn read_wasm_u32(wasm: &[u8], position: &mut usize) -> Result<u32, String> {
+    let mut result: u32 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *wasm
+            .get(*position)
+            .ok_or_else(|| "Wasm: unexpected end of the file".to_string())?;
+        *position += 1;
+        if shift > 28 {
+            return Err("Wasm: invalid LEB128 number".to_string());
+        }
+        result |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Returns the names of all functions exported from the wasm module (these are the contract methods)
+pub fn get_wasm_exported_functions(wasm: &[u8]) -> Result<Vec<String>, String> {
+    if wasm.len() < 8 || &wasm[0..4] != b"\0asm" {
+        return Err("Wasm: the file is not a WebAssembly module".to_string());
+    }
+    let mut position = 8;
+    let mut functions = vec![];
+    while position < wasm.len() {
+        let section_id = wasm[position];
+        position += 1;
+        let section_size = read_wasm_u32(wasm, &mut position)? as usize;
+        let section_end = position
+            .checked_add(section_size)
+            .filter(|section_end| *section_end <= wasm.len())
+            .ok_or_else(|| "Wasm: section size is out of bounds".to_string())?;
+        // 7 is the id of the export section
+        if section_id == 7 {
+            let exports_count = read_wasm_u32(wasm, &mut position)?;
+            for _ in 0..exports_count {
+                let name_len = read_wasm_u32(wasm, &mut position)? as usize;
+                let name = wasm
+                    .get(position..position + name_len)
+                    .ok_or_else(|| "Wasm: export name is out of bounds".to_string())?;
+                let name = String::from_utf8(name.to_vec())
+                    .map_err(|err| format!("Wasm: export name is not valid UTF-8: {}", err))?;
+                position += name_len;
+                let export_kind = *wasm
+                    .get(position)
+                    .ok_or_else(|| "Wasm: unexpected end of the file".to_string())?;
+                position += 1;
+                read_wasm_u32(wasm, &mut position)?;
+                // 0 is the kind of the exported function
+                if export_kind == 0 {
+                    functions.push(name);
+                }
+            }
+        }
+        position = section_end;
+    }
+    Ok(functions)
+}
+
+pub fn get_contract_methods(
+    wasm: &[u8],
+    contract_abi: Option<&ContractAbi>,
+) -> color_eyre::eyre::Result<Vec<ContractMethod>> {
+    let functions = get_wasm_exported_functions(wasm).map_err(color_eyre::Report::msg)?;
+    Ok(functions
+        .into_iter()
+        .map(|name| {
+            match contract_abi.and_then(|abi| abi.methods.iter().find(|method| method.name == name))
+            {
+                Some(abi_method) => ContractMethod {
+                    name,
+                    kind: if abi_method.is_view {
+                        ContractMethodKind::View
+                    } else {
+                        ContractMethodKind::Change
+                    },
+                    arg_names: abi_method.args.clone(),
+                },
+                None => ContractMethod {
+                    name,
+                    kind: ContractMethodKind::Unknown,
+                    arg_names: vec![],
+                },
+            }
+        })
+        .collect())
+}
+
+/// Without the contract code only the methods described in the ABI are known
+pub fn get_contract_methods_from_abi(contract_abi: &ContractAbi) -> Vec<ContractMethod> {
+    contract_abi
+        .methods
+        .iter()
+        .map(|abi_method| ContractMethod {
+            name: abi_method.name.clone(),
+            kind: if abi_method.is_view {
+                ContractMethodKind::View
+            } else {
+                ContractMethodKind::Change
+            },
+            arg_names: abi_method.args.clone(),
+        })
+        .collect()
+}
+
+pub async fn fetch_contract_code(
+    connection_config: &ConnectionConfig,
+    contract_account_id: near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let query_view_code_response = crate::rpc::RpcClient::new(&connection_config)
+        .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+            block_reference: near_primitives::types::Finality::Final.into(),
+            request: near_primitives::views::QueryRequest::ViewCode {
+                account_id: contract_account_id,
+            },
+        })
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to fetch query for view contract: {:?}",
+                err
+            ))
+        })?;
+    match query_view_code_response.kind {
+        near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) => {
+            Ok(result.code)
+        }
+        _ => Err(color_eyre::Report::msg("Error call result")),
+    }
+}
+
+/// Collects the contract methods from the ABI file, the contract code is only checked when the
+/// command is processed. Returns an empty list if there is no ABI file (the caller falls back to a manual input).
+pub fn find_contract_methods(abi_file: Option<&std::path::Path>) -> Vec<ContractMethod> {
+    match abi_file.map(ContractAbi::from_file) {
+        Some(Ok(contract_abi)) => get_contract_methods_from_abi(&contract_abi),
+        Some(Err(err)) => {
+            println!("{}", err);
+            vec![]
+        }
+        None => vec![],
+    }
+}
+
+/// Checks that the contract code on the network exports the method and lists the exported
+/// methods if it does not. A contract code that can't be parsed is not checked.
+pub async fn verify_contract_method(
+    connection_config: &ConnectionConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    method_name: &str,
+) -> CliResult {
+    let code = fetch_contract_code(connection_config, contract_account_id.clone()).await?;
+    let methods = match get_contract_methods(&code, None) {
+        Ok(methods) => methods,
+        Err(err) => {
+            println!("Failed to parse the contract code: {}", err);
+            return Ok(());
+        }
+    };
+    if methods.iter().any(|method| method.name == method_name) {
+        return Ok(());
+    }
+    Err(color_eyre::Report::msg(format!(
+        "The contract <{}> has no method `{}`. The methods of the contract:\n{}",
+        contract_account_id,
+        method_name,
+        methods
+            .iter()
+            .map(|method| format!("  {}", method))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )))
+}
+
+/// Offers the contract methods as a selectable list. Methods known (from ABI) to be of the other kind are not shown.
+/// Returns None if the user wants to type the method name manually.
+pub fn select_contract_method(
+    methods: &[ContractMethod],
+    excluded_kind: ContractMethodKind,
+) -> color_eyre::eyre::Result<Option<ContractMethod>> {
+    let methods: Vec<&ContractMethod> = methods
+        .iter()
+        .filter(|method| method.kind != excluded_kind)
+        .collect();
+    if methods.is_empty() {
+        return Ok(None);
+    }
+    let mut items: Vec<String> = methods.iter().map(|method| method.to_string()).collect();
+    items.push("Enter a method name manually".to_string());
+    println!();
+    let selection = dialoguer::Select::with_theme(&dialoguer::theme::ColorfulTheme::default())
+        .with_prompt("Choose a method of the contract")
+        .items(&items)
+        .default(0)
+        .require_interactive("<method-name>")?
+        .interact()
+        .unwrap();
+    Ok(methods.get(selection).map(|method| (*method).clone()))
+}
+
+/// Turns the path and the query of a browser redirect into the captured value and the page
+/// shown in the browser; `None` for the paths the server does not serve
+pub type CallbackHandler<T> =
+    fn(&str, &std::collections::HashMap<String, String>) -> Option<(T, actix_web::HttpResponse)>;
+
+struct CallbackState<T> {
+    handler: CallbackHandler<T>,
+    callback: std::sync::Mutex<Option<T>>,
+}
+
+/// One-shot localhost server which captures the wallet redirect to one of its URLs
+pub struct CallbackServer<T: Send + 'static> {
+    port: u16,
+    server: actix_web::dev::Server,
+    state: actix_web::web::Data<CallbackState<T>>,
+}
+
+impl<T: Send + 'static> CallbackServer<T> {
+    /// Starts the server on a free port of 127.0.0.1
+    pub fn start(handler: CallbackHandler<T>) -> color_eyre::eyre::Result<Self> {
+        let state = actix_web::web::Data::new(CallbackState {
+            handler,
+            callback: std::sync::Mutex::new(None),
+        });
+        let app_state = state.clone();
+        let http_server = actix_web::HttpServer::new(move || {
+            actix_web::App::new().app_data(app_state.clone()).route(
+                "/{path:.*}",
+                actix_web::web::get().to(capture_callback::<T>),
+            )
+        })
+        .workers(1)
+        .disable_signals()
+        .bind(("127.0.0.1", 0))?;
+        let port = match http_server.addrs().first() {
+            Some(address) => address.port(),
+            None => {
+                return Err(color_eyre::Report::msg(
+                    "The local server is not bound to any address".to_string(),
+                ))
+            }
+        };
+        Ok(Self {
+            port,
+            server: http_server.run(),
+            state,
+        })
+    }
+
+    pub fn url(&self, path: &str) -> url::Url {
+        format!("http://127.0.0.1:{}{}", self.port, path)
+            .parse()
+            .unwrap()
+    }
+
+    /// Waits for the wallet redirect; returns None on timeout
+    pub async fn wait(self, timeout: std::time::Duration) -> Option<T> {
+        let started_at = std::time::Instant::now();
+        let callback = loop {
+            if let Some(callback) = self.state.callback.lock().unwrap().take() {
+                break Some(callback);
+            }
+            if started_at.elapsed() >= timeout {
+                break None;
+            }
+            actix::clock::sleep(std::time::Duration::from_millis(100)).await;
+        };
+        self.server.stop(true).await;
+        callback
+    }
+}
+
+async fn capture_callback<T: Send + 'static>(
+    request: actix_web::HttpRequest,
+    query: actix_web::web::Query<std::collections::HashMap<String, String>>,
+    state: actix_web::web::Data<CallbackState<T>>,
+) -> actix_web::HttpResponse {
+    match (state.handler)(request.path(), &query) {
+        Some((callback, response)) => {
+            *state.callback.lock().unwrap() = Some(callback);
+            response
+        }
+        None => actix_web::HttpResponse::NotFound().finish(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1530,4 +2424,229 @@ mod tests {
             Err("Near Gas: invalid digit found in string".to_string())
         );
     }
+
+    const WASM_WITH_EXPORTS: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x07, 0x10, 0x02, // export section with 2 exports
+        0x03, b'g', b'e', b't', 0x00, 0x00, // function "get"
+        0x06, b'm', b'e', b'm', b'o', b'r', b'y', 0x02, 0x00, // memory "memory"
+    ];
+
+    #[test]
+    fn wasm_exported_functions() {
+        assert_eq!(
+            get_wasm_exported_functions(WASM_WITH_EXPORTS),
+            Ok(vec!["get".to_string()])
+        );
+    }
+    #[test]
+    fn wasm_exported_functions_not_wasm() {
+        assert_eq!(
+            get_wasm_exported_functions(b"not a wasm file"),
+            Err("Wasm: the file is not a WebAssembly module".to_string())
+        );
+    }
+    #[test]
+    fn wasm_exported_functions_truncated() {
+        assert_eq!(
+            get_wasm_exported_functions(&WASM_WITH_EXPORTS[..20]),
+            Err("Wasm: section size is out of bounds".to_string())
+        );
+    }
+    #[test]
+    fn contract_methods_with_abi() {
+        let contract_abi: ContractAbi = serde_json::from_str(
+            r#"{"methods": [{"name": "get", "is_view": true, "args": ["key"]}]}"#,
+        )
+        .unwrap();
+        let methods = get_contract_methods(WASM_WITH_EXPORTS, Some(&contract_abi)).unwrap();
+        assert_eq!(
+            methods,
+            vec![ContractMethod {
+                name: "get".to_string(),
+                kind: ContractMethodKind::View,
+                arg_names: vec!["key".to_string()],
+            }]
+        );
+        assert_eq!(
+            methods[0].args_template(),
+            Some(r#"{"key":""}"#.to_string())
+        );
+    }
+    #[test]
+    fn next_nonce_for_consecutive_transactions() {
+        let signer_id: AccountId = "alice.testnet".to_string().try_into().unwrap();
+        let public_key = near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519);
+        assert_eq!(next_nonce(&signer_id, &public_key, 10), 11);
+        assert_eq!(next_nonce(&signer_id, &public_key, 10), 12);
+        assert_eq!(next_nonce(&signer_id, &public_key, 20), 21);
+    }
+    #[test]
+    fn function_call_key_is_suitable_for_matching_calls() {
+        let function_call = |method_name: &str, deposit: u128| {
+            near_primitives::transaction::Action::FunctionCall(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args: vec![],
+                    gas: 100_000_000_000_000,
+                    deposit,
+                },
+            )
+        };
+        let transaction = |receiver_id: &str, actions| near_primitives::transaction::Transaction {
+            signer_id: "alice.testnet".to_string().try_into().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 0,
+            receiver_id: receiver_id.to_string().try_into().unwrap(),
+            block_hash: Default::default(),
+            actions,
+        };
+        let permission = AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "app.testnet".to_string().try_into().unwrap(),
+            method_names: vec!["vote".to_string()],
+        };
+        assert!(is_access_key_suitable(
+            &permission,
+            &transaction("app.testnet", vec![function_call("vote", 0)])
+        ));
+        assert!(!is_access_key_suitable(
+            &permission,
+            &transaction("app.testnet", vec![function_call("withdraw", 0)])
+        ));
+        assert!(!is_access_key_suitable(
+            &permission,
+            &transaction("app.testnet", vec![function_call("vote", 1)])
+        ));
+        assert!(!is_access_key_suitable(
+            &permission,
+            &transaction("other.testnet", vec![function_call("vote", 0)])
+        ));
+        assert!(!is_access_key_suitable(
+            &permission,
+            &transaction(
+                "app.testnet",
+                vec![function_call("vote", 0), function_call("vote", 0)]
+            )
+        ));
+        assert!(is_access_key_suitable(
+            &AccessKeyPermissionView::FullAccess,
+            &transaction("other.testnet", vec![function_call("withdraw", 1)])
+        ));
+    }
+
+    #[test]
+    fn read_secret_key_from_env() {
+        let secret_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice.testnet");
+        std::env::set_var("NEAR_CLI_TEST_SECRET_KEY", secret_key.to_string());
+        assert_eq!(
+            read_secret_key(
+                None,
+                None,
+                Some("NEAR_CLI_TEST_SECRET_KEY".to_string()),
+                false
+            )
+            .unwrap(),
+            Some((
+                secret_key.clone(),
+                SecretKeySource::Env("NEAR_CLI_TEST_SECRET_KEY".to_string())
+            ))
+        );
+        assert!(read_secret_key(
+            Some(secret_key),
+            None,
+            Some("NEAR_CLI_TEST_SECRET_KEY".to_string()),
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn generate_secp256k1_keypair() {
+        let key_pair_properties = actix::System::new()
+            .block_on(generate_keypair(near_crypto::KeyType::SECP256K1))
+            .unwrap();
+        assert_eq!(
+            key_pair_properties.key_type,
+            near_crypto::KeyType::SECP256K1
+        );
+        assert!(key_pair_properties.implicit_account_id.is_none());
+        let secret_key =
+            near_crypto::SecretKey::from_str(&key_pair_properties.secret_keypair_str).unwrap();
+        assert_eq!(
+            secret_key.public_key().to_string(),
+            key_pair_properties.public_key_str
+        );
+        assert_eq!(
+            secret_key.public_key().key_type(),
+            near_crypto::KeyType::SECP256K1
+        );
+    }
+
+    #[test]
+    fn replace_rotated_access_key_in_keychain() {
+        let keychain_dir = std::env::temp_dir().join(format!(
+            "near-cli-keychain-{}-{}",
+            std::process::id(),
+            rand::random::<u32>()
+        ));
+        let account_id = near_primitives::types::AccountId::from_str("alice.testnet").unwrap();
+        let old_secret_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "old");
+        let old_public_key = old_secret_key.public_key();
+        let old_data = serde_json::json!({
+            "account_id": "alice.testnet",
+            "public_key": old_public_key.to_string(),
+            "private_key": old_secret_key.to_string(),
+        })
+        .to_string();
+        let account_keys_dir = keychain_dir.join("alice.testnet");
+        std::fs::create_dir_all(&account_keys_dir).unwrap();
+        let old_key_path = account_keys_dir.join(format!(
+            "{}.json",
+            old_public_key.to_string().replace(":", "_")
+        ));
+        std::fs::write(keychain_dir.join("alice.testnet.json"), &old_data).unwrap();
+        std::fs::write(&old_key_path, &old_data).unwrap();
+
+        let new_secret_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "new");
+        let key_pair_properties = KeyPairProperties {
+            key_type: near_crypto::KeyType::ED25519,
+            seed_phrase_hd_path: None,
+            master_seed_phrase: None,
+            implicit_account_id: None,
+            public_key_str: new_secret_key.public_key().to_string(),
+            secret_keypair_str: new_secret_key.to_string(),
+        };
+        let backups = replace_access_key_in_keychain(
+            &keychain_dir,
+            &key_pair_properties,
+            &account_id,
+            &old_public_key,
+        )
+        .unwrap();
+
+        let account_data: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(keychain_dir.join("alice.testnet.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            account_data["private_key"],
+            serde_json::json!(new_secret_key.to_string())
+        );
+        assert!(account_keys_dir
+            .join(format!(
+                "{}.json",
+                key_pair_properties.public_key_str.replace(":", "_")
+            ))
+            .exists());
+        assert!(!old_key_path.exists());
+        assert_eq!(backups.len(), 2);
+        for backup in &backups {
+            assert_eq!(std::fs::read_to_string(backup).unwrap(), old_data);
+        }
+        std::fs::remove_dir_all(&keychain_dir).unwrap();
+    }
 }
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+thread_local! {
+    static CONFIG: std::cell::RefCell<Config> = std::cell::RefCell::new(Config::default());
+}
+
+/// Settings of near-cli stored in ~/.near-cli/config.json
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Config {
+    /// Ledger HD paths saved for the account IDs
+    #[serde(default)]
+    pub ledger_hd_paths: std::collections::BTreeMap<String, String>,
+    #[serde(default)]
+    pub rpc: RpcConfig,
+    /// The relayer endpoints `utils relay-delegate-action` posts to, by network
+    #[serde(default)]
+    pub relayer_urls: std::collections::BTreeMap<String, url::Url>,
+}
+
+/// Settings of the RPC layer
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RpcConfig {
+    /// The RPC endpoints in the order they are tried, by network (testnet, mainnet, betanet or the URL of a custom network)
+    #[serde(default)]
+    pub endpoints: std::collections::BTreeMap<String, Vec<url::Url>>,
+    /// The archival RPC endpoints in the order they are tried, by network
+    #[serde(default)]
+    pub archival_endpoints: std::collections::BTreeMap<String, Vec<url::Url>>,
+    /// The timeout of a single RPC request
+    pub timeout_secs: Option<u64>,
+    /// How many times a failed query is retried
+    pub max_retries: Option<u32>,
+}
+
+impl Config {
+    fn path() -> std::path::PathBuf {
+        let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+        path.push(crate::consts::FILE_NAME_CONFIG);
+        path
+    }
+
+    pub fn load() -> color_eyre::eyre::Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(&path).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", &path, err))
+        })?;
+        serde_json::from_str(&data).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", &path, err))
+        })
+    }
+
+    pub fn save(&self) -> crate::CliResult {
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)?).map_err(|err| {
+            color_eyre::Report::msg(format!("Failed to write the file {:?}: {}", &path, err))
+        })?;
+        Ok(())
+    }
+
+    pub fn ledger_hd_path(
+        &self,
+        account_id: &near_primitives::types::AccountId,
+    ) -> Option<slip10::BIP32Path> {
+        self.ledger_hd_paths
+            .get(&account_id.to_string())
+            .and_then(|seed_phrase_hd_path| slip10::BIP32Path::from_str(seed_phrase_hd_path).ok())
+    }
+
+    pub fn set_ledger_hd_path(
+        &mut self,
+        account_id: &near_primitives::types::AccountId,
+        seed_phrase_hd_path: &slip10::BIP32Path,
+    ) {
+        self.ledger_hd_paths
+            .insert(account_id.to_string(), seed_phrase_hd_path.to_string());
+    }
+}
+
+/// Reads the settings once for the rest of the command
+pub fn init() -> crate::CliResult {
+    let config = Config::load()?;
+    CONFIG.with(|cached| *cached.borrow_mut() = config);
+    Ok(())
+}
+
+pub fn get() -> Config {
+    CONFIG.with(|config| config.borrow().clone())
+}
+
+/// Saves the settings and keeps them for the rest of the command
+pub fn set(config: Config) -> crate::CliResult {
+    config.save()?;
+    CONFIG.with(|cached| *cached.borrow_mut() = config);
+    Ok(())
+}
+
+/// The relayer endpoint configured for the network
+pub fn get_relayer_url(
+    network_connection_config: &crate::common::ConnectionConfig,
+) -> Option<url::Url> {
+    get()
+        .relayer_urls
+        .get(&crate::journal::network_name(network_connection_config))
+        .cloned()
+}
+
+/// Returns the Ledger HD path saved for the account with `near ledger accounts`
+pub fn get_saved_ledger_hd_path(
+    account_id: &near_primitives::types::AccountId,
+) -> Option<slip10::BIP32Path> {
+    get().ledger_hd_path(account_id)
+}
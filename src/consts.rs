@@ -19,3 +19,82 @@ pub const DIR_NAME_TESTNET: &str = ".near-credentials/testnet/";
 pub const DIR_NAME_MAINNET: &str = ".near-credentials/mainnet/";
 pub const DIR_NAME_BETANET: &str = ".near-credentials/betanet/";
 pub const DIR_NAME_CUSTOM: &str = ".near-credentials/default/";
+
+pub const DIR_NAME_TEMPLATES: &str = ".near-cli/templates/";
+
+pub const FILE_NAME_TOKEN_LIST: &str = ".near-cli/tokens.json";
+pub const TESTNET_TOKEN_LIST: &[&str] = &["wrap.testnet", "usdc.fakes.testnet"];
+pub const MAINNET_TOKEN_LIST: &[&str] = &[
+    "wrap.near",
+    "usdt.tether-token.near",
+    "17208628f84f5d6ad33f0da3bbbeb27ffcb398eac501a31bd6ad2011e36133a1",
+    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48.factory.bridge.near",
+    "token.sweat",
+];
+
+pub const LOGIN_CALLBACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+/// How long to wait for the wallet to redirect back after approving (or rejecting) a transaction
+pub const WALLET_SIGN_CALLBACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+pub const IMPLICIT_ACCOUNT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+pub const IMPLICIT_ACCOUNT_WAIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+/// How long `keys rotate` waits for the new key to be in a final block
+pub const ACCESS_KEY_ROTATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+pub const ACCESS_KEY_ROTATION_POLL_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(2);
+
+pub const TESTNET_HELPER_URL: &str = "https://helper.testnet.near.org";
+pub const MAINNET_HELPER_URL: &str = "https://helper.mainnet.near.org";
+pub const BETANET_HELPER_URL: &str = "https://helper.betanet.near.org";
+pub const ACCOUNT_HELPER_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+pub const CREATE_ACCOUNT_GAS: near_primitives::types::Gas = 30_000_000_000_000;
+
+/// The prefix of the signed delegate action message (NEP-461): 2^30 + the NEP number 366
+pub const DELEGATE_ACTION_MESSAGE_PREFIX: u32 = (1 << 30) + 366;
+/// For how many blocks a delegate action stays valid unless --max-block-height is given
+pub const DELEGATE_ACTION_TTL: near_primitives::types::BlockHeightDelta = 1000;
+pub const RELAYER_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+pub const TESTNET_LINKDROP_ACCOUNT_ID: &str = "testnet";
+pub const MAINNET_LINKDROP_ACCOUNT_ID: &str = "near";
+pub const BETANET_LINKDROP_ACCOUNT_ID: &str = "betanet";
+/// The linkdrop contract keeps this amount of every drop as the allowance of the claim key
+pub const LINKDROP_ACCESS_KEY_ALLOWANCE: near_primitives::types::Balance =
+    1_000_000_000_000_000_000_000_000;
+pub const LINKDROP_SEND_GAS: near_primitives::types::Gas = 10_000_000_000_000;
+pub const LINKDROP_CLAIM_GAS: near_primitives::types::Gas = 100_000_000_000_000;
+pub const DIR_NAME_LINKDROPS: &str = ".near-cli/linkdrops/";
+
+pub const DEFAULT_LEDGER_HD_PATH: &str = "44'/397'/0'/0'/1'";
+pub const FILE_NAME_CONFIG: &str = ".near-cli/config.json";
+pub const FILE_NAME_HISTORY: &str = ".near-cli/history.jsonl";
+/// The project defaults looked up in the working directory and its parents
+pub const FILE_NAME_PROJECT_CONFIG: &str = ".near-cli.toml";
+/// Transactions referring to a block older than this number of blocks are rejected by the network
+pub const TRANSACTION_VALIDITY_PERIOD: near_primitives::types::BlockHeightDelta = 86400;
+/// The protocol limits of a transaction (`max_actions_per_receipt` and `max_transaction_size`)
+pub const MAX_ACTIONS_PER_TRANSACTION: usize = 100;
+pub const MAX_TRANSACTION_SIZE: u64 = 4 * 1024 * 1024;
+/// The most gas all the function calls of a transaction can attach
+pub const MAX_TRANSACTION_GAS: near_primitives::types::Gas = 300_000_000_000_000;
+
+pub const RPC_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+pub const RPC_MAX_RETRIES: u32 = 3;
+/// The delay before the first retry of an RPC query, doubled for every next one
+pub const RPC_RETRY_INITIAL_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+pub const RPC_RETRY_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(8);
+
+/// The exit code when an argument is missing in the non-interactive mode
+pub const EXIT_CODE_MISSING_ARGUMENT: i32 = 3;
+
+/// The environment variable with the default for the global `--network` option
+pub const ENV_NEAR_NETWORK: &str = "NEAR_NETWORK";
+
+/// The environment variable with the default for the global `--signer` option
+pub const ENV_NEAR_SIGNER: &str = "NEAR_SIGNER";
+
+/// The template and plan placeholder for the secret key left out of the console command
+pub const SECRET_KEY_PLACEHOLDER: &str = "signer_private_key";
+
+/// Shown in the console command instead of a secret given in the command line or typed into a prompt;
+/// a template or a plan step with it is replayed with `--set signer_private_key=...`
+pub const REDACTED_SECRET: &str = "{{signer_private_key}}";
@@ -0,0 +1,487 @@
+use near_primitives::borsh::{BorshDeserialize, BorshSerialize};
+
+/// The options of `construct-transaction --as-delegate`: the transaction actions are signed as
+/// a delegate action (NEP-366) to be sent by a relayer instead of a transaction
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DelegateActionOptions {
+    /// The block height the delegate action expires at; defaults to the current one plus
+    /// `DELEGATE_ACTION_TTL` in the online mode
+    pub max_block_height: Option<near_primitives::types::BlockHeight>,
+}
+
+thread_local! {
+    static DELEGATE_ACTION_OPTIONS: std::cell::RefCell<Option<DelegateActionOptions>> = std::cell::RefCell::new(None);
+}
+
+pub fn set_options(options: Option<DelegateActionOptions>) {
+    DELEGATE_ACTION_OPTIONS
+        .with(|delegate_action_options| *delegate_action_options.borrow_mut() = options);
+}
+
+/// None unless the transaction is constructed as a delegate action
+pub fn options() -> Option<DelegateActionOptions> {
+    DELEGATE_ACTION_OPTIONS.with(|delegate_action_options| delegate_action_options.borrow().clone())
+}
+
+// The pinned near-primitives predates NEP-366, so the types below follow its layout. The Borsh
+// impls are written by hand since the derive macros need the `borsh` crate itself.
+
+/// The actions the relayer executes on behalf of the sender (NEP-366)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelegateAction {
+    pub sender_id: near_primitives::types::AccountId,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<near_primitives::transaction::Action>,
+    /// The nonce of the sender access key
+    pub nonce: near_primitives::types::Nonce,
+    pub max_block_height: near_primitives::types::BlockHeight,
+    pub public_key: near_crypto::PublicKey,
+}
+
+impl BorshSerialize for DelegateAction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.sender_id.serialize(writer)?;
+        self.receiver_id.serialize(writer)?;
+        self.actions.serialize(writer)?;
+        self.nonce.serialize(writer)?;
+        self.max_block_height.serialize(writer)?;
+        self.public_key.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for DelegateAction {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self {
+            sender_id: BorshDeserialize::deserialize(buf)?,
+            receiver_id: BorshDeserialize::deserialize(buf)?,
+            actions: BorshDeserialize::deserialize(buf)?,
+            nonce: BorshDeserialize::deserialize(buf)?,
+            max_block_height: BorshDeserialize::deserialize(buf)?,
+            public_key: BorshDeserialize::deserialize(buf)?,
+        })
+    }
+}
+
+impl DelegateAction {
+    /// The hash that is signed: the delegate action prefixed as a NEP-461 message
+    pub fn hash(&self) -> near_primitives::hash::CryptoHash {
+        let mut bytes = crate::consts::DELEGATE_ACTION_MESSAGE_PREFIX
+            .try_to_vec()
+            .expect("The prefix is not expected to fail on serialization");
+        bytes.extend(
+            self.try_to_vec()
+                .expect("Delegate action is not expected to fail on serialization"),
+        );
+        near_primitives::hash::hash(&bytes)
+    }
+
+    pub fn sign(self, signer_private_key: &near_crypto::SecretKey) -> SignedDelegateAction {
+        let signature = signer_private_key.sign(self.hash().as_ref());
+        SignedDelegateAction {
+            delegate_action: self,
+            signature,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedDelegateAction {
+    pub delegate_action: DelegateAction,
+    pub signature: near_crypto::Signature,
+}
+
+impl BorshSerialize for SignedDelegateAction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.delegate_action.serialize(writer)?;
+        self.signature.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for SignedDelegateAction {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        Ok(Self {
+            delegate_action: BorshDeserialize::deserialize(buf)?,
+            signature: BorshDeserialize::deserialize(buf)?,
+        })
+    }
+}
+
+impl SignedDelegateAction {
+    pub fn verify(&self) -> bool {
+        self.signature.verify(
+            self.delegate_action.hash().as_ref(),
+            &self.delegate_action.public_key,
+        )
+    }
+
+    pub fn to_base64(&self) -> String {
+        near_primitives::serialize::to_base64(
+            self.try_to_vec()
+                .expect("Delegate action is not expected to fail on serialization"),
+        )
+    }
+
+    pub fn from_base64(data: &str) -> color_eyre::eyre::Result<Self> {
+        let bytes = near_primitives::serialize::from_base64(data).map_err(|err| {
+            color_eyre::Report::msg(format!("The signed delegate action is not base64: {}", err))
+        })?;
+        Self::try_from_slice(&bytes).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to parse the signed delegate action: {}",
+                err
+            ))
+        })
+    }
+}
+
+/// The transaction of the relayer with the single `Delegate` action. `Transaction` of
+/// near-primitives has no such action, so it's serialized here with the same layout.
+#[derive(Debug, Clone)]
+struct RelayerTransaction {
+    signer_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    nonce: near_primitives::types::Nonce,
+    receiver_id: near_primitives::types::AccountId,
+    block_hash: near_primitives::hash::CryptoHash,
+    signed_delegate_action: SignedDelegateAction,
+}
+
+impl BorshSerialize for RelayerTransaction {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.signer_id.serialize(writer)?;
+        self.public_key.serialize(writer)?;
+        self.nonce.serialize(writer)?;
+        self.receiver_id.serialize(writer)?;
+        self.block_hash.serialize(writer)?;
+        // A single action: the index of `Action::Delegate` and the signed delegate action
+        1u32.serialize(writer)?;
+        8u8.serialize(writer)?;
+        self.signed_delegate_action.serialize(writer)
+    }
+}
+
+/// The base64-encoded transaction of the relayer which executes the signed delegate action,
+/// and its hash
+pub fn sign_relayer_transaction(
+    signed_delegate_action: SignedDelegateAction,
+    relayer_id: near_primitives::types::AccountId,
+    relayer_private_key: &near_crypto::SecretKey,
+    nonce: near_primitives::types::Nonce,
+    block_hash: near_primitives::hash::CryptoHash,
+) -> (String, near_primitives::hash::CryptoHash) {
+    let transaction = RelayerTransaction {
+        signer_id: relayer_id,
+        public_key: relayer_private_key.public_key(),
+        nonce,
+        receiver_id: signed_delegate_action.delegate_action.sender_id.clone(),
+        block_hash,
+        signed_delegate_action,
+    };
+    let mut bytes = transaction
+        .try_to_vec()
+        .expect("Transaction is not expected to fail on serialization");
+    let hash = near_primitives::hash::hash(&bytes);
+    let signature = relayer_private_key.sign(hash.as_ref());
+    bytes.extend(
+        signature
+            .try_to_vec()
+            .expect("Signature is not expected to fail on serialization"),
+    );
+    (near_primitives::serialize::to_base64(bytes), hash)
+}
+
+/// The delegate action with the transaction actions, to be signed with the access key. The nonce
+/// and the max block height are fetched in the online mode; in the offline mode the nonce is the
+/// one given for the transaction.
+pub async fn build_delegate_action(
+    unsigned_transaction: near_primitives::transaction::Transaction,
+    public_key: near_crypto::PublicKey,
+    offline_nonce: Option<near_primitives::types::Nonce>,
+    options: DelegateActionOptions,
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+) -> color_eyre::eyre::Result<DelegateAction> {
+    let (nonce, max_block_height) = match network_connection_config {
+        Some(network_connection_config) => {
+            let access_key_response = crate::rpc::RpcClient::new(&network_connection_config)
+                .query(near_jsonrpc_primitives::types::query::RpcQueryRequest {
+                    block_reference: near_primitives::types::Finality::Final.into(),
+                    request: near_primitives::views::QueryRequest::ViewAccessKey {
+                        account_id: unsigned_transaction.signer_id.clone(),
+                        public_key: public_key.clone(),
+                    },
+                })
+                .await
+                .map_err(|err| {
+                    color_eyre::Report::msg(format!(
+                        "Failed to fetch public key information for nonce: {:?}",
+                        err
+                    ))
+                })?;
+            let current_nonce =
+                if let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(
+                    access_key,
+                ) = access_key_response.kind
+                {
+                    access_key.nonce
+                } else {
+                    return Err(color_eyre::Report::msg(format!("Error current_nonce")));
+                };
+            (
+                crate::common::next_nonce(
+                    &unsigned_transaction.signer_id,
+                    &public_key,
+                    current_nonce,
+                ),
+                options.max_block_height.unwrap_or(
+                    access_key_response.block_height + crate::consts::DELEGATE_ACTION_TTL,
+                ),
+            )
+        }
+        None => match (offline_nonce, options.max_block_height) {
+            (Some(nonce), Some(max_block_height)) => (nonce, max_block_height),
+            _ => {
+                return Err(color_eyre::Report::msg(
+                    "The nonce and --max-block-height are required for a delegate action in the offline mode",
+                ))
+            }
+        },
+    };
+    Ok(DelegateAction {
+        sender_id: unsigned_transaction.signer_id,
+        receiver_id: unsigned_transaction.receiver_id,
+        actions: unsigned_transaction.actions,
+        nonce,
+        max_block_height,
+        public_key,
+    })
+}
+
+pub fn print_signed_delegate_action(signed_delegate_action: &SignedDelegateAction) {
+    let delegate_action = &signed_delegate_action.delegate_action;
+    println!("\nSigned delegate action:\n");
+    println!("{:<18} {}", "sender_id:", delegate_action.sender_id);
+    println!("{:<18} {}", "public_key:", delegate_action.public_key);
+    println!("{:<18} {}", "nonce:", delegate_action.nonce);
+    println!(
+        "{:<18} {}",
+        "max_block_height:", delegate_action.max_block_height
+    );
+    println!("actions:");
+    crate::common::print_actions(&delegate_action.receiver_id, &delegate_action.actions);
+    let signed_delegate_action_base64 = signed_delegate_action.to_base64();
+    println!(
+        "\nBase64-encoded signed delegate action (relay it with `utils relay-delegate-action`):\n{}",
+        signed_delegate_action_base64
+    );
+    crate::common::record_command_output("signed_delegate_action", signed_delegate_action_base64);
+}
+
+/// Hands the signed delegate action over to a relayer service
+pub async fn post_to_relayer(
+    relayer_url: &url::Url,
+    signed_delegate_action: &SignedDelegateAction,
+) -> color_eyre::eyre::Result<String> {
+    let mut response = awc::Client::default()
+        .post(relayer_url.as_str())
+        .timeout(crate::consts::RELAYER_TIMEOUT)
+        .send_json(&serde_json::json!({
+            "signed_delegate_action": signed_delegate_action.to_base64(),
+        }))
+        .await
+        .map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to send the delegate action to the relayer: {}",
+                err
+            ))
+        })?;
+    let body = response.body().await.unwrap_or_default();
+    if !response.status().is_success() {
+        return Err(color_eyre::Report::msg(format!(
+            "The relayer refused the delegate action ({}): {}",
+            response.status(),
+            String::from_utf8_lossy(&body)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&body).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed_delegate_action() -> SignedDelegateAction {
+        let signer_private_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice.testnet");
+        DelegateAction {
+            sender_id: "alice.testnet".parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec![near_primitives::transaction::Action::Transfer(
+                near_primitives::transaction::TransferAction { deposit: 1 },
+            )],
+            nonce: 1,
+            max_block_height: 100,
+            public_key: signer_private_key.public_key(),
+        }
+        .sign(&signer_private_key)
+    }
+
+    #[test]
+    fn sign_and_relay_delegate_action() {
+        let signed_delegate_action = signed_delegate_action();
+        assert!(signed_delegate_action.verify());
+        let decoded =
+            SignedDelegateAction::from_base64(&signed_delegate_action.to_base64()).unwrap();
+        assert_eq!(decoded, signed_delegate_action);
+
+        let mut tampered = signed_delegate_action.clone();
+        tampered.delegate_action.max_block_height += 1;
+        assert!(!tampered.verify());
+
+        let relayer_private_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "relayer.testnet");
+        let (relayer_transaction, hash) = sign_relayer_transaction(
+            signed_delegate_action.clone(),
+            "relayer.testnet".parse().unwrap(),
+            &relayer_private_key,
+            1,
+            Default::default(),
+        );
+        let bytes = near_primitives::serialize::from_base64(&relayer_transaction).unwrap();
+        let signature = near_crypto::Signature::try_from_slice(&bytes[bytes.len() - 65..]).unwrap();
+        assert!(signature.verify(hash.as_ref(), &relayer_private_key.public_key()));
+        let transaction_bytes = &bytes[..bytes.len() - 65];
+        assert_eq!(near_primitives::hash::hash(transaction_bytes), hash);
+        assert!(transaction_bytes.ends_with(
+            &[
+                vec![1, 0, 0, 0, 8],
+                signed_delegate_action.try_to_vec().unwrap()
+            ]
+            .concat()
+        ));
+    }
+
+    /// The NEP-366 encoding written out field by field, independently of the Borsh impls above
+    #[test]
+    fn delegate_action_matches_nep366_encoding() {
+        let signed_delegate_action = SignedDelegateAction {
+            delegate_action: DelegateAction {
+                sender_id: "alice.testnet".parse().unwrap(),
+                receiver_id: "bob.testnet".parse().unwrap(),
+                actions: vec![near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction { deposit: 1 },
+                )],
+                nonce: 1,
+                max_block_height: 100,
+                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            },
+            signature: near_crypto::Signature::empty(near_crypto::KeyType::ED25519),
+        };
+        let expected_signed_delegate_action: Vec<u8> = [
+            // sender_id: u32 length and "alice.testnet"
+            &[13, 0, 0, 0][..],
+            b"alice.testnet",
+            // receiver_id: u32 length and "bob.testnet"
+            &[11, 0, 0, 0],
+            b"bob.testnet",
+            // actions: u32 count, then `Transfer` (index 3) with the u128 deposit
+            &[1, 0, 0, 0],
+            &[3],
+            &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            // nonce: u64
+            &[1, 0, 0, 0, 0, 0, 0, 0],
+            // max_block_height: u64
+            &[100, 0, 0, 0, 0, 0, 0, 0],
+            // public_key: the ED25519 key type and 32 bytes
+            &[0],
+            &[0; 32],
+            // signature: the ED25519 key type and 64 bytes
+            &[0],
+            &[0; 64],
+        ]
+        .concat();
+        assert_eq!(
+            signed_delegate_action.try_to_vec().unwrap(),
+            expected_signed_delegate_action
+        );
+        assert_eq!(
+            SignedDelegateAction::try_from_slice(&expected_signed_delegate_action).unwrap(),
+            signed_delegate_action
+        );
+
+        let relayer_transaction = RelayerTransaction {
+            signer_id: "relayer.testnet".parse().unwrap(),
+            public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+            nonce: 2,
+            receiver_id: "alice.testnet".parse().unwrap(),
+            block_hash: Default::default(),
+            signed_delegate_action,
+        };
+        let expected_relayer_transaction: Vec<u8> = [
+            // signer_id
+            &[15, 0, 0, 0][..],
+            b"relayer.testnet",
+            // public_key
+            &[0],
+            &[0; 32],
+            // nonce
+            &[2, 0, 0, 0, 0, 0, 0, 0],
+            // receiver_id
+            &[13, 0, 0, 0],
+            b"alice.testnet",
+            // block_hash
+            &[0; 32],
+            // actions: a single `Delegate` (index 8) with the signed delegate action
+            &[1, 0, 0, 0],
+            &[8],
+            &expected_signed_delegate_action[..],
+        ]
+        .concat();
+        assert_eq!(
+            relayer_transaction.try_to_vec().unwrap(),
+            expected_relayer_transaction
+        );
+    }
+
+    #[test]
+    fn post_to_mock_relayer() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let relayer_url: url::Url = format!(
+            "http://127.0.0.1:{}/relay",
+            listener.local_addr().unwrap().port()
+        )
+        .parse()
+        .unwrap();
+        let relayer = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 4096];
+            // The body is a single line of JSON, so it's complete once the closing brace arrives
+            while !request.ends_with(b"}") {
+                let read = stream.read(&mut buf).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        let signed_delegate_action = signed_delegate_action();
+        let response = actix::System::new()
+            .block_on(post_to_relayer(&relayer_url, &signed_delegate_action))
+            .unwrap();
+        assert_eq!(response, "ok");
+        let request = relayer.join().unwrap();
+        assert!(request.starts_with("POST /relay HTTP/1.1"));
+        let body: serde_json::Value =
+            serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "signed_delegate_action": signed_delegate_action.to_base64() })
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use near_primitives::borsh::{BorshDeserialize, BorshSerialize};
+use std::io::{Read, Write};
+
+/// Where the external signer (e.g. an HSM wrapper) is reached. Every request is a single JSON
+/// line; the signer answers with a JSON object.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExternalSigner {
+    /// An executable started for every request: the request goes to its stdin, the response
+    /// is read from its stdout
+    Command(std::path::PathBuf),
+    /// A unix socket the signer listens on: the request is written and the write half is closed,
+    /// the response is read until the signer closes the connection
+    Socket(std::path::PathBuf),
+}
+
+impl std::fmt::Display for ExternalSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Command(path) => write!(f, "the command {:?}", path),
+            Self::Socket(path) => write!(f, "the socket {:?}", path),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Request {
+    /// Asks for the public key the signer uses for the account
+    GetPublicKey {
+        signer_id: near_primitives::types::AccountId,
+    },
+    /// Asks to sign the transaction hash
+    SignTransaction {
+        signer_id: near_primitives::types::AccountId,
+        public_key: near_crypto::PublicKey,
+        /// The base64-encoded Borsh-serialized transaction
+        transaction: String,
+        hash: near_primitives::hash::CryptoHash,
+    },
+    /// Asks to sign the hash of the delegate action (NEP-366) prefixed as a NEP-461 message
+    SignDelegateAction {
+        signer_id: near_primitives::types::AccountId,
+        public_key: near_crypto::PublicKey,
+        /// The base64-encoded Borsh-serialized delegate action
+        delegate_action: String,
+        hash: near_primitives::hash::CryptoHash,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Response {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<near_crypto::PublicKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<near_crypto::Signature>,
+    /// Why the signer refused the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ExternalSigner {
+    fn exchange(&self, request: &Request) -> color_eyre::eyre::Result<Response> {
+        let mut request_line = serde_json::to_string(request)?;
+        request_line.push('\n');
+        let output = match self {
+            Self::Command(path) => {
+                let mut child = std::process::Command::new(path)
+                    .stdin(std::process::Stdio::piped())
+                    .stdout(std::process::Stdio::piped())
+                    .spawn()
+                    .map_err(|err| {
+                        color_eyre::Report::msg(format!("Failed to start {}: {}", self, err))
+                    })?;
+                child
+                    .stdin
+                    .take()
+                    .expect("The stdin of the signer is piped")
+                    .write_all(request_line.as_bytes())?;
+                let output = child.wait_with_output()?;
+                if !output.status.success() {
+                    return Err(color_eyre::Report::msg(format!(
+                        "The external signer ({}) failed with {}",
+                        self, output.status
+                    )));
+                }
+                String::from_utf8(output.stdout)?
+            }
+            #[cfg(unix)]
+            Self::Socket(path) => {
+                let mut stream = std::os::unix::net::UnixStream::connect(path).map_err(|err| {
+                    color_eyre::Report::msg(format!("Failed to connect to {}: {}", self, err))
+                })?;
+                stream.write_all(request_line.as_bytes())?;
+                stream.shutdown(std::net::Shutdown::Write)?;
+                let mut output = String::new();
+                stream.read_to_string(&mut output)?;
+                output
+            }
+            #[cfg(not(unix))]
+            Self::Socket(_) => {
+                return Err(color_eyre::Report::msg(
+                    "The external signer sockets are only supported on unix",
+                ))
+            }
+        };
+        // Anything the signer prints after the response (e.g. logs) is ignored
+        let response: Response = serde_json::Deserializer::from_str(&output)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| {
+                Err(<serde_json::Error as serde::de::Error>::custom(
+                    "the response is empty",
+                ))
+            })
+            .map_err(|err| {
+                color_eyre::Report::msg(format!(
+                    "The external signer ({}) sent an invalid response: {}",
+                    self, err
+                ))
+            })?;
+        match response.error {
+            Some(error) => Err(color_eyre::Report::msg(format!(
+                "The external signer ({}) refused the request: {}",
+                self, error
+            ))),
+            None => Ok(response),
+        }
+    }
+
+    pub fn get_public_key(
+        &self,
+        signer_id: &near_primitives::types::AccountId,
+    ) -> color_eyre::eyre::Result<near_crypto::PublicKey> {
+        self.exchange(&Request::GetPublicKey {
+            signer_id: signer_id.clone(),
+        })?
+        .public_key
+        .ok_or_else(|| {
+            color_eyre::Report::msg(format!("The external signer ({}) sent no public key", self))
+        })
+    }
+
+    /// Signs the transaction with the signer and checks the signature before it's submitted
+    pub fn sign_transaction(
+        &self,
+        unsigned_transaction: near_primitives::transaction::Transaction,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+        let response = self.exchange(&sign_transaction_request(&unsigned_transaction))?;
+        let signature = verify_response(&unsigned_transaction, response)?;
+        Ok(near_primitives::transaction::SignedTransaction::new(
+            signature,
+            unsigned_transaction,
+        ))
+    }
+
+    /// Signs the delegate action with the signer and checks the signature
+    pub fn sign_delegate_action(
+        &self,
+        delegate_action: crate::delegate_action::DelegateAction,
+    ) -> color_eyre::eyre::Result<crate::delegate_action::SignedDelegateAction> {
+        let response = self.exchange(&Request::SignDelegateAction {
+            signer_id: delegate_action.sender_id.clone(),
+            public_key: delegate_action.public_key.clone(),
+            delegate_action: near_primitives::serialize::to_base64(
+                delegate_action
+                    .try_to_vec()
+                    .expect("Delegate action is not expected to fail on serialization"),
+            ),
+            hash: delegate_action.hash(),
+        })?;
+        let signed_delegate_action = match (response.public_key, response.signature) {
+            (Some(public_key), Some(signature)) if public_key == delegate_action.public_key => {
+                crate::delegate_action::SignedDelegateAction {
+                    delegate_action,
+                    signature,
+                }
+            }
+            (Some(public_key), Some(_)) => {
+                return Err(color_eyre::Report::msg(format!(
+                    "The external signer signed with the key {} instead of {}",
+                    public_key, delegate_action.public_key
+                )))
+            }
+            _ => {
+                return Err(color_eyre::Report::msg(
+                    "The external signer sent no public key or no signature",
+                ))
+            }
+        };
+        if !signed_delegate_action.verify() {
+            return Err(color_eyre::Report::msg(
+                "The signature from the external signer doesn't match the delegate action",
+            ));
+        }
+        Ok(signed_delegate_action)
+    }
+}
+
+fn sign_transaction_request(
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+) -> Request {
+    Request::SignTransaction {
+        signer_id: unsigned_transaction.signer_id.clone(),
+        public_key: unsigned_transaction.public_key.clone(),
+        transaction: near_primitives::serialize::to_base64(
+            unsigned_transaction
+                .try_to_vec()
+                .expect("Transaction is not expected to fail on serialization"),
+        ),
+        hash: unsigned_transaction.get_hash_and_size().0,
+    }
+}
+
+/// The signature from the response, if it's made with the access key of the transaction
+fn verify_response(
+    unsigned_transaction: &near_primitives::transaction::Transaction,
+    response: Response,
+) -> color_eyre::eyre::Result<near_crypto::Signature> {
+    let (public_key, signature) = match (response.public_key, response.signature) {
+        (Some(public_key), Some(signature)) => (public_key, signature),
+        _ => {
+            return Err(color_eyre::Report::msg(
+                "The external signer sent no public key or no signature",
+            ))
+        }
+    };
+    if public_key != unsigned_transaction.public_key {
+        return Err(color_eyre::Report::msg(format!(
+            "The external signer signed with the key {} instead of {}",
+            public_key, unsigned_transaction.public_key
+        )));
+    }
+    if !signature.verify(
+        unsigned_transaction.get_hash_and_size().0.as_ref(),
+        &public_key,
+    ) {
+        return Err(color_eyre::Report::msg(
+            "The signature from the external signer doesn't match the transaction",
+        ));
+    }
+    Ok(signature)
+}
+
+/// How the reference signer (`utils external-signer-stub`) answers a request with a private key
+pub fn respond(request_line: &str, signer_private_key: &near_crypto::SecretKey) -> Response {
+    let public_key = signer_private_key.public_key();
+    let request: Request = match serde_json::from_str(request_line.trim()) {
+        Ok(request) => request,
+        Err(err) => {
+            return Response {
+                error: Some(format!("Invalid request: {}", err)),
+                ..Default::default()
+            }
+        }
+    };
+    match request {
+        Request::GetPublicKey { .. } => Response {
+            public_key: Some(public_key),
+            ..Default::default()
+        },
+        Request::SignTransaction {
+            public_key: requested_public_key,
+            transaction,
+            hash,
+            ..
+        } => {
+            if requested_public_key != public_key {
+                return Response {
+                    error: Some(format!("Unknown key {}", requested_public_key)),
+                    ..Default::default()
+                };
+            }
+            // Sign the hash of the transaction actually received, not the hash we are told
+            let transaction_hash = near_primitives::serialize::from_base64(&transaction)
+                .ok()
+                .and_then(|bytes| {
+                    near_primitives::transaction::Transaction::try_from_slice(&bytes).ok()
+                })
+                .map(|transaction| transaction.get_hash_and_size().0);
+            match transaction_hash {
+                Some(transaction_hash) if transaction_hash == hash => Response {
+                    public_key: Some(public_key),
+                    signature: Some(signer_private_key.sign(hash.as_ref())),
+                    error: None,
+                },
+                Some(_) => Response {
+                    error: Some("The hash doesn't match the transaction".to_string()),
+                    ..Default::default()
+                },
+                None => Response {
+                    error: Some("Invalid transaction".to_string()),
+                    ..Default::default()
+                },
+            }
+        }
+        Request::SignDelegateAction {
+            public_key: requested_public_key,
+            delegate_action,
+            hash,
+            ..
+        } => {
+            if requested_public_key != public_key {
+                return Response {
+                    error: Some(format!("Unknown key {}", requested_public_key)),
+                    ..Default::default()
+                };
+            }
+            let delegate_action_hash = near_primitives::serialize::from_base64(&delegate_action)
+                .ok()
+                .and_then(|bytes| {
+                    crate::delegate_action::DelegateAction::try_from_slice(&bytes).ok()
+                })
+                .map(|delegate_action| delegate_action.hash());
+            match delegate_action_hash {
+                Some(delegate_action_hash) if delegate_action_hash == hash => Response {
+                    public_key: Some(public_key),
+                    signature: Some(signer_private_key.sign(hash.as_ref())),
+                    error: None,
+                },
+                Some(_) => Response {
+                    error: Some("The hash doesn't match the delegate action".to_string()),
+                    ..Default::default()
+                },
+                None => Response {
+                    error: Some("Invalid delegate action".to_string()),
+                    ..Default::default()
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_with_reference_signer() {
+        let signer_private_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice.testnet");
+        let unsigned_transaction = near_primitives::transaction::Transaction {
+            signer_id: "alice.testnet".parse().unwrap(),
+            public_key: signer_private_key.public_key(),
+            nonce: 1,
+            receiver_id: "bob.testnet".parse().unwrap(),
+            block_hash: Default::default(),
+            actions: vec![],
+        };
+        let request_line =
+            serde_json::to_string(&sign_transaction_request(&unsigned_transaction)).unwrap();
+        let response = respond(&request_line, &signer_private_key);
+        assert!(verify_response(&unsigned_transaction, response.clone()).is_ok());
+
+        let other_transaction = near_primitives::transaction::Transaction {
+            nonce: 2,
+            ..unsigned_transaction.clone()
+        };
+        assert!(verify_response(&other_transaction, response).is_err());
+
+        let other_private_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "bob.testnet");
+        assert!(respond(&request_line, &other_private_key).error.is_some());
+    }
+
+    #[test]
+    fn sign_delegate_action_with_reference_signer() {
+        let signer_private_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice.testnet");
+        let delegate_action = crate::delegate_action::DelegateAction {
+            sender_id: "alice.testnet".parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec![],
+            nonce: 1,
+            max_block_height: 100,
+            public_key: signer_private_key.public_key(),
+        };
+        let request = Request::SignDelegateAction {
+            signer_id: delegate_action.sender_id.clone(),
+            public_key: delegate_action.public_key.clone(),
+            delegate_action: near_primitives::serialize::to_base64(
+                delegate_action.try_to_vec().unwrap(),
+            ),
+            hash: delegate_action.hash(),
+        };
+        let response = respond(
+            &serde_json::to_string(&request).unwrap(),
+            &signer_private_key,
+        );
+        assert!(crate::delegate_action::SignedDelegateAction {
+            delegate_action,
+            signature: response.signature.unwrap(),
+        }
+        .verify());
+    }
+}
@@ -0,0 +1,144 @@
+use clap::IntoApp;
+
+static NON_INTERACTIVE: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+thread_local! {
+    /// The console command being resolved, used to report the subcommand with the missing argument
+    static COMMAND_ARGS: std::cell::RefCell<Vec<String>> = std::cell::RefCell::new(vec![]);
+}
+
+/// Enables the strict non-interactive mode with `--no-interactive` or when stdin is not
+/// a terminal: a missing argument is reported instead of opening a prompt.
+pub fn init(no_interactive: bool) {
+    NON_INTERACTIVE.store(
+        no_interactive || !atty::is(atty::Stream::Stdin),
+        std::sync::atomic::Ordering::Relaxed,
+    );
+}
+
+pub fn is_interactive() -> bool {
+    !NON_INTERACTIVE.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Runs a step of a `run` plan without prompts if the step has `--no-interactive`
+pub fn with_step_options<T>(no_interactive: bool, f: impl FnOnce() -> T) -> T {
+    let plan_non_interactive = NON_INTERACTIVE.load(std::sync::atomic::Ordering::Relaxed);
+    NON_INTERACTIVE.store(
+        plan_non_interactive || no_interactive,
+        std::sync::atomic::Ordering::Relaxed,
+    );
+    let result = f();
+    NON_INTERACTIVE.store(plan_non_interactive, std::sync::atomic::Ordering::Relaxed);
+    result
+}
+
+pub fn set_command_args(args: Vec<String>) {
+    COMMAND_ARGS.with(|command_args| *command_args.borrow_mut() = args);
+}
+
+/// The argument a prompt asked for in the non-interactive mode. It is returned up through the
+/// argument conversions: `main` exits with `EXIT_CODE_MISSING_ARGUMENT`, a `run` step fails.
+#[derive(Debug, serde::Serialize)]
+pub struct MissingArgumentError {
+    error: &'static str,
+    /// The subcommands leading to the one with the missing argument, e.g. `transfer near network testnet`
+    subcommand: String,
+    /// The missing argument, or the choices when a subcommand is missing
+    missing: Vec<String>,
+}
+
+/// Finds the deepest subcommand given in the console command and what it still expects
+fn missing_argument_error(argument: Option<&str>) -> MissingArgumentError {
+    let args = COMMAND_ARGS.with(|command_args| command_args.borrow().clone());
+    let mut app = crate::CliArgs::into_app();
+    let mut subcommand = vec![];
+    let mut missing = vec![];
+    if let Ok(arg_matches) = app.clone().try_get_matches_from(args) {
+        let mut arg_matches = &arg_matches;
+        while let Some((name, subcommand_matches)) = arg_matches.subcommand() {
+            match app.get_subcommands().find(|app| app.get_name() == name) {
+                Some(subcommand_app) => app = subcommand_app.clone(),
+                None => break,
+            }
+            subcommand.push(name.to_string());
+            arg_matches = subcommand_matches;
+        }
+        if argument.is_none() {
+            missing = app
+                .get_subcommands()
+                .map(|app| app.get_name().to_string())
+                .filter(|name| name != "help")
+                .collect();
+            if missing.is_empty() {
+                missing = app
+                    .get_arguments()
+                    .filter(|arg| arg.is_set(clap::ArgSettings::TakesValue))
+                    .filter(|arg| !arg_matches.is_present(arg.get_name()))
+                    .map(|arg| match arg.get_long() {
+                        Some(long) => format!("--{}", long),
+                        None => format!("<{}>", arg.get_name().replace('_', "-")),
+                    })
+                    .collect();
+            }
+        }
+    }
+    if let Some(argument) = argument {
+        missing = vec![argument.to_string()];
+    }
+    MissingArgumentError {
+        error: "missing_argument",
+        subcommand: subcommand.join(" "),
+        missing,
+    }
+}
+
+impl std::fmt::Display for MissingArgumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is missing for `{}` and the prompts are disabled (--no-interactive or stdin is not a terminal)",
+            match self.missing.as_slice() {
+                [argument] => argument.clone(),
+                choices => format!("one of {}", choices.join(", ")),
+            },
+            self.subcommand
+        )
+    }
+}
+
+impl std::error::Error for MissingArgumentError {}
+
+impl MissingArgumentError {
+    /// The message and the machine-readable error for the scripts
+    pub fn print(&self) {
+        eprintln!("Error: {}", self);
+        eprintln!(
+            "{}",
+            serde_json::to_string(self).expect("The error is serializable")
+        );
+    }
+}
+
+/// Guards the prompts that ask for a missing argument
+pub trait RequireInteractive: Sized {
+    /// In the non-interactive mode, returns `MissingArgumentError` naming the argument
+    /// (e.g. `--gas` or `<receiver-account-id>`) instead of showing the prompt
+    fn require_interactive(self, argument: &str) -> Result<Self, MissingArgumentError> {
+        if !is_interactive() {
+            return Err(missing_argument_error(Some(argument)));
+        }
+        Ok(self)
+    }
+
+    /// The same for the prompts choosing a subcommand: the error lists the choices
+    fn require_interactive_choice(self) -> Result<Self, MissingArgumentError> {
+        if !is_interactive() {
+            return Err(missing_argument_error(None));
+        }
+        Ok(self)
+    }
+}
+
+impl<T> RequireInteractive for dialoguer::Input<'_, T> {}
+impl RequireInteractive for dialoguer::Select<'_> {}
+impl RequireInteractive for dialoguer::MultiSelect<'_> {}
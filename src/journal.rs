@@ -0,0 +1,270 @@
+use near_primitives::borsh::BorshDeserialize;
+
+/// What happened to the transaction recorded in the journal
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalStatus {
+    /// The unsigned transaction was printed to be signed elsewhere
+    Constructed,
+    /// The transaction was signed, but not sent
+    Signed,
+    Succeeded,
+    Failed,
+}
+
+impl std::fmt::Display for JournalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constructed => write!(f, "constructed"),
+            Self::Signed => write!(f, "signed"),
+            Self::Succeeded => write!(f, "succeeded"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// A line of the transaction journal stored in ~/.near-cli/history.jsonl
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct JournalEntry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// testnet, mainnet, betanet or the RPC URL of a custom network; none for the offline mode
+    pub network: Option<String>,
+    pub status: JournalStatus,
+    pub signer_id: String,
+    pub receiver_id: String,
+    pub actions: String,
+    pub hash: String,
+    /// The base64-encoded transaction: signed unless the status is `constructed`
+    pub transaction: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl JournalEntry {
+    pub fn network_connection_config(&self) -> Option<crate::common::ConnectionConfig> {
+        self.network
+            .as_deref()
+            .and_then(network_connection_config_from_name)
+    }
+
+    pub fn signed_transaction(
+        &self,
+    ) -> color_eyre::eyre::Result<near_primitives::transaction::SignedTransaction> {
+        if self.status == JournalStatus::Constructed {
+            return Err(color_eyre::Report::msg(
+                "The transaction was not signed, sign it first",
+            ));
+        }
+        let bytes = near_primitives::serialize::from_base64(&self.transaction).map_err(|err| {
+            color_eyre::Report::msg(format!("The journal entry is corrupted: {}", err))
+        })?;
+        near_primitives::transaction::SignedTransaction::try_from_slice(&bytes).map_err(|err| {
+            color_eyre::Report::msg(format!("The journal entry is corrupted: {}", err))
+        })
+    }
+}
+
+fn path() -> std::path::PathBuf {
+    let mut path = dirs::home_dir().expect("Impossible to get your home dir!");
+    path.push(crate::consts::FILE_NAME_HISTORY);
+    path
+}
+
+pub fn network_name(network_connection_config: &crate::common::ConnectionConfig) -> String {
+    match network_connection_config {
+        crate::common::ConnectionConfig::Testnet => "testnet".to_string(),
+        crate::common::ConnectionConfig::Mainnet => "mainnet".to_string(),
+        crate::common::ConnectionConfig::Betanet => "betanet".to_string(),
+        crate::common::ConnectionConfig::Custom { url } => url.to_string(),
+    }
+}
+
+pub fn network_connection_config_from_name(
+    network_name: &str,
+) -> Option<crate::common::ConnectionConfig> {
+    match network_name {
+        "testnet" => Some(crate::common::ConnectionConfig::Testnet),
+        "mainnet" => Some(crate::common::ConnectionConfig::Mainnet),
+        "betanet" => Some(crate::common::ConnectionConfig::Betanet),
+        url => url
+            .parse()
+            .ok()
+            .map(|url| crate::common::ConnectionConfig::Custom { url }),
+    }
+}
+
+/// A short description of the actions, e.g. `Transfer(1 NEAR), FunctionCall(ft_transfer)`
+pub fn summarize_actions(actions: &[near_primitives::transaction::Action]) -> String {
+    actions
+        .iter()
+        .map(|action| match action {
+            near_primitives::transaction::Action::CreateAccount(_) => "CreateAccount".to_string(),
+            near_primitives::transaction::Action::DeployContract(deploy_contract_action) => {
+                format!(
+                    "DeployContract({} bytes)",
+                    deploy_contract_action.code.len()
+                )
+            }
+            near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                format!("FunctionCall({})", function_call_action.method_name)
+            }
+            near_primitives::transaction::Action::Transfer(transfer_action) => format!(
+                "Transfer({})",
+                crate::common::NearBalance::from_yoctonear(transfer_action.deposit)
+            ),
+            near_primitives::transaction::Action::Stake(stake_action) => format!(
+                "Stake({})",
+                crate::common::NearBalance::from_yoctonear(stake_action.stake)
+            ),
+            near_primitives::transaction::Action::AddKey(add_key_action) => {
+                format!("AddKey({})", add_key_action.public_key)
+            }
+            near_primitives::transaction::Action::DeleteKey(delete_key_action) => {
+                format!("DeleteKey({})", delete_key_action.public_key)
+            }
+            near_primitives::transaction::Action::DeleteAccount(delete_account_action) => {
+                format!("DeleteAccount({})", delete_account_action.beneficiary_id)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Appends the transaction to the journal. A failure to write the journal
+/// is reported, but it does not fail the command.
+pub fn record_transaction(
+    status: JournalStatus,
+    network_connection_config: Option<&crate::common::ConnectionConfig>,
+    transaction: &near_primitives::transaction::Transaction,
+    serialize_to_base64: &str,
+    error: Option<String>,
+) {
+    let entry = JournalEntry {
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+        network: network_connection_config.map(network_name),
+        status,
+        signer_id: transaction.signer_id.to_string(),
+        receiver_id: transaction.receiver_id.to_string(),
+        actions: summarize_actions(&transaction.actions),
+        hash: transaction.get_hash_and_size().0.to_string(),
+        transaction: serialize_to_base64.to_string(),
+        error,
+    };
+    if let Err(err) = append(&entry) {
+        println!("Failed to record the transaction in the journal: {}", err);
+    }
+}
+
+/// Records a transaction that is only known as base64, e.g. the one passed to
+/// `utils send-signed-transaction`. Transactions that cannot be decoded are not recorded.
+pub fn record_serialized_transaction(
+    status: JournalStatus,
+    network_connection_config: Option<&crate::common::ConnectionConfig>,
+    serialize_to_base64: &str,
+    error: Option<String>,
+) {
+    let signed_transaction = near_primitives::serialize::from_base64(serialize_to_base64)
+        .ok()
+        .and_then(|bytes| {
+            near_primitives::transaction::SignedTransaction::try_from_slice(&bytes).ok()
+        });
+    if let Some(signed_transaction) = signed_transaction {
+        record_transaction(
+            status,
+            network_connection_config,
+            &signed_transaction.transaction,
+            serialize_to_base64,
+            error,
+        )
+    }
+}
+
+fn append(entry: &JournalEntry) -> crate::CliResult {
+    let path = path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    std::io::Write::write_all(
+        &mut file,
+        format!("{}\n", serde_json::to_string(entry)?).as_bytes(),
+    )?;
+    Ok(())
+}
+
+/// Reads the journal. The entries are numbered from 1 in the order they were recorded.
+pub fn load() -> color_eyre::eyre::Result<Vec<JournalEntry>> {
+    let path = path();
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let data = std::fs::read_to_string(&path).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", &path, err))
+    })?;
+    parse(&data).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", &path, err))
+    })
+}
+
+fn parse(data: &str) -> serde_json::Result<Vec<JournalEntry>> {
+    data.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str)
+        .collect()
+}
+
+/// Formats the timestamp as `YYYY-MM-DD HH:MM:SS` (UTC)
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds_of_day = timestamp % 86400;
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_journal_lines() {
+        let entry = JournalEntry {
+            timestamp: 1_600_000_000,
+            network: Some("testnet".to_string()),
+            status: JournalStatus::Succeeded,
+            signer_id: "alice.testnet".to_string(),
+            receiver_id: "bob.testnet".to_string(),
+            actions: "Transfer(1 NEAR)".to_string(),
+            hash: "11111111111111111111111111111111".to_string(),
+            transaction: "".to_string(),
+            error: None,
+        };
+        let data = format!("{}\n\n", serde_json::to_string(&entry).unwrap());
+        assert_eq!(parse(&data).unwrap(), vec![entry]);
+        assert_eq!(format_timestamp(1_600_000_000), "2020-09-13 12:26:40");
+    }
+}
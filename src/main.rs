@@ -5,7 +5,15 @@ use common::{try_external_subcommand_execution, CliResult};
 
 mod commands;
 mod common;
+mod config;
 mod consts;
+mod delegate_action;
+mod external_signer;
+mod interactive;
+mod journal;
+mod network;
+mod project_config;
+mod rpc;
 
 /// near-cli is a toolbox for interacting with NEAR protocol
 #[derive(Debug, Clap)]
@@ -19,6 +27,22 @@ mod consts;
     // setting(clap::AppSettings::NextLineHelp)
 )]
 struct CliArgs {
+    /// Save the console command as a template to replay it later with `template run`
+    /// (<name> or <name>:<placeholder>=<value>,... to choose the placeholders without prompts)
+    #[clap(long)]
+    save_template: Option<String>,
+    /// Never prompt: exit with code 3 naming the missing argument (the default when stdin is not a terminal)
+    #[clap(long)]
+    no_interactive: bool,
+    /// The network for the commands that ask for one: testnet, mainnet, betanet or an RPC URL (defaults to $NEAR_NETWORK)
+    #[clap(long)]
+    network: Option<String>,
+    /// Prepare the transactions without a network connection in the commands that ask for the mode
+    #[clap(long)]
+    offline: bool,
+    /// The account that signs the transactions in the commands that ask for one (defaults to $NEAR_SIGNER)
+    #[clap(long)]
+    signer: Option<near_primitives::types::AccountId>,
     #[clap(subcommand)]
     top_level_command: Option<self::commands::CliTopLevelCommand>,
 }
@@ -35,6 +59,17 @@ impl CliArgs {
             .as_ref()
             .map(|subcommand| subcommand.to_cli_args())
             .unwrap_or_default();
+        if let Some(signer) = &self.signer {
+            args.push_front(signer.to_string());
+            args.push_front("--signer".to_owned());
+        }
+        if self.offline {
+            args.push_front("--offline".to_owned());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.clone());
+            args.push_front("--network".to_owned());
+        }
         args.push_front("./near-cli".to_owned());
         args
     }
@@ -42,19 +77,28 @@ impl CliArgs {
 
 impl From<Args> for CliArgs {
     fn from(cli_args: Args) -> Self {
+        let (network, offline, signer) = self::network::cli_options();
         Self {
+            save_template: None,
+            no_interactive: false,
+            network,
+            offline,
+            signer,
             top_level_command: Some(cli_args.top_level_command.into()),
         }
     }
 }
 
-impl From<CliArgs> for Args {
-    fn from(cli_args: CliArgs) -> Self {
+impl Args {
+    fn from(cli_args: CliArgs) -> color_eyre::eyre::Result<Self> {
+        // Every command (and every step of a `run` plan) starts without the options left by
+        // the previous one
+        self::delegate_action::set_options(None);
         let top_level_command = match cli_args.top_level_command {
-            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand),
-            None => self::commands::TopLevelCommand::choose_command(),
+            Some(cli_subcommand) => self::commands::TopLevelCommand::from(cli_subcommand)?,
+            None => self::commands::TopLevelCommand::choose_command()?,
         };
-        Self { top_level_command }
+        Ok(Self { top_level_command })
     }
 }
 
@@ -67,7 +111,19 @@ impl Args {
 fn main() -> CliResult {
     color_eyre::install()?;
 
-    let cli = match CliArgs::try_parse() {
+    let result = run_cli();
+    if let Err(report) = &result {
+        if let Some(error) = report.downcast_ref::<self::interactive::MissingArgumentError>() {
+            error.print();
+            std::process::exit(crate::consts::EXIT_CODE_MISSING_ARGUMENT)
+        }
+    }
+    result
+}
+
+fn run_cli() -> CliResult {
+    let mut command_args: Vec<String> = std::env::args().collect();
+    let mut cli = match CliArgs::try_parse() {
         Ok(cli) => cli,
         Err(error) => {
             if matches!(
@@ -80,6 +136,11 @@ fn main() -> CliResult {
         }
     };
 
+    self::interactive::init(cli.no_interactive);
+    self::config::init()?;
+    self::project_config::init()?;
+    self::network::init(cli.network.clone(), cli.offline, cli.signer.clone())?;
+
     if let Some(self::commands::CliTopLevelCommand::GenerateShellCompletions(subcommand)) =
         cli.top_level_command
     {
@@ -87,16 +148,87 @@ fn main() -> CliResult {
         return Ok(());
     }
 
-    let args = Args::from(cli);
+    if let Some(self::commands::CliTopLevelCommand::Run(subcommand)) = &cli.top_level_command {
+        return subcommand.process();
+    }
+
+    if let Some(self::commands::CliTopLevelCommand::Template(subcommand)) = &cli.top_level_command {
+        match subcommand.process()? {
+            Some(template_args) => {
+                command_args = template_args.clone();
+                let mut template_cli = CliArgs::try_parse_from(template_args)
+                    .map_err(|err| color_eyre::eyre::eyre!(err))?;
+                // The global options given with `template run` take precedence over the saved ones
+                if cli.network.is_some() || cli.offline {
+                    template_cli.network = cli.network.clone();
+                    template_cli.offline = cli.offline;
+                }
+                if cli.signer.is_some() {
+                    template_cli.signer = cli.signer.clone();
+                }
+                self::network::init(
+                    template_cli.network.clone(),
+                    template_cli.offline,
+                    template_cli.signer.clone(),
+                )?;
+                cli = template_cli;
+            }
+            None => return Ok(()),
+        }
+    }
+
+    let save_template = cli.save_template.clone();
+
+    self::interactive::set_command_args(command_args);
+    let args = Args::from(cli)?;
 
     let completed_cli = CliArgs::from(args.clone());
 
-    let process_result = actix::System::new().block_on(args.process());
+    // The placeholders are chosen before the command is executed, so a prompt can't fail it
+    // after the transaction is sent
+    let template_to_save = match save_template {
+        Some(save_template) => Some(self::commands::template_command::TemplateToSave::prepare(
+            &save_template,
+            completed_cli.to_cli_args(),
+        )?),
+        None => None,
+    };
+
+    let process_result = crate::common::block_on(args.process());
 
     println!(
         "Your console command:\n{}",
         shell_words::join(&completed_cli.to_cli_args())
     );
 
-    process_result
+    // A failed or aborted command is not worth replaying, neither is a transaction that
+    // could not be sent or failed on chain
+    process_result?;
+    if let Some(template_to_save) = template_to_save {
+        match crate::common::take_command_outputs().get("error") {
+            Some(err) => println!("The template is not saved, the command failed: {}", err),
+            None => template_to_save.save()?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_global_options() {
+        let args: Vec<String> = vec![
+            "./near-cli".to_owned(),
+            "--network".to_owned(),
+            "testnet".to_owned(),
+            "view".to_owned(),
+            "account".to_owned(),
+            "bob.testnet".to_owned(),
+        ];
+        let cli = CliArgs::try_parse_from(args.clone()).unwrap();
+        assert_eq!(cli.network.as_deref(), Some("testnet"));
+        assert_eq!(cli.to_cli_args().into_iter().collect::<Vec<_>>(), args);
+    }
 }
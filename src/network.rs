@@ -0,0 +1,378 @@
+use crate::interactive::RequireInteractive;
+use dialoguer::{theme::ColorfulTheme, Select};
+use strum::{EnumIter, EnumMessage, IntoEnumIterator};
+
+/// The choices pre-filled with the global options `--network`, `--offline` and `--signer`,
+/// the `NEAR_NETWORK` and `NEAR_SIGNER` environment variables or the project defaults
+#[derive(Debug, Clone, Default)]
+struct GlobalOptions {
+    network_connection_config: Option<crate::common::ConnectionConfig>,
+    offline: bool,
+    signer: Option<near_primitives::types::AccountId>,
+    /// Where the network (or the offline mode) comes from, for `near config show`
+    network_source: Option<String>,
+    signer_source: Option<String>,
+}
+
+thread_local! {
+    static GLOBAL_OPTIONS: std::cell::RefCell<GlobalOptions> = std::cell::RefCell::new(GlobalOptions::default());
+}
+
+/// The RPC servers every command can work with
+#[derive(Debug, Clone, Copy, PartialEq, EnumMessage, EnumIter)]
+pub enum Network {
+    #[strum(message = "Testnet")]
+    Testnet,
+    #[strum(message = "Mainnet")]
+    Mainnet,
+    #[strum(message = "Betanet")]
+    Betanet,
+    #[strum(message = "Custom")]
+    Custom,
+}
+
+impl std::str::FromStr for Network {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "testnet" => Ok(Self::Testnet),
+            "mainnet" => Ok(Self::Mainnet),
+            "betanet" => Ok(Self::Betanet),
+            "custom" => Ok(Self::Custom),
+            _ => Err(format!(
+                "Unknown network <{}>: use testnet, mainnet, betanet or custom",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Testnet => write!(f, "testnet"),
+            Self::Mainnet => write!(f, "mainnet"),
+            Self::Betanet => write!(f, "betanet"),
+            Self::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+/// The network of the online commands: `network testnet ...` or `network custom --url <URL> ...`
+#[derive(Debug, Default, Clone, clap::Clap)]
+pub struct CliSelectServer {
+    /// testnet, mainnet, betanet or custom (defaults to --network, or it is asked for)
+    #[clap(possible_values = &["testnet", "mainnet", "betanet", "custom"])]
+    network: Option<Network>,
+    /// The RPC endpoint of the custom network
+    #[clap(long)]
+    url: Option<crate::common::AvailableRpcServerUrl>,
+    /// Do not check that the RPC server responds
+    #[clap(long)]
+    no_verify_url: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SelectServer {
+    pub connection_config: crate::common::ConnectionConfig,
+    verify_url: bool,
+}
+
+impl CliSelectServer {
+    /// The console command arguments of the network followed by those of the next subcommand
+    pub fn prepend_to_cli_args(
+        &self,
+        mut args: std::collections::VecDeque<String>,
+    ) -> std::collections::VecDeque<String> {
+        if self.no_verify_url {
+            args.push_front("--no-verify-url".to_string());
+        }
+        if let Some(url) = &self.url {
+            args.push_front(url.to_string());
+            args.push_front("--url".to_string());
+        }
+        if let Some(network) = &self.network {
+            args.push_front(network.to_string());
+        }
+        args
+    }
+}
+
+impl From<SelectServer> for CliSelectServer {
+    fn from(select_server: SelectServer) -> Self {
+        let network = match select_server.connection_config {
+            crate::common::ConnectionConfig::Testnet => Network::Testnet,
+            crate::common::ConnectionConfig::Mainnet => Network::Mainnet,
+            crate::common::ConnectionConfig::Betanet => Network::Betanet,
+            crate::common::ConnectionConfig::Custom { url } => {
+                return Self {
+                    network: Some(Network::Custom),
+                    url: Some(crate::common::AvailableRpcServerUrl { inner: url }),
+                    no_verify_url: !select_server.verify_url,
+                }
+            }
+        };
+        Self {
+            network: Some(network),
+            url: None,
+            no_verify_url: false,
+        }
+    }
+}
+
+impl SelectServer {
+    pub fn from(item: CliSelectServer) -> color_eyre::eyre::Result<Self> {
+        let network = match item.network {
+            Some(network) => network,
+            None if item.url.is_some() => Network::Custom,
+            None => choose_network()?,
+        };
+        if network != Network::Custom && item.url.is_some() {
+            return Err(color_eyre::Report::msg(format!(
+                "The option --url is only used with the custom network, not with {}",
+                network
+            )));
+        }
+        let connection_config = match network {
+            Network::Testnet => crate::common::ConnectionConfig::Testnet,
+            Network::Mainnet => crate::common::ConnectionConfig::Mainnet,
+            Network::Betanet => crate::common::ConnectionConfig::Betanet,
+            Network::Custom => crate::common::ConnectionConfig::Custom {
+                url: crate::common::AvailableRpcServerUrl::resolve(item.url)?.inner,
+            },
+        };
+        Ok(Self {
+            connection_config,
+            verify_url: !item.no_verify_url,
+        })
+    }
+
+    pub fn choose_server() -> color_eyre::eyre::Result<Self> {
+        Self::from(CliSelectServer::default())
+    }
+
+    /// Checks that the RPC server of the custom network responds before the command is processed
+    pub async fn verify(&self) -> crate::CliResult {
+        match &self.connection_config {
+            crate::common::ConnectionConfig::Custom { url } if self.verify_url => {
+                crate::common::AvailableRpcServerUrl { inner: url.clone() }
+                    .verify()
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The first value that is set, with its source
+fn first_set<T>(candidates: Vec<(Option<T>, String)>) -> Option<(T, String)> {
+    candidates
+        .into_iter()
+        .find_map(|(value, source)| value.map(|value| (value, source)))
+}
+
+fn network_connection_config(
+    network: &str,
+    source: &str,
+) -> color_eyre::eyre::Result<crate::common::ConnectionConfig> {
+    crate::journal::network_connection_config_from_name(network).ok_or_else(|| {
+        color_eyre::Report::msg(format!(
+            "Unknown network <{}> in {}: use testnet, mainnet, betanet or an RPC URL",
+            network, source
+        ))
+    })
+}
+
+/// Sets the global options. The command line options take precedence over the environment
+/// variables, and those over the project defaults.
+pub fn init(
+    network: Option<String>,
+    offline: bool,
+    signer: Option<near_primitives::types::AccountId>,
+) -> crate::CliResult {
+    if offline && network.is_some() {
+        return Err(color_eyre::Report::msg(
+            "The options --network and --offline can't be used together",
+        ));
+    }
+    let project_config = crate::project_config::get();
+    let (network_connection_config, network_source) = if offline {
+        (None, Some("--offline".to_string()))
+    } else {
+        match first_set(vec![
+            (network, "--network".to_string()),
+            (
+                std::env::var(crate::consts::ENV_NEAR_NETWORK).ok(),
+                crate::consts::ENV_NEAR_NETWORK.to_string(),
+            ),
+            (project_config.network, crate::project_config::source()),
+        ]) {
+            Some((network, source)) => (
+                Some(network_connection_config(&network, &source)?),
+                Some(source),
+            ),
+            None => (None, None),
+        }
+    };
+    let env_signer = match std::env::var(crate::consts::ENV_NEAR_SIGNER) {
+        Ok(signer) => Some(signer.parse().map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "{} is not a valid account ID: {}",
+                crate::consts::ENV_NEAR_SIGNER,
+                err
+            ))
+        })?),
+        Err(_) => None,
+    };
+    let (signer, signer_source) = match first_set(vec![
+        (signer, "--signer".to_string()),
+        (env_signer, crate::consts::ENV_NEAR_SIGNER.to_string()),
+        (project_config.signer, crate::project_config::source()),
+    ]) {
+        Some((signer, source)) => (Some(signer), Some(source)),
+        None => (None, None),
+    };
+    GLOBAL_OPTIONS.with(|global_options| {
+        *global_options.borrow_mut() = GlobalOptions {
+            network_connection_config,
+            offline,
+            signer,
+            network_source,
+            signer_source,
+        }
+    });
+    Ok(())
+}
+
+fn global_options() -> GlobalOptions {
+    GLOBAL_OPTIONS.with(|global_options| global_options.borrow().clone())
+}
+
+/// Runs a step of a `run` plan with its own `--network`, `--offline` and `--signer`, which
+/// take precedence over the options of the plan; those are restored afterwards
+pub fn with_step_options<T>(
+    network: Option<String>,
+    offline: bool,
+    signer: Option<near_primitives::types::AccountId>,
+    f: impl FnOnce() -> color_eyre::eyre::Result<T>,
+) -> color_eyre::eyre::Result<T> {
+    if offline && network.is_some() {
+        return Err(color_eyre::Report::msg(
+            "The options --network and --offline can't be used together",
+        ));
+    }
+    let plan_options = global_options();
+    let mut step_options = plan_options.clone();
+    if offline {
+        step_options.network_connection_config = None;
+        step_options.offline = true;
+        step_options.network_source = Some("--offline".to_string());
+    }
+    if let Some(network) = network {
+        step_options.network_connection_config =
+            Some(network_connection_config(&network, "--network")?);
+        step_options.offline = false;
+        step_options.network_source = Some("--network".to_string());
+    }
+    if let Some(signer) = signer {
+        step_options.signer = Some(signer);
+        step_options.signer_source = Some("--signer".to_string());
+    }
+    GLOBAL_OPTIONS.with(|global_options| *global_options.borrow_mut() = step_options);
+    let result = f();
+    GLOBAL_OPTIONS.with(|global_options| *global_options.borrow_mut() = plan_options);
+    result
+}
+
+/// Whether a command with both modes runs online (`Some(true)`) or offline (`Some(false)`);
+/// `None` if the mode has to be asked for
+pub fn preselected_online_mode() -> Option<bool> {
+    let global_options = global_options();
+    if global_options.offline {
+        Some(false)
+    } else if global_options.network_connection_config.is_some() {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// The RPC URL given with `--network` for a custom network
+pub fn custom_rpc_url() -> Option<crate::common::AvailableRpcServerUrl> {
+    match global_options().network_connection_config {
+        Some(crate::common::ConnectionConfig::Custom { url }) => {
+            Some(crate::common::AvailableRpcServerUrl { inner: url })
+        }
+        _ => None,
+    }
+}
+
+/// The global options `--network`, `--offline` and `--signer` for the console command
+pub fn cli_options() -> (
+    Option<String>,
+    bool,
+    Option<near_primitives::types::AccountId>,
+) {
+    let global_options = global_options();
+    (
+        global_options
+            .network_connection_config
+            .as_ref()
+            .map(crate::journal::network_name),
+        global_options.offline,
+        global_options.signer,
+    )
+}
+
+pub fn signer() -> Option<near_primitives::types::AccountId> {
+    global_options().signer
+}
+
+/// The effective network (`offline` for the offline mode) and where it comes from
+pub fn network_setting() -> Option<(String, String)> {
+    let global_options = global_options();
+    let network = if global_options.offline {
+        "offline".to_string()
+    } else {
+        crate::journal::network_name(global_options.network_connection_config.as_ref()?)
+    };
+    Some((network, global_options.network_source?))
+}
+
+/// The effective signer and where it comes from
+pub fn signer_setting() -> Option<(near_primitives::types::AccountId, String)> {
+    let global_options = global_options();
+    Some((global_options.signer?, global_options.signer_source?))
+}
+
+/// The network selection shared by all the commands: the network given with `--network`,
+/// otherwise the one chosen in the prompt
+pub fn choose_network() -> color_eyre::eyre::Result<Network> {
+    let global_options = global_options();
+    if global_options.offline {
+        return Err(color_eyre::Report::msg(
+            "This command needs a network connection, it can't be used with --offline",
+        ));
+    }
+    match global_options.network_connection_config {
+        Some(crate::common::ConnectionConfig::Testnet) => return Ok(Network::Testnet),
+        Some(crate::common::ConnectionConfig::Mainnet) => return Ok(Network::Mainnet),
+        Some(crate::common::ConnectionConfig::Betanet) => return Ok(Network::Betanet),
+        Some(crate::common::ConnectionConfig::Custom { .. }) => return Ok(Network::Custom),
+        None => {}
+    }
+    println!();
+    let variants = Network::iter().collect::<Vec<_>>();
+    let networks = variants
+        .iter()
+        .map(|p| p.get_message().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    let selected_network = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Select NEAR protocol RPC server:")
+        .items(&networks)
+        .default(0)
+        .require_interactive_choice()?
+        .interact()
+        .unwrap();
+    Ok(variants[selected_network])
+}
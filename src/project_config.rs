@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+/// The `.near-cli.toml` file as written by the user
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectConfigFile {
+    network: Option<String>,
+    signer: Option<String>,
+    contract: Option<String>,
+    gas: Option<String>,
+    deposit: Option<String>,
+    output_format: Option<String>,
+}
+
+/// The project defaults from the `.near-cli.toml` file found in the working directory
+/// or the closest of its parents
+#[derive(Debug, Default, Clone)]
+pub struct ProjectConfig {
+    /// The file the defaults were read from; none if there is no such file
+    pub path: Option<std::path::PathBuf>,
+    /// testnet, mainnet, betanet or an RPC URL
+    pub network: Option<String>,
+    pub signer: Option<near_primitives::types::AccountId>,
+    /// The contract the functions are called on
+    pub contract: Option<near_primitives::types::AccountId>,
+    /// The prepaid gas of the function calls
+    pub gas: Option<crate::common::NearGas>,
+    /// The deposit attached to the function calls
+    pub deposit: Option<crate::common::NearBalance>,
+    pub output_format: Option<crate::common::OutputFormat>,
+}
+
+thread_local! {
+    static PROJECT_CONFIG: std::cell::RefCell<ProjectConfig> = std::cell::RefCell::new(ProjectConfig::default());
+}
+
+/// Looks for `.near-cli.toml` in the working directory and its parents
+fn find() -> Option<std::path::PathBuf> {
+    let mut dir = std::env::current_dir().ok();
+    while let Some(current_dir) = dir {
+        let path = current_dir.join(crate::consts::FILE_NAME_PROJECT_CONFIG);
+        if path.is_file() {
+            return Some(path);
+        }
+        dir = current_dir.parent().map(std::path::Path::to_path_buf);
+    }
+    None
+}
+
+fn parse_value<T>(
+    path: &std::path::Path,
+    key: &str,
+    value: Option<String>,
+) -> color_eyre::eyre::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    match value {
+        Some(value) => T::from_str(&value).map(Some).map_err(|err| {
+            color_eyre::Report::msg(format!(
+                "Failed to parse <{}> in the file {:?}: {}",
+                key, path, err
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+fn parse(data: &str, path: &std::path::Path) -> color_eyre::eyre::Result<ProjectConfig> {
+    let file: ProjectConfigFile = toml::from_str(data).map_err(|err| {
+        color_eyre::Report::msg(format!("Failed to parse the file {:?}: {}", path, err))
+    })?;
+    Ok(ProjectConfig {
+        path: Some(path.to_path_buf()),
+        network: file.network,
+        signer: parse_value(path, "signer", file.signer)?,
+        contract: parse_value(path, "contract", file.contract)?,
+        gas: parse_value(path, "gas", file.gas)?,
+        deposit: parse_value(path, "deposit", file.deposit)?,
+        output_format: parse_value(path, "output_format", file.output_format)?,
+    })
+}
+
+/// Reads the project defaults, if there are any, for the rest of the command
+pub fn init() -> crate::CliResult {
+    let project_config = match find() {
+        Some(path) => {
+            let data = std::fs::read_to_string(&path).map_err(|err| {
+                color_eyre::Report::msg(format!("Failed to read the file {:?}: {}", &path, err))
+            })?;
+            parse(&data, &path)?
+        }
+        None => ProjectConfig::default(),
+    };
+    PROJECT_CONFIG.with(|config| *config.borrow_mut() = project_config);
+    Ok(())
+}
+
+pub fn get() -> ProjectConfig {
+    PROJECT_CONFIG.with(|config| config.borrow().clone())
+}
+
+/// Where the project defaults come from, as shown by `near config show`
+pub fn source() -> String {
+    match get().path {
+        Some(path) => path.display().to_string(),
+        None => crate::consts::FILE_NAME_PROJECT_CONFIG.to_string(),
+    }
+}
+
+pub fn contract() -> Option<near_primitives::types::AccountId> {
+    get().contract
+}
+
+pub fn gas() -> Option<crate::common::NearGas> {
+    get().gas
+}
+
+pub fn deposit() -> Option<crate::common::NearBalance> {
+    get().deposit
+}
+
+pub fn output_format() -> Option<crate::common::OutputFormat> {
+    get().output_format
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_project_config() {
+        let path = std::path::Path::new("/project/.near-cli.toml");
+        let project_config = parse(
+            r#"
+network = "testnet"
+signer = "alice.testnet"
+contract = "counter.alice.testnet"
+gas = "30 TeraGas"
+deposit = "1 NEAR"
+output_format = "json"
+"#,
+            path,
+        )
+        .unwrap();
+        assert_eq!(project_config.network.as_deref(), Some("testnet"));
+        assert_eq!(
+            project_config.contract.unwrap().to_string(),
+            "counter.alice.testnet"
+        );
+        assert_eq!(project_config.gas.unwrap().inner, 30_000_000_000_000);
+        assert!(parse("gas = \"lots\"", path).is_err());
+        assert!(parse("unknown = 1", path).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use near_jsonrpc_primitives::errors::RpcError;
+
+/// How an RPC request failed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RpcErrorKind {
+    /// The endpoint is unreachable or its response is not a JSON RPC response
+    Transport,
+    /// The request timed out on the client or on the node
+    Timeout,
+    /// The node handled the request and returned an error, e.g. an unknown account
+    Handler,
+}
+
+const PARSE_ERROR_CODE: i64 = -32700;
+
+pub fn classify_error(err: &RpcError) -> RpcErrorKind {
+    match &err.data {
+        Some(serde_json::Value::String(data)) if data.contains("Timeout") => RpcErrorKind::Timeout,
+        _ if err.code == PARSE_ERROR_CODE => RpcErrorKind::Transport,
+        Some(_) => RpcErrorKind::Handler,
+        None => RpcErrorKind::Transport,
+    }
+}
+
+/// The node does not know the block: it has been garbage collected or never existed
+pub fn is_unknown_block_error(err: &RpcError) -> bool {
+    classify_error(err) == RpcErrorKind::Handler
+        && serde_json::to_string(err).map_or(false, |err| {
+            err.contains("UNKNOWN_BLOCK") || err.contains("DB Not Found Error: BLOCK")
+        })
+}
+
+fn timeout_error(endpoint: &url::Url, timeout: std::time::Duration) -> RpcError {
+    RpcError::new(
+        -32000,
+        "Server error".to_string(),
+        Some(serde_json::Value::String(format!(
+            "Timeout: {} did not respond in {:?}",
+            endpoint, timeout
+        ))),
+    )
+}
+
+/// Resolves to `None` if the future does not complete before the sleep
+struct WithTimeout<T> {
+    future: std::pin::Pin<Box<dyn std::future::Future<Output = T>>>,
+    sleep: std::pin::Pin<Box<dyn std::future::Future<Output = ()>>>,
+}
+
+impl<T> std::future::Future for WithTimeout<T> {
+    type Output = Option<T>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if let std::task::Poll::Ready(output) = self.future.as_mut().poll(cx) {
+            return std::task::Poll::Ready(Some(output));
+        }
+        if self.sleep.as_mut().poll(cx).is_ready() {
+            return std::task::Poll::Ready(None);
+        }
+        std::task::Poll::Pending
+    }
+}
+
+/// The RPC client shared by all the commands: it sends the request to the
+/// endpoints of the network in order, retries the queries that failed on
+/// transport errors or timeouts with an exponential backoff, and fails over
+/// to the next endpoint.
+#[derive(Debug, Clone)]
+pub struct RpcClient {
+    endpoints: Vec<url::Url>,
+    timeout: std::time::Duration,
+    max_retries: u32,
+}
+
+impl RpcClient {
+    pub fn new(connection_config: &crate::common::ConnectionConfig) -> Self {
+        let config = crate::config::get();
+        let endpoints = config
+            .rpc
+            .endpoints
+            .get(&crate::journal::network_name(connection_config))
+            .filter(|endpoints| !endpoints.is_empty())
+            .cloned()
+            .unwrap_or_else(|| vec![connection_config.rpc_url()]);
+        Self::with_endpoints(&config.rpc, endpoints)
+    }
+
+    pub fn archival(connection_config: &crate::common::ConnectionConfig) -> Self {
+        let config = crate::config::get();
+        let endpoints = config
+            .rpc
+            .archival_endpoints
+            .get(&crate::journal::network_name(connection_config))
+            .filter(|endpoints| !endpoints.is_empty())
+            .cloned()
+            .unwrap_or_else(|| vec![connection_config.archival_rpc_url()]);
+        Self::with_endpoints(&config.rpc, endpoints)
+    }
+
+    /// The client of a single endpoint, e.g. to check the URL of a custom network
+    pub fn from_url(url: url::Url) -> Self {
+        let config = crate::config::get();
+        Self::with_endpoints(&config.rpc, vec![url])
+    }
+
+    fn with_endpoints(rpc_config: &crate::config::RpcConfig, endpoints: Vec<url::Url>) -> Self {
+        Self {
+            endpoints,
+            timeout: rpc_config
+                .timeout_secs
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(crate::consts::RPC_TIMEOUT),
+            max_retries: rpc_config
+                .max_retries
+                .unwrap_or(crate::consts::RPC_MAX_RETRIES),
+        }
+    }
+
+    /// Sends the request to the endpoints in order. Queries are retried on
+    /// transport errors and timeouts up to `max_retries` times; other requests
+    /// are only passed to the next endpoint when the current one is unreachable.
+    async fn call<T, F, Fut>(&self, is_idempotent: bool, request: F) -> Result<T, RpcError>
+    where
+        F: Fn(&near_jsonrpc_client::JsonRpcClient) -> Fut,
+        Fut: std::future::Future<Output = Result<T, RpcError>> + 'static,
+    {
+        let attempts = if is_idempotent {
+            self.max_retries as usize + 1
+        } else {
+            self.endpoints.len()
+        };
+        let mut delay = crate::consts::RPC_RETRY_INITIAL_DELAY;
+        let mut attempt = 0;
+        loop {
+            let endpoint = &self.endpoints[attempt % self.endpoints.len()];
+            let client = near_jsonrpc_client::new_client(endpoint.as_str());
+            let err = match (WithTimeout {
+                future: Box::pin(request(&client)),
+                sleep: Box::pin(actix::clock::sleep(self.timeout)),
+            })
+            .await
+            {
+                Some(Ok(response)) => return Ok(response),
+                Some(Err(err)) => err,
+                None => timeout_error(endpoint, self.timeout),
+            };
+            let error_kind = classify_error(&err);
+            attempt += 1;
+            let is_retriable = match error_kind {
+                RpcErrorKind::Handler => false,
+                RpcErrorKind::Timeout => is_idempotent,
+                RpcErrorKind::Transport => true,
+            };
+            if !is_retriable || attempt >= attempts {
+                return Err(err);
+            }
+            let next_endpoint = &self.endpoints[attempt % self.endpoints.len()];
+            println!(
+                "RPC request to {} failed ({:?} error), retrying with {} ...",
+                endpoint, error_kind, next_endpoint
+            );
+            if is_idempotent {
+                actix::clock::sleep(delay).await;
+                delay = std::cmp::min(delay * 2, crate::consts::RPC_RETRY_MAX_DELAY);
+            }
+        }
+    }
+
+    pub async fn query(
+        &self,
+        request: near_jsonrpc_primitives::types::query::RpcQueryRequest,
+    ) -> Result<near_jsonrpc_primitives::types::query::RpcQueryResponse, RpcError> {
+        self.call(true, |client| client.query(request.clone()))
+            .await
+    }
+
+    pub async fn block(
+        &self,
+        block_reference: near_primitives::types::BlockReference,
+    ) -> Result<near_primitives::views::BlockView, RpcError> {
+        self.call(true, |client| client.block(block_reference.clone()))
+            .await
+    }
+
+    pub async fn status(&self) -> Result<near_primitives::views::StatusResponse, RpcError> {
+        self.call(true, |client| client.status()).await
+    }
+
+    pub async fn tx(
+        &self,
+        transaction_hash: String,
+        account_id: near_primitives::types::AccountId,
+    ) -> Result<near_primitives::views::FinalExecutionOutcomeView, RpcError> {
+        self.call(true, |client| {
+            client.tx(transaction_hash.clone(), account_id.clone())
+        })
+        .await
+    }
+
+    #[allow(non_snake_case)]
+    pub async fn EXPERIMENTAL_protocol_config(
+        &self,
+        request: near_jsonrpc_primitives::types::config::RpcProtocolConfigRequest,
+    ) -> Result<near_jsonrpc_primitives::types::config::RpcProtocolConfigResponse, RpcError> {
+        self.call(true, |client| {
+            client.EXPERIMENTAL_protocol_config(request.clone())
+        })
+        .await
+    }
+
+    /// Sending a signed transaction once again can't execute it twice,
+    /// but the request is not retried here: the callers resend it on timeouts.
+    pub async fn broadcast_tx_commit(
+        &self,
+        signed_transaction_base64: String,
+    ) -> Result<near_primitives::views::FinalExecutionOutcomeView, RpcError> {
+        self.call(false, |client| {
+            client.broadcast_tx_commit(signed_transaction_base64.clone())
+        })
+        .await
+    }
+
+    /// Sends the transaction without waiting for it to be executed; returns its hash
+    pub async fn broadcast_tx_async(
+        &self,
+        signed_transaction_base64: String,
+    ) -> Result<String, RpcError> {
+        self.call(false, |client| {
+            client.broadcast_tx_async(signed_transaction_base64.clone())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_rpc_errors() {
+        let endpoint: url::Url = "https://rpc.testnet.near.org".parse().unwrap();
+        assert_eq!(
+            classify_error(&timeout_error(&endpoint, crate::consts::RPC_TIMEOUT)),
+            RpcErrorKind::Timeout
+        );
+        assert_eq!(
+            classify_error(&RpcError::new(
+                PARSE_ERROR_CODE,
+                "Parse error".to_string(),
+                Some(serde_json::Value::String(
+                    "<html>502 Bad Gateway</html>".to_string()
+                )),
+            )),
+            RpcErrorKind::Transport
+        );
+        assert_eq!(
+            classify_error(&RpcError::new(
+                -32000,
+                "Server error".to_string(),
+                Some(serde_json::Value::String(
+                    "account bob.testnet does not exist while viewing".to_string()
+                )),
+            )),
+            RpcErrorKind::Handler
+        );
+    }
+
+    #[test]
+    fn detect_unknown_block_errors() {
+        assert!(is_unknown_block_error(&RpcError::new(
+            -32000,
+            "Server error".to_string(),
+            Some(serde_json::Value::String(
+                "DB Not Found Error: BLOCK: 11111111111111111111111111111111".to_string()
+            )),
+        )));
+        let endpoint: url::Url = "https://rpc.testnet.near.org".parse().unwrap();
+        assert!(!is_unknown_block_error(&timeout_error(
+            &endpoint,
+            crate::consts::RPC_TIMEOUT
+        )));
+        assert!(!is_unknown_block_error(&RpcError::new(
+            PARSE_ERROR_CODE,
+            "Parse error".to_string(),
+            Some(serde_json::Value::String(
+                "<html>502 Bad Gateway</html>".to_string()
+            )),
+        )));
+    }
+}